@@ -0,0 +1,48 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+/// Returns the total calories carried by each elf, largest first.
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut calories: Vec<i32> = aoc_parse::blocks(input)
+        .map(|elf_inventory| {
+            elf_inventory.lines().try_fold(0i32, |total, line| {
+                total
+                    .checked_add(aoc_parse::number(input, line.trim())?)
+                    .ok_or_else(|| {
+                        ParseError::new(input, elf_inventory, "a total that fits in 32 bits")
+                    })
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    calories.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(calories)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(calories: &Self::Input<'_>) -> i32 {
+        calories.first().copied().unwrap_or(0)
+    }
+
+    fn part2(calories: &Self::Input<'_>) -> i32 {
+        calories.iter().take(3).sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day01::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day01::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,180 @@
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Move {
+    pub fn from_opponent_code(code: &str) -> Option<Self> {
+        match code {
+            "A" => Some(Move::Rock),
+            "B" => Some(Move::Paper),
+            "C" => Some(Move::Scissors),
+            _ => None,
+        }
+    }
+
+    pub fn from_player_code(code: &str) -> Option<Self> {
+        match code {
+            "X" => Some(Move::Rock),
+            "Y" => Some(Move::Paper),
+            "Z" => Some(Move::Scissors),
+            _ => None,
+        }
+    }
+
+    pub fn score(self) -> i32 {
+        match self {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissors => 3,
+        }
+    }
+
+    pub fn beats(self, other: Move) -> bool {
+        matches!(
+            (self, other),
+            (Move::Rock, Move::Scissors)
+                | (Move::Paper, Move::Rock)
+                | (Move::Scissors, Move::Paper)
+        )
+    }
+
+    pub fn move_for_outcome(self, outcome: Outcome) -> Move {
+        match outcome {
+            Outcome::Draw => self,
+            Outcome::Win => self.winning_move(),
+            Outcome::Loss => self.losing_move(),
+        }
+    }
+
+    fn winning_move(self) -> Move {
+        match self {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock,
+        }
+    }
+
+    fn losing_move(self) -> Move {
+        match self {
+            Move::Rock => Move::Scissors,
+            Move::Paper => Move::Rock,
+            Move::Scissors => Move::Paper,
+        }
+    }
+}
+
+impl Outcome {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "X" => Some(Outcome::Loss),
+            "Y" => Some(Outcome::Draw),
+            "Z" => Some(Outcome::Win),
+            _ => None,
+        }
+    }
+
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Loss => 0,
+        }
+    }
+
+    pub fn determine(player: Move, opponent: Move) -> Self {
+        if player.beats(opponent) {
+            Outcome::Win
+        } else if opponent.beats(player) {
+            Outcome::Loss
+        } else {
+            Outcome::Draw
+        }
+    }
+}
+
+/// A line of the strategy guide. The meaning of the second column differs
+/// between the two parts, so it is kept as the raw code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Move,
+    pub code: String,
+}
+
+fn parse_round(line: &str) -> Result<Round, ParseError> {
+    let (opponent, code) = aoc_parse::split_once(line, line, " ")?;
+    let opponent = Move::from_opponent_code(opponent)
+        .ok_or_else(|| ParseError::new(line, opponent, "'A', 'B' or 'C'"))?;
+    if Move::from_player_code(code).is_none() {
+        return Err(ParseError::new(line, code, "'X', 'Y' or 'Z'"));
+    }
+    Ok(Round {
+        opponent,
+        code: code.to_string(),
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Round>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_round(line).within(input, line))
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Round>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rounds: &Self::Input<'_>) -> i32 {
+        rounds
+            .iter()
+            .filter_map(|round| {
+                let opponent = round.opponent;
+                let player = Move::from_player_code(&round.code)?;
+
+                let outcome = Outcome::determine(player, opponent);
+                Some(player.score() + outcome.score())
+            })
+            .sum()
+    }
+
+    fn part2(rounds: &Self::Input<'_>) -> i32 {
+        rounds
+            .iter()
+            .filter_map(|round| {
+                let opponent = round.opponent;
+                let desired_outcome = Outcome::from_code(&round.code)?;
+
+                let player_move = opponent.move_for_outcome(desired_outcome);
+                Some(player_move.score() + desired_outcome.score())
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day02::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day02::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,122 @@
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+use std::collections::HashSet;
+
+fn priority(c: char) -> i32 {
+    match c {
+        'a'..='z' => (c as i32) - ('a' as i32) + 1,
+        'A'..='Z' => (c as i32) - ('A' as i32) + 27,
+        _ => 0,
+    }
+}
+
+pub struct Rucksack {
+    compartment1: String,
+    compartment2: String,
+}
+
+impl Rucksack {
+    pub fn from_line(line: &str) -> Self {
+        let mid = line.len() / 2;
+        Self {
+            compartment1: line[..mid].to_string(),
+            compartment2: line[mid..].to_string(),
+        }
+    }
+
+    pub fn find_common_item(&self) -> char {
+        let set1: HashSet<char> = self.compartment1.chars().collect();
+        self.compartment2
+            .chars()
+            .find(|c| set1.contains(c))
+            .expect("No common item found between compartments")
+    }
+
+    pub fn priority(&self) -> i32 {
+        priority(self.find_common_item())
+    }
+}
+
+pub struct Group<'a> {
+    rucksacks: &'a [Rucksack],
+}
+
+impl<'a> Group<'a> {
+    pub fn new(rucksacks: &'a [Rucksack]) -> Self {
+        Self { rucksacks }
+    }
+
+    pub fn find_badge(&self) -> char {
+        if self.rucksacks.is_empty() {
+            panic!("Cannot find badge in empty group");
+        }
+
+        let mut candidates: HashSet<char> = self.rucksacks[0]
+            .compartment1
+            .chars()
+            .chain(self.rucksacks[0].compartment2.chars())
+            .collect();
+
+        for rucksack in self.rucksacks.iter().skip(1) {
+            let rucksack_chars: HashSet<char> = rucksack
+                .compartment1
+                .chars()
+                .chain(rucksack.compartment2.chars())
+                .collect();
+            candidates.retain(|c| rucksack_chars.contains(c));
+        }
+
+        candidates
+            .into_iter()
+            .next()
+            .expect("No common badge found in group")
+    }
+
+    pub fn badge_priority(&self) -> i32 {
+        priority(self.find_badge())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(line, i, 1, "an item ('a'-'z' or 'A'-'Z')"))
+                    .within(input, line);
+            }
+            Ok(Rucksack::from_line(line))
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Rucksack>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> i32 {
+        rucksacks.iter().map(Rucksack::priority).sum()
+    }
+
+    fn part2(rucksacks: &Self::Input<'_>) -> i32 {
+        rucksacks
+            .chunks_exact(3)
+            .map(|group| Group::new(group).badge_priority())
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day03::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day03::part2(&parse(input).expect("Failed to parse input"))
+}
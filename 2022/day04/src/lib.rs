@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use aoc_intervals::Interval;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+/// The sections assigned to one elf.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Range {
+    sections: Interval<u32>,
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start_s, end_s) = aoc_parse::split_once(s, s, "-")?;
+        let start_val: u32 = aoc_parse::number(s, start_s)?;
+        let end_val: u32 = aoc_parse::number(s, end_s)?;
+
+        if start_val > end_val {
+            return Err(ParseError::new(
+                s,
+                end_s,
+                format!("an end no less than the start ({})", start_val),
+            ));
+        }
+
+        Ok(Range {
+            sections: Interval::inclusive(start_val, end_val),
+        })
+    }
+}
+
+impl Range {
+    pub fn contains(&self, other: &Range) -> bool {
+        self.sections.contains_interval(&other.sections)
+    }
+
+    pub fn overlaps(&self, other: &Range) -> bool {
+        self.sections.overlaps(&other.sections)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<(Range, Range)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> usize {
+        pairs
+            .iter()
+            .filter(|(r1, r2)| r1.contains(r2) || r2.contains(r1))
+            .count()
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> usize {
+        pairs.iter().filter(|(r1, r2)| r1.overlaps(r2)).count()
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day04::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day04::part2(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Range, Range)>, ParseError> {
+    let mut results = Vec::new();
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        let (r1_s, r2_s) = aoc_parse::split_once(input, line, ",")?;
+        let r1 = Range::from_str(r1_s).within(input, r1_s)?;
+        let r2 = Range::from_str(r2_s).within(input, r2_s)?;
+        results.push((r1, r2));
+    }
+    Ok(results)
+}
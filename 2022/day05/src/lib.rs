@@ -0,0 +1,145 @@
+use aoc_solution::{ParseError, Solution};
+
+pub trait Crane {
+    fn move_crates(&self, stacks: &mut [Vec<char>], instruction: &Instruction);
+
+    fn solve(&self, procedure: &Procedure) -> String {
+        let mut ship = procedure.ship.clone();
+        for instruction in &procedure.instructions {
+            self.move_crates(&mut ship.stacks, instruction);
+        }
+        ship.get_top_crates()
+    }
+}
+
+pub struct CrateMover9000;
+impl Crane for CrateMover9000 {
+    fn move_crates(&self, stacks: &mut [Vec<char>], instruction: &Instruction) {
+        for _ in 0..instruction.count {
+            if let Some(c) = stacks[instruction.from].pop() {
+                stacks[instruction.to].push(c);
+            }
+        }
+    }
+}
+
+pub struct CrateMover9001;
+impl Crane for CrateMover9001 {
+    fn move_crates(&self, stacks: &mut [Vec<char>], instruction: &Instruction) {
+        let from_stack = &mut stacks[instruction.from];
+        let drain_start = from_stack.len().saturating_sub(instruction.count);
+        let crates: Vec<char> = from_stack.drain(drain_start..).collect();
+        stacks[instruction.to].extend(crates);
+    }
+}
+
+pub struct Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Instruction {
+    fn parse_multiple(input: &str) -> Vec<Self> {
+        input.lines().filter_map(Self::parse).collect()
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.len() < 6 {
+            return None;
+        }
+
+        let count = words[1].parse().ok()?;
+        let from = words[3].parse::<usize>().ok()?.checked_sub(1)?;
+        let to = words[5].parse::<usize>().ok()?.checked_sub(1)?;
+
+        Some(Self { count, from, to })
+    }
+}
+
+#[derive(Clone)]
+pub struct Ship {
+    stacks: Vec<Vec<char>>,
+}
+
+impl Ship {
+    fn parse(diagram: &str) -> Option<Self> {
+        let lines: Vec<&str> = diagram.lines().collect();
+        let last_line = lines.last()?;
+
+        // The stacks are labelled 1, 2, 3... in order along the bottom.
+        let labels: Vec<&str> = last_line.split_whitespace().collect();
+        if labels.is_empty() || (1..).zip(&labels).any(|(i, label)| label.parse() != Ok(i)) {
+            return None;
+        }
+        let num_stacks = labels.len();
+
+        let mut stacks = vec![Vec::new(); num_stacks];
+
+        for line in lines.iter().rev().skip(1) {
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                let char_idx = 1 + i * 4;
+                if let Some(c) = line.chars().nth(char_idx) {
+                    if c.is_alphabetic() {
+                        stack.push(c);
+                    }
+                }
+            }
+        }
+
+        Some(Self { stacks })
+    }
+
+    fn get_top_crates(&self) -> String {
+        self.stacks.iter().filter_map(|s| s.last()).collect()
+    }
+}
+
+/// The starting stacks and the rearrangement procedure applied to them.
+pub struct Procedure {
+    ship: Ship,
+    instructions: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Option<Procedure> {
+    let (diagram, moves) = input.split_once("\n\n")?;
+    Some(Procedure {
+        ship: Ship::parse(diagram)?,
+        instructions: Instruction::parse_multiple(moves),
+    })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input).ok_or_else(|| {
+            ParseError::end(input, "a drawing of the stacks, a blank line and the moves")
+        })
+    }
+
+    fn part1(procedure: &Self::Input<'_>) -> String {
+        CrateMover9000.solve(procedure)
+    }
+
+    fn part2(procedure: &Self::Input<'_>) -> String {
+        CrateMover9001.solve(procedure)
+    }
+}
+
+pub fn part1(input: &str) -> String {
+    parse(input)
+        .map(|procedure| Day05::part1(&procedure))
+        .unwrap_or_default()
+}
+
+pub fn part2(input: &str) -> String {
+    parse(input)
+        .map(|procedure| Day05::part2(&procedure))
+        .unwrap_or_default()
+}
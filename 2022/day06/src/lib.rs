@@ -0,0 +1,82 @@
+use aoc_solution::{ParseError, Solution};
+
+/// Returns the number of characters processed once the last `size`
+/// characters are all different.
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let mut last_seen = [None; 256];
+    let mut start = 0;
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        if let Some(prev_pos) = last_seen[byte as usize] {
+            if prev_pos >= start {
+                start = prev_pos + 1;
+            }
+        }
+        last_seen[byte as usize] = Some(i);
+
+        if i + 1 - start >= size {
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+/// Returns the datastream buffer without its trailing newline, checking it
+/// is a single line of lowercase letters.
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    let signal = input.trim_end();
+    if let Some((i, c)) = signal.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        return Err(ParseError::at(input, i, c.len_utf8(), "a lowercase letter"));
+    }
+    Ok(signal)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(signal: &Self::Input<'_>) -> usize {
+        find_marker(signal, 4).expect("Should find a start-of-packet marker")
+    }
+
+    fn part2(signal: &Self::Input<'_>) -> usize {
+        find_marker(signal, 14).expect("Should find a start-of-message marker")
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day06::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day06::part2(&parse(input).expect("Failed to parse input"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_examples() {
+        assert_eq!(part1("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
+        assert_eq!(part1("nppdvjthqldpwncqszvftbrmjlhg"), 6);
+        assert_eq!(part1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
+        assert_eq!(part1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
+    }
+
+    #[test]
+    fn test_part2_examples() {
+        assert_eq!(part2("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
+        assert_eq!(part2("nppdvjthqldpwncqszvftbrmjlhg"), 23);
+        assert_eq!(part2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
+        assert_eq!(part2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
+    }
+}
@@ -0,0 +1,141 @@
+const TOTAL_DISK_SPACE: u64 = 70_000_000;
+const UPDATE_REQUIRED_SPACE: u64 = 30_000_000;
+const PART1_SIZE_LIMIT: u64 = 100_000;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Node {
+    File(u64),
+    Directory(HashMap<String, Node>),
+}
+
+impl Node {
+    fn new_dir() -> Self {
+        Self::Directory(HashMap::new())
+    }
+
+    fn calculate_sizes(&self, all_sizes: &mut Vec<u64>) -> u64 {
+        match self {
+            Self::File(size) => *size,
+            Self::Directory(children) => {
+                let total_size: u64 = children
+                    .values()
+                    .map(|node| node.calculate_sizes(all_sizes))
+                    .sum();
+                all_sizes.push(total_size);
+                total_size
+            }
+        }
+    }
+
+    /// The directory at `path`, creating any missing on the way, or `None`
+    /// if the path runs into a file.
+    fn get_or_create_dir(&mut self, path: &[String]) -> Option<&mut HashMap<String, Node>> {
+        let mut current = self;
+        for segment in path {
+            if let Self::Directory(children) = current {
+                current = children
+                    .entry(segment.clone())
+                    .or_insert_with(Self::new_dir);
+            }
+        }
+
+        match current {
+            Self::Directory(children) => Some(children),
+            Self::File(_) => None,
+        }
+    }
+}
+
+pub struct FileSystem {
+    root: Node,
+}
+
+impl FileSystem {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut root = Node::new_dir();
+        let mut current_path = Vec::new();
+
+        for line in input.lines() {
+            let not_a_dir = || ParseError::new(input, line, "a listing inside a directory");
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.as_slice() {
+                ["$", "cd", "/"] => current_path.clear(),
+                ["$", "cd", ".."] => {
+                    current_path.pop();
+                }
+                ["$", "cd", dir] => current_path.push(dir.to_string()),
+                ["$", "ls"] => {}
+                ["dir", name] => {
+                    root.get_or_create_dir(&current_path)
+                        .ok_or_else(not_a_dir)?
+                        .entry(name.to_string())
+                        .or_insert_with(Node::new_dir);
+                }
+                [size_str, name] => {
+                    let size = aoc_parse::number(input, size_str)?;
+                    root.get_or_create_dir(&current_path)
+                        .ok_or_else(not_a_dir)?
+                        .insert(name.to_string(), Node::File(size));
+                }
+                _ => return Err(ParseError::new(input, line, "a command or a listing")),
+            }
+        }
+        Ok(Self { root })
+    }
+
+    /// Returns the total size of every directory, including the root.
+    pub fn dir_sizes(&self) -> Vec<u64> {
+        let mut sizes = Vec::new();
+        self.root.calculate_sizes(&mut sizes);
+        sizes
+    }
+}
+
+pub fn parse(input: &str) -> Result<FileSystem, ParseError> {
+    FileSystem::parse(input)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = FileSystem;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(fs: &Self::Input<'_>) -> u64 {
+        fs.dir_sizes()
+            .into_iter()
+            .filter(|&size| size <= PART1_SIZE_LIMIT)
+            .sum()
+    }
+
+    fn part2(fs: &Self::Input<'_>) -> u64 {
+        let sizes = fs.dir_sizes();
+
+        let used_space = *sizes.iter().max().unwrap_or(&0);
+        let current_free_space = TOTAL_DISK_SPACE.saturating_sub(used_space);
+        let need_to_free = UPDATE_REQUIRED_SPACE.saturating_sub(current_free_space);
+
+        sizes
+            .into_iter()
+            .filter(|&size| size >= need_to_free)
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+pub fn part1(input: &str) -> u64 {
+    Day07::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> u64 {
+    Day07::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,92 @@
+use aoc_grid::{Pos, ORTHOGONAL};
+use aoc_solution::{ParseError, Solution};
+
+pub struct Grid {
+    trees: aoc_grid::Grid<u8>,
+}
+
+impl Grid {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let trees = aoc_grid::Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(Self { trees })
+    }
+
+    pub fn height_at(&self, pos: Pos) -> u8 {
+        self.trees[pos]
+    }
+
+    pub fn is_visible(&self, pos: Pos) -> bool {
+        let h = self.height_at(pos);
+
+        ORTHOGONAL.iter().any(|&(dx, dy)| {
+            self.trees
+                .ray(pos, dx, dy)
+                .all(|tree| self.height_at(tree) < h)
+        })
+    }
+
+    pub fn scenic_score(&self, pos: Pos) -> i32 {
+        let h = self.height_at(pos);
+
+        ORTHOGONAL
+            .iter()
+            .map(|&(dx, dy)| {
+                let mut count = 0;
+                for tree in self.trees.ray(pos, dx, dy) {
+                    count += 1;
+                    if self.height_at(tree) >= h {
+                        break;
+                    }
+                }
+                count
+            })
+            .product()
+    }
+
+    pub fn get_max_scenic_score(&self) -> i32 {
+        self.trees
+            .positions()
+            .map(|pos| self.scenic_score(pos))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn count_visible_trees(&self) -> i32 {
+        self.trees
+            .positions()
+            .filter(|&pos| self.is_visible(pos))
+            .count() as i32
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    Grid::new(input)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Grid;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> i32 {
+        grid.count_visible_trees()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> i32 {
+        grid.get_max_scenic_score()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day08::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day08::part2(&parse(input).expect("Failed to parse input"))
+}
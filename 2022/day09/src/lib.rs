@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug)]
+pub enum RopeError {
+    EmptyRope,
+}
+
+impl fmt::Display for RopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyRope => write!(f, "Rope must have at least one knot"),
+        }
+    }
+}
+
+impl std::error::Error for RopeError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn move_in(&mut self, dir: Direction) {
+        match dir {
+            Direction::Up => self.y += 1,
+            Direction::Down => self.y -= 1,
+            Direction::Left => self.x -= 1,
+            Direction::Right => self.x += 1,
+        }
+    }
+}
+
+trait FollowStrategy {
+    fn follow(&self, leader: &Point, follower: &mut Point);
+}
+
+struct StandardFollowStrategy;
+
+impl FollowStrategy for StandardFollowStrategy {
+    fn follow(&self, leader: &Point, follower: &mut Point) {
+        let dx = leader.x - follower.x;
+        let dy = leader.y - follower.y;
+
+        if dx.abs() > 1 || dy.abs() > 1 {
+            follower.x += dx.signum();
+            follower.y += dy.signum();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseError::new(s, s, "one of U, D, L or R")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    direction: Direction,
+    steps: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let dir_str = aoc_parse::next(s, s, &mut parts, "a direction")?;
+        let steps_str = aoc_parse::next(s, s, &mut parts, "a step count")?;
+
+        let direction = dir_str.parse().within(s, dir_str)?;
+        let steps = aoc_parse::number(s, steps_str)?;
+
+        Ok(Instruction { direction, steps })
+    }
+}
+
+struct Rope<'a, S: FollowStrategy> {
+    knots: Vec<Point>,
+    instructions: &'a [Instruction],
+    strategy: S,
+}
+
+impl Rope<'static, StandardFollowStrategy> {
+    fn new(num_knots: usize) -> Self {
+        Self {
+            knots: vec![Point::default(); num_knots],
+            instructions: &[],
+            strategy: StandardFollowStrategy,
+        }
+    }
+}
+
+impl<'a, S: FollowStrategy> Rope<'a, S> {
+    fn add_instructions(self, instructions: &'a [Instruction]) -> Self {
+        Self {
+            knots: self.knots,
+            instructions,
+            strategy: self.strategy,
+        }
+    }
+
+    fn simulate(&mut self) -> Result<i32, RopeError> {
+        if self.knots.is_empty() {
+            return Err(RopeError::EmptyRope);
+        }
+
+        let mut visited = HashSet::new();
+        let tail_idx = self.knots.len() - 1;
+        visited.insert(self.knots[tail_idx]);
+
+        for instruction in self.instructions {
+            for _ in 0..instruction.steps {
+                self.knots[0].move_in(instruction.direction);
+
+                for i in 1..self.knots.len() {
+                    let leader = self.knots[i - 1];
+                    self.strategy.follow(&leader, &mut self.knots[i]);
+                }
+                visited.insert(self.knots[tail_idx]);
+            }
+        }
+
+        Ok(visited.len() as i32)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse().within(input, line))
+        .collect()
+}
+
+fn solve(instructions: &[Instruction], num_knots: usize) -> Result<i32, RopeError> {
+    Rope::new(num_knots)
+        .add_instructions(instructions)
+        .simulate()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> i32 {
+        solve(instructions, 2).unwrap_or(0)
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> i32 {
+        solve(instructions, 10).unwrap_or(0)
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day09::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day09::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,139 @@
+const SCREEN_WIDTH: i32 = 40;
+const SCREEN_HEIGHT: i32 = 6;
+const SPRITE_RADIUS: i32 = 1;
+
+const FIRST_SIGNAL_CYCLE: i32 = 20;
+const SIGNAL_CYCLE_STEP: i32 = 40;
+const MAX_SIGNAL_CYCLE: i32 = 220;
+
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        match aoc_parse::next(s, s, &mut parts, "an instruction")? {
+            "noop" => Ok(Instruction::Noop),
+            "addx" => {
+                let v = aoc_parse::next(s, s, &mut parts, "an addx value")?;
+                Ok(Instruction::Addx(aoc_parse::number(s, v)?))
+            }
+            other => Err(ParseError::new(s, other, "noop or addx")),
+        }
+    }
+}
+
+pub struct Cpu {
+    program: Vec<Instruction>,
+}
+
+impl FromStr for Cpu {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let program = input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.parse().within(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { program })
+    }
+}
+
+impl Cpu {
+    /// Returns the value of the X register during each cycle.
+    pub fn get_register_history(&self) -> Vec<i32> {
+        let mut x = 1;
+        let mut history = Vec::with_capacity(240);
+
+        for ins in &self.program {
+            match ins {
+                Instruction::Noop => {
+                    history.push(x);
+                }
+                Instruction::Addx(v) => {
+                    history.push(x);
+                    history.push(x);
+                    x += v;
+                }
+            }
+        }
+        history
+    }
+}
+
+pub struct Screen;
+
+impl Screen {
+    pub fn render(history: &[i32]) -> String {
+        let capacity = (SCREEN_WIDTH * SCREEN_HEIGHT + SCREEN_HEIGHT) as usize;
+        let mut output = String::with_capacity(capacity);
+
+        for (i, &x) in history.iter().enumerate() {
+            let pos = (i as i32) % SCREEN_WIDTH;
+
+            let is_sprite_visible = (pos - x).abs() <= SPRITE_RADIUS;
+            output.push(if is_sprite_visible { '#' } else { '.' });
+
+            if pos == SCREEN_WIDTH - 1 {
+                output.push('\n');
+            }
+
+            if i as i32 + 1 >= SCREEN_WIDTH * SCREEN_HEIGHT {
+                break;
+            }
+        }
+        output
+    }
+}
+
+pub fn parse(input: &str) -> Result<Cpu, ParseError> {
+    input.parse()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Cpu;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cpu: &Self::Input<'_>) -> i32 {
+        cpu.get_register_history()
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (i as i32 + 1, x))
+            .filter(|(cycle, _)| {
+                (*cycle - FIRST_SIGNAL_CYCLE) % SIGNAL_CYCLE_STEP == 0 && *cycle <= MAX_SIGNAL_CYCLE
+            })
+            .map(|(cycle, x)| cycle * x)
+            .sum()
+    }
+
+    fn part2(cpu: &Self::Input<'_>) -> String {
+        Screen::render(&cpu.get_register_history())
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day10::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> String {
+    Day10::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,195 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use aoc_math::number::lcm;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(i64),
+    Mul(i64),
+    Square,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let op_str = aoc_parse::strip_prefix(s, s, "Operation: new = old ")?;
+        let mut parts = op_str.split_whitespace();
+        let operator = aoc_parse::next(s, s, &mut parts, "an operator")?;
+        let operand = aoc_parse::next(s, s, &mut parts, "an operand")?;
+
+        match (operator, operand) {
+            ("*", "old") => Ok(Self::Square),
+            ("*", n) => aoc_parse::number(s, n).map(Self::Mul),
+            ("+", n) => aoc_parse::number(s, n).map(Self::Add),
+            _ => Err(ParseError::new(s, operator, "\"*\" or \"+\"")),
+        }
+    }
+}
+
+impl Operation {
+    fn apply(&self, old: i64) -> i64 {
+        match self {
+            Self::Add(n) => old + n,
+            Self::Mul(n) => old * n,
+            Self::Square => old * old,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<i64>,
+    operation: Operation,
+    test_divisor: i64,
+    true_target: usize,
+    false_target: usize,
+    inspection_count: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(block: &str) -> Result<Self, Self::Err> {
+        let mut lines = block.lines().map(|l| l.trim());
+        let mut line = |expected| aoc_parse::next(block, block, &mut lines, expected);
+        let _header = line("a monkey header")?;
+        let items_line = line("a starting items line")?;
+        let operation_line = line("an operation line")?;
+        let test_line = line("a test line")?;
+        let true_line = line("an if true line")?;
+        let false_line = line("an if false line")?;
+
+        let items = aoc_parse::strip_prefix(block, items_line, "Starting items: ")?
+            .split(", ")
+            .map(|s| aoc_parse::number(block, s))
+            .collect::<Result<VecDeque<_>, _>>()?;
+
+        let operation = operation_line.parse().within(block, operation_line)?;
+        let test_divisor = aoc_parse::number(
+            block,
+            aoc_parse::strip_prefix(block, test_line, "Test: divisible by ")?,
+        )?;
+        let true_target = aoc_parse::number(
+            block,
+            aoc_parse::strip_prefix(block, true_line, "If true: throw to monkey ")?,
+        )?;
+        let false_target = aoc_parse::number(
+            block,
+            aoc_parse::strip_prefix(block, false_line, "If false: throw to monkey ")?,
+        )?;
+
+        Ok(Self {
+            items,
+            operation,
+            test_divisor,
+            true_target,
+            false_target,
+            inspection_count: 0,
+        })
+    }
+}
+
+pub trait ReliefStrategy {
+    fn apply(&self, worry: i64, common_multiple: i64) -> i64;
+}
+
+pub struct DivideByThree;
+impl ReliefStrategy for DivideByThree {
+    fn apply(&self, worry: i64, common_multiple: i64) -> i64 {
+        (worry / 3) % common_multiple
+    }
+}
+
+pub struct NoRelief;
+impl ReliefStrategy for NoRelief {
+    fn apply(&self, worry: i64, common_multiple: i64) -> i64 {
+        worry % common_multiple
+    }
+}
+
+#[derive(Clone)]
+pub struct MonkeySimulation {
+    monkeys: Vec<Monkey>,
+    common_multiple: i64,
+}
+
+impl MonkeySimulation {
+    pub fn try_new(input: &str) -> Result<Self, ParseError> {
+        let monkeys = aoc_parse::blocks(input)
+            .map(|block| block.parse::<Monkey>().within(input, block))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Keeping worry levels modulo a multiple of every divisor leaves every
+        // monkey's test unchanged.
+        let common_multiple = monkeys.iter().map(|m| m.test_divisor).fold(1, lcm);
+        Ok(Self {
+            monkeys,
+            common_multiple,
+        })
+    }
+
+    pub fn run<S: ReliefStrategy>(mut self, rounds: usize, strategy: S) -> Self {
+        for _ in 0..rounds {
+            for i in 0..self.monkeys.len() {
+                // Take items out to avoid borrow checker issues during the turn
+                let mut items = std::mem::take(&mut self.monkeys[i].items);
+                self.monkeys[i].inspection_count += items.len();
+
+                while let Some(item) = items.pop_front() {
+                    let worry =
+                        strategy.apply(self.monkeys[i].operation.apply(item), self.common_multiple);
+
+                    let target = if worry % self.monkeys[i].test_divisor == 0 {
+                        self.monkeys[i].true_target
+                    } else {
+                        self.monkeys[i].false_target
+                    };
+
+                    self.monkeys[target].items.push_back(worry);
+                }
+            }
+        }
+        self
+    }
+
+    pub fn monkey_business(&self) -> i64 {
+        let mut counts: Vec<_> = self.monkeys.iter().map(|m| m.inspection_count).collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.iter().take(2).map(|&c| c as i64).product()
+    }
+}
+
+pub fn parse(input: &str) -> Result<MonkeySimulation, ParseError> {
+    MonkeySimulation::try_new(input)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = MonkeySimulation;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(simulation: &Self::Input<'_>) -> i64 {
+        simulation.clone().run(20, DivideByThree).monkey_business()
+    }
+
+    fn part2(simulation: &Self::Input<'_>) -> i64 {
+        simulation.clone().run(10000, NoRelief).monkey_business()
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day11::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day11::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use aoc_grid::{Direction, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+trait SearchGoal {
+    fn is_reached(&self, pos: Pos, hm: &HeightMap) -> bool;
+}
+
+trait MoveConstraint {
+    fn can_move(&self, current: u8, next: u8) -> bool;
+}
+
+struct ReachTargetPos(Pos);
+impl SearchGoal for ReachTargetPos {
+    fn is_reached(&self, pos: Pos, _hm: &HeightMap) -> bool {
+        pos == self.0
+    }
+}
+
+struct ReachElevation(u8);
+impl SearchGoal for ReachElevation {
+    fn is_reached(&self, pos: Pos, hm: &HeightMap) -> bool {
+        hm.elevations[pos.y][pos.x] == self.0
+    }
+}
+
+struct ForwardMove;
+impl MoveConstraint for ForwardMove {
+    fn can_move(&self, current: u8, next: u8) -> bool {
+        next <= current + 1
+    }
+}
+
+struct BackwardMove;
+impl MoveConstraint for BackwardMove {
+    fn can_move(&self, current: u8, next: u8) -> bool {
+        next >= current.saturating_sub(1)
+    }
+}
+
+pub struct HeightMap {
+    elevations: Vec<Vec<u8>>,
+    start: Pos,
+    end: Pos,
+    rows: usize,
+    cols: usize,
+}
+
+impl FromStr for HeightMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let cols = lines.first().map_or(0, |line| line.chars().count());
+        if cols == 0 {
+            return Err(ParseError::new(s, s, "a heightmap"));
+        }
+        let rows = lines.len();
+
+        let mut start = None;
+        let mut end = None;
+        let mut elevations = vec![vec![0u8; cols]; rows];
+
+        for (y, line) in lines.iter().enumerate() {
+            if line.chars().count() != cols {
+                let extra = line.char_indices().nth(cols).map_or(line.len(), |(i, _)| i);
+                return Err(ParseError::new(
+                    s,
+                    &line[extra..],
+                    format!("a row of {} cells", cols),
+                ));
+            }
+            for (x, (i, ch)) in line.char_indices().enumerate() {
+                match ch {
+                    'S' => {
+                        start = Some(Pos { x, y });
+                        elevations[y][x] = 0;
+                    }
+                    'E' => {
+                        end = Some(Pos { x, y });
+                        elevations[y][x] = 25;
+                    }
+                    'a'..='z' => {
+                        elevations[y][x] = (ch as u8) - b'a';
+                    }
+                    _ => {
+                        let found = &line[i..i + ch.len_utf8()];
+                        return Err(ParseError::new(s, found, "a-z, S or E"));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            elevations,
+            start: start.ok_or_else(|| ParseError::end(s, "a start (S)"))?,
+            end: end.ok_or_else(|| ParseError::end(s, "an end (E)"))?,
+            rows,
+            cols,
+        })
+    }
+}
+
+impl HeightMap {
+    fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| dir.step(pos))
+            .filter(|next| next.x < self.cols && next.y < self.rows)
+    }
+
+    fn shortest_path<G, M>(&self, start_pos: Pos, goal: G, constraint: M) -> Option<usize>
+    where
+        G: SearchGoal,
+        M: MoveConstraint,
+    {
+        let constraint = &constraint;
+        let path = aoc_search::bfs(
+            [start_pos],
+            |&pos| {
+                let current_elevation = self.elevations[pos.y][pos.x];
+                self.neighbors(pos).filter(move |next| {
+                    constraint.can_move(current_elevation, self.elevations[next.y][next.x])
+                })
+            },
+            |&pos| goal.is_reached(pos, self),
+        );
+
+        path.map(|path| path.cost)
+    }
+}
+
+pub fn parse(input: &str) -> Result<HeightMap, ParseError> {
+    input.parse()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = HeightMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(hm: &Self::Input<'_>) -> usize {
+        hm.shortest_path(hm.start, ReachTargetPos(hm.end), ForwardMove)
+            .unwrap_or(0)
+    }
+
+    fn part2(hm: &Self::Input<'_>) -> usize {
+        hm.shortest_path(hm.end, ReachElevation(0), BackwardMove)
+            .unwrap_or(0)
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day12::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day12::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::{CharIndices, FromStr};
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int(l), Self::Int(r)) => l.cmp(r),
+            (Self::List(l), Self::List(r)) => l.cmp(r),
+            (Self::Int(l), Self::List(_)) => Self::List(vec![Self::Int(*l)]).cmp(other),
+            (Self::List(_), Self::Int(r)) => self.cmp(&Self::List(vec![Self::Int(*r)])),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse(s: &str, chars: &mut Peekable<CharIndices>) -> Result<Packet, ParseError> {
+            match chars.peek().copied() {
+                Some((_, '[')) => {
+                    chars.next();
+                    let mut list = Vec::new();
+                    while let Some(&(_, c)) = chars.peek() {
+                        if c == ']' {
+                            chars.next();
+                            return Ok(Packet::List(list));
+                        }
+                        if c == ',' {
+                            chars.next();
+                        } else {
+                            list.push(parse(s, chars)?);
+                        }
+                    }
+                    Err(ParseError::end(s, "a closing bracket"))
+                }
+                Some((start, c)) if c.is_ascii_digit() => {
+                    let mut end = start;
+                    while let Some(&(i, c)) = chars.peek().filter(|(_, c)| c.is_ascii_digit()) {
+                        end = i + c.len_utf8();
+                        chars.next();
+                    }
+                    aoc_parse::number(s, &s[start..end]).map(Packet::Int)
+                }
+                Some((i, c)) => Err(ParseError::new(
+                    s,
+                    &s[i..i + c.len_utf8()],
+                    "a list or an integer",
+                )),
+                None => Err(ParseError::end(s, "a packet")),
+            }
+        }
+
+        let mut chars = s.char_indices().peekable();
+        let packet = parse(s, &mut chars)?;
+        match chars.peek() {
+            Some(&(i, _)) => Err(ParseError::new(s, &s[i..], "the end of the packet")),
+            None => Ok(packet),
+        }
+    }
+}
+
+struct PacketPair<'a> {
+    index: usize,
+    left: &'a Packet,
+    right: &'a Packet,
+}
+
+impl<'a> PacketPair<'a> {
+    fn new(index: usize, left: &'a Packet, right: &'a Packet) -> Self {
+        Self { index, left, right }
+    }
+
+    fn is_ordered_correctly(&self) -> bool {
+        self.left <= self.right
+    }
+}
+
+pub struct TwoSixDecoder;
+
+impl TwoSixDecoder {
+    pub fn decode(&self, packets: &[Packet]) -> usize {
+        let d2 = Packet::List(vec![Packet::List(vec![Packet::Int(2)])]);
+        let d6 = Packet::List(vec![Packet::List(vec![Packet::Int(6)])]);
+
+        let i2 = packets.iter().filter(|&p| p < &d2).count() + 1;
+        let i6 = packets.iter().filter(|&p| p < &d6).count() + 2;
+
+        i2 * i6
+    }
+}
+
+pub struct PairOrderValidator;
+
+impl PairOrderValidator {
+    pub fn sum_ordered_indices(&self, packets: &[Packet]) -> usize {
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter_map(|(i, chunk)| {
+                let pair = PacketPair::new(i + 1, &chunk[0], &chunk[1]);
+                pair.is_ordered_correctly().then_some(pair.index)
+            })
+            .sum()
+    }
+}
+
+pub struct Signal {
+    packets: Vec<Packet>,
+}
+
+impl Signal {
+    pub fn packets(&self) -> &[Packet] {
+        &self.packets
+    }
+}
+
+impl FromStr for Signal {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let packets = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.parse::<Packet>().within(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { packets })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Signal, ParseError> {
+    Signal::from_str(input)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Signal;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(signal: &Self::Input<'_>) -> usize {
+        PairOrderValidator.sum_ordered_indices(signal.packets())
+    }
+
+    fn part2(signal: &Self::Input<'_>) -> usize {
+        TwoSixDecoder.decode(signal.packets())
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day13::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day13::part2(&parse(input).expect("Failed to parse input"))
+}
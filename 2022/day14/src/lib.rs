@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_geometry::Vec2;
+use aoc_grid::{Grid, Pos};
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+use aoc_visualize::Simulation;
+
+/// Scans are a few hundred cells across; anything past this isn't a scan,
+/// and tracing it would take forever.
+const MAX_COORDINATE: i32 = 1_000;
+
+pub type Point = Vec2<i32>;
+
+/// Every point on the horizontal or vertical line from `a` to `b`.
+fn points_between(a: Point, b: Point) -> impl Iterator<Item = Point> {
+    let min_x = a.x.min(b.x);
+    let max_x = a.x.max(b.x);
+    let min_y = a.y.min(b.y);
+    let max_y = a.y.max(b.y);
+
+    (min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| Point::new(x, y)))
+}
+
+fn parse_point(s: &str) -> Result<Point, ParseError> {
+    let point: Point = s.parse()?;
+    for (coordinate, token) in [point.x, point.y].into_iter().zip(s.split(',')) {
+        if !(0..=MAX_COORDINATE).contains(&coordinate) {
+            return Err(ParseError::new(
+                s,
+                token,
+                format!("a coordinate up to {}", MAX_COORDINATE),
+            ));
+        }
+    }
+    Ok(point)
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    occupied: HashSet<Point>,
+    max_y: i32,
+    sand_source: Option<Point>,
+    floor: Option<i32>,
+    path: Vec<Point>,
+}
+
+impl FromStr for Cave {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut occupied = HashSet::new();
+        let mut max_y = 0;
+
+        for line in input.lines() {
+            let points: Vec<Point> = line
+                .split(" -> ")
+                .map(|p| parse_point(p).within(input, p))
+                .collect::<Result<_, _>>()?;
+
+            for window in points.windows(2) {
+                if window[0].x != window[1].x && window[0].y != window[1].y {
+                    return Err(ParseError::new(input, line, "horizontal or vertical lines"));
+                }
+                for p in points_between(window[0], window[1]) {
+                    max_y = max_y.max(p.y);
+                    occupied.insert(p);
+                }
+            }
+        }
+
+        Ok(Self {
+            occupied,
+            max_y,
+            sand_source: None,
+            floor: None,
+            path: Vec::new(),
+        })
+    }
+}
+
+impl Cave {
+    pub fn with_source(mut self, source: Point) -> Self {
+        self.sand_source = Some(source);
+        self
+    }
+
+    pub fn with_floor(mut self, offset: i32) -> Self {
+        self.floor = Some(self.max_y + offset);
+        self
+    }
+
+    #[inline]
+    fn is_blocked(&self, x: i32, y: i32) -> bool {
+        self.occupied.contains(&Point::new(x, y)) || self.floor == Some(y)
+    }
+
+    pub fn drop_sand(&mut self) -> Option<Point> {
+        let source = self.sand_source.expect("Sand source must be configured");
+
+        if self.path.is_empty() {
+            if self.is_blocked(source.x, source.y) {
+                return None;
+            }
+            self.path.push(source);
+        }
+
+        while let Some(&curr) = self.path.last() {
+            if self.floor.is_none() && curr.y >= self.max_y {
+                return None;
+            }
+
+            let next_y = curr.y + 1;
+            let next_x = if !self.is_blocked(curr.x, next_y) {
+                Some(curr.x)
+            } else if !self.is_blocked(curr.x - 1, next_y) {
+                Some(curr.x - 1)
+            } else if !self.is_blocked(curr.x + 1, next_y) {
+                Some(curr.x + 1)
+            } else {
+                None
+            };
+
+            match next_x {
+                Some(x) => {
+                    self.path.push(Point::new(x, next_y));
+                }
+                None => {
+                    let settled = self.path.pop().unwrap();
+                    self.occupied.insert(settled);
+                    return Some(settled);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Pours sand into the cave a grain a frame, showing the rock, the settled
+/// sand and the path the last grain fell along.
+pub struct Pouring {
+    cave: Cave,
+    rock: HashSet<Point>,
+}
+
+impl Simulation for Pouring {
+    fn frame(&self) -> Grid<char> {
+        let source = self.cave.sand_source.unwrap_or(Point::new(500, 0));
+        let (min_x, max_x) = self
+            .cave
+            .occupied
+            .iter()
+            .fold((source.x, source.x), |(lo, hi), p| {
+                (lo.min(p.x), hi.max(p.x))
+            });
+        let max_y = self.cave.floor.unwrap_or(self.cave.max_y);
+
+        // Leave a column either side for sand to fall past the rock.
+        let origin = Point::new(min_x - 1, 0);
+        let cell = |p: Point| Pos::new((p.x - origin.x) as usize, (p.y - origin.y) as usize);
+        let mut frame = Grid::new((max_x - min_x + 3) as usize, (max_y + 1) as usize, '.');
+        if let Some(floor) = self.cave.floor {
+            for x in 0..frame.width() {
+                frame[Pos::new(x, floor as usize)] = '#';
+            }
+        }
+        for &p in &self.cave.path {
+            frame[cell(p)] = '~';
+        }
+        for &p in &self.cave.occupied {
+            frame[cell(p)] = if self.rock.contains(&p) { '#' } else { 'o' };
+        }
+        frame[cell(source)] = '+';
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        self.cave.drop_sand().is_some()
+    }
+}
+
+/// Sand poured into the cave, onto the floor for part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let mut cave = parse(input)?.with_source(Point::new(500, 0));
+    if part == 2 {
+        cave = cave.with_floor(2);
+    }
+    let rock = cave.occupied.clone();
+    Ok(Box::new(Pouring { cave, rock }))
+}
+
+pub fn parse(input: &str) -> Result<Cave, ParseError> {
+    input.parse()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Cave;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(cave: &Self::Input<'_>) -> i32 {
+        let mut cave = cave.clone().with_source(Point::new(500, 0));
+        let mut count = 0;
+        while cave.drop_sand().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    fn part2(cave: &Self::Input<'_>) -> i32 {
+        let mut cave = cave.clone().with_source(Point::new(500, 0)).with_floor(2);
+        let mut count = 0;
+        while cave.drop_sand().is_some() {
+            count += 1;
+        }
+        count
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day14::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day14::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use aoc_intervals::{Interval, IntervalSet};
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+struct Sensor {
+    position: Point,
+    beacon: Point,
+    radius: i64,
+}
+
+impl Sensor {
+    /// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`. Coordinates
+    /// are read as `i32` so distances between them can't overflow.
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let rest = aoc_parse::strip_prefix(line, line, "Sensor at ")?;
+        let (position, beacon) = aoc_parse::split_once(line, rest, ": ")?;
+        let beacon = aoc_parse::strip_prefix(line, beacon, "closest beacon is at ")?;
+        let position = parse_point(line, position)?;
+        let beacon = parse_point(line, beacon)?;
+        let radius = (position.x - beacon.x).abs() + (position.y - beacon.y).abs();
+        Ok(Self {
+            position,
+            beacon,
+            radius,
+        })
+    }
+
+    fn covers(&self, point: Point) -> bool {
+        (self.position.x - point.x).abs() + (self.position.y - point.y).abs() <= self.radius
+    }
+
+    fn coverage_at_row(&self, y: i64) -> Option<Interval<i64>> {
+        let dy = (self.position.y - y).abs();
+        if dy <= self.radius {
+            let dx = self.radius - dy;
+            Some(Interval::inclusive(
+                self.position.x - dx,
+                self.position.x + dx,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// `x=2, y=18`, a slice of `line`.
+fn parse_point(line: &str, part: &str) -> Result<Point, ParseError> {
+    let (x, y) = aoc_parse::split_once(line, part, ", ")?;
+    let x = aoc_parse::strip_prefix(line, x, "x=")?;
+    let y = aoc_parse::strip_prefix(line, y, "y=")?;
+    Ok(Point {
+        x: aoc_parse::number::<i32>(line, x)?.into(),
+        y: aoc_parse::number::<i32>(line, y)?.into(),
+    })
+}
+
+pub struct SensorSystem {
+    sensors: Vec<Sensor>,
+}
+
+impl SensorSystem {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let sensors = input
+            .lines()
+            .map(|line| Sensor::from_line(line).within(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { sensors })
+    }
+
+    pub fn count_impossible_positions(&self, target_row: i64) -> i64 {
+        let covered: IntervalSet<i64> = self
+            .sensors
+            .iter()
+            .filter_map(|s| s.coverage_at_row(target_row))
+            .collect();
+
+        let beacons_in_coverage = self
+            .sensors
+            .iter()
+            .filter(|s| s.beacon.y == target_row)
+            .map(|s| s.beacon.x)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|&x| covered.contains(x))
+            .count() as i64;
+
+        covered.len() - beacons_in_coverage
+    }
+
+    pub fn find_distress_beacon_frequency(&self, max_coord: i64) -> Option<i64> {
+        let mut pos_coeffs = Vec::with_capacity(self.sensors.len() * 2);
+        let mut neg_coeffs = Vec::with_capacity(self.sensors.len() * 2);
+
+        for s in &self.sensors {
+            let d = s.radius + 1;
+            pos_coeffs.push(s.position.y - s.position.x + d);
+            pos_coeffs.push(s.position.y - s.position.x - d);
+            neg_coeffs.push(s.position.y + s.position.x + d);
+            neg_coeffs.push(s.position.y + s.position.x - d);
+        }
+
+        for &a in &pos_coeffs {
+            for &b in &neg_coeffs {
+                if (a + b) % 2 == 0 {
+                    let x = (b - a) / 2;
+                    let y = (a + b) / 2;
+
+                    if x >= 0 && x <= max_coord && y >= 0 && y <= max_coord {
+                        let candidate = Point { x, y };
+                        if self.sensors.iter().all(|s| !s.covers(candidate)) {
+                            return Some(x * 4_000_000 + y);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+pub fn parse(input: &str) -> Result<SensorSystem, ParseError> {
+    SensorSystem::new(input)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = SensorSystem;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(system: &Self::Input<'_>) -> i64 {
+        let target_row = if system.sensors.len() < 20 {
+            10
+        } else {
+            2000000
+        };
+        system.count_impossible_positions(target_row)
+    }
+
+    fn part2(system: &Self::Input<'_>) -> i64 {
+        let max_coord = if system.sensors.len() < 20 {
+            20
+        } else {
+            4000000
+        };
+        system
+            .find_distress_beacon_frequency(max_coord)
+            .unwrap_or(0)
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day15::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day15::part2(&parse(input).expect("Failed to parse input"))
+}
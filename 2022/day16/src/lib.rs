@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+const INFINITY: i32 = 1000;
+const MAX_TIME: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ValveId(usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ValveMask(u32);
+
+impl ValveMask {
+    fn new() -> Self {
+        Self(0)
+    }
+
+    fn set(&self, id: ValveId) -> Self {
+        Self(self.0 | (1 << id.0))
+    }
+
+    fn is_set(&self, id: ValveId) -> bool {
+        (self.0 & (1 << id.0)) != 0
+    }
+
+    fn as_usize(&self) -> usize {
+        self.0 as usize
+    }
+
+    fn complement(&self, max_mask: u32) -> Self {
+        Self((!self.0) & max_mask)
+    }
+}
+
+struct RawValveInfo<'a> {
+    name: &'a str,
+    flow_rate: i32,
+    neighbors: Vec<&'a str>,
+}
+
+struct ValveNode {
+    flow_rate: i32,
+    distances: Vec<i32>,
+}
+
+pub struct ValveNetwork {
+    nodes: Vec<ValveNode>,
+    start_id: ValveId,
+    flow_node_count: usize,
+}
+
+struct NetworkBuilder<'a> {
+    raw_data: Vec<RawValveInfo<'a>>,
+}
+
+impl<'a> NetworkBuilder<'a> {
+    /// Reads lines like `Valve BB has flow rate=13; tunnels lead to valves CC, AA`.
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let raw_data = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let end_of_line = &line[line.len()..];
+                let mut words = line.split_whitespace();
+
+                let name = words
+                    .nth(1)
+                    .ok_or_else(|| ParseError::new(input, end_of_line, "a valve name"))?;
+                let rate = words
+                    .nth(2)
+                    .ok_or_else(|| ParseError::new(input, end_of_line, "a flow rate"))?;
+                let rate = aoc_parse::strip_prefix(input, rate, "rate=")?;
+                let flow_rate = aoc_parse::number(input, rate.trim_end_matches(';'))?;
+
+                let neighbors: Vec<&str> = words.skip(4).map(|s| s.trim_end_matches(',')).collect();
+                if neighbors.is_empty() {
+                    return Err(ParseError::new(input, end_of_line, "a list of tunnels"));
+                }
+
+                Ok(RawValveInfo {
+                    name,
+                    flow_rate,
+                    neighbors,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !raw_data.iter().any(|v| v.name == "AA") {
+            return Err(ParseError::end(input, "a starting valve named AA"));
+        }
+
+        Ok(Self { raw_data })
+    }
+
+    fn build(self) -> ValveNetwork {
+        let mut all_names: Vec<&str> = self.raw_data.iter().map(|v| v.name).collect();
+        all_names.sort_unstable();
+
+        let name_to_idx: HashMap<&str, usize> =
+            all_names.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let n = all_names.len();
+        let dist_matrix = self.compute_distance_matrix(n, &name_to_idx);
+
+        let mut flow_valves: Vec<&RawValveInfo> =
+            self.raw_data.iter().filter(|v| v.flow_rate > 0).collect();
+        flow_valves.sort_unstable_by_key(|v| v.name);
+
+        let flow_node_count = flow_valves.len();
+
+        let nodes: Vec<ValveNode> = flow_valves
+            .iter()
+            .map(|valve| {
+                let distances =
+                    self.map_distances(valve.name, &flow_valves, &name_to_idx, &dist_matrix, n);
+                ValveNode {
+                    flow_rate: valve.flow_rate,
+                    distances,
+                }
+            })
+            .collect();
+
+        let start_distances = self.map_distances("AA", &flow_valves, &name_to_idx, &dist_matrix, n);
+
+        let mut final_nodes = nodes;
+        final_nodes.push(ValveNode {
+            flow_rate: 0,
+            distances: start_distances,
+        });
+
+        ValveNetwork {
+            nodes: final_nodes,
+            start_id: ValveId(flow_node_count),
+            flow_node_count,
+        }
+    }
+
+    fn compute_distance_matrix(&self, n: usize, name_to_idx: &HashMap<&str, usize>) -> Vec<i32> {
+        let mut dists = vec![INFINITY; n * n];
+        for i in 0..n {
+            dists[i * n + i] = 0;
+        }
+
+        for valve in &self.raw_data {
+            let u = name_to_idx[valve.name];
+            for &neighbor in &valve.neighbors {
+                if let Some(&v) = name_to_idx.get(neighbor) {
+                    Self::dist_matrix_set(&mut dists, u, v, n, 1);
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                let ik = i * n + k;
+                if dists[ik] >= INFINITY {
+                    continue;
+                }
+                for j in 0..n {
+                    let kj = k * n + j;
+                    let ij = i * n + j;
+                    dists[ij] = dists[ij].min(dists[ik] + dists[kj]);
+                }
+            }
+        }
+        dists
+    }
+
+    fn map_distances(
+        &self,
+        source: &str,
+        targets: &[&RawValveInfo],
+        name_to_idx: &HashMap<&str, usize>,
+        matrix: &[i32],
+        n: usize,
+    ) -> Vec<i32> {
+        let u = name_to_idx[source];
+        targets
+            .iter()
+            .map(|target| matrix[u * n + name_to_idx[target.name]])
+            .collect()
+    }
+
+    fn dist_matrix_set(matrix: &mut [i32], u: usize, v: usize, n: usize, val: i32) {
+        matrix[u * n + v] = val;
+    }
+}
+
+impl ValveNetwork {
+    fn mask_count(&self) -> usize {
+        1 << self.flow_node_count
+    }
+
+    fn max_mask(&self) -> u32 {
+        (self.mask_count() - 1) as u32
+    }
+
+    fn iter_masks(&self) -> impl Iterator<Item = ValveMask> {
+        (0..self.mask_count()).map(|m| ValveMask(m as u32))
+    }
+
+    /// Returns the most pressure that can be released within `time_limit`
+    /// minutes for every subset of opened valves.
+    pub fn solve_for_subsets(&self, time_limit: i32) -> Vec<i32> {
+        let mut max_pressures = vec![0; self.mask_count()];
+        // Memoization table: [valve_index][mask][time_remaining]
+        let time_dim = MAX_TIME + 1;
+        let mut memo = vec![-1; self.nodes.len() * self.mask_count() * time_dim];
+
+        self.dfs(
+            self.start_id,
+            time_limit,
+            ValveMask::new(),
+            0,
+            &mut max_pressures,
+            &mut memo,
+        );
+        self.sos_dp(max_pressures)
+    }
+
+    fn dfs(
+        &self,
+        u: ValveId,
+        time: i32,
+        mask: ValveMask,
+        pressure: i32,
+        max_pressures: &mut [i32],
+        memo: &mut [i32],
+    ) {
+        max_pressures[mask.as_usize()] = max_pressures[mask.as_usize()].max(pressure);
+
+        let time_dim = MAX_TIME + 1;
+        let memo_idx = (u.0 * self.mask_count() + mask.as_usize()) * time_dim + time as usize;
+        if memo[memo_idx] >= pressure {
+            return;
+        }
+        memo[memo_idx] = pressure;
+
+        let node = &self.nodes[u.0];
+        for (v_idx, &dist) in node.distances.iter().enumerate() {
+            let v = ValveId(v_idx);
+            if !mask.is_set(v) {
+                let time_left = time - dist - 1;
+                if time_left > 0 {
+                    let next_pressure = pressure + self.nodes[v.0].flow_rate * time_left;
+                    self.dfs(
+                        v,
+                        time_left,
+                        mask.set(v),
+                        next_pressure,
+                        max_pressures,
+                        memo,
+                    );
+                }
+            }
+        }
+    }
+
+    fn sos_dp(&self, mut f: Vec<i32>) -> Vec<i32> {
+        for i in 0..self.flow_node_count {
+            for mask in 0..self.mask_count() {
+                if (mask & (1 << i)) != 0 {
+                    f[mask] = f[mask].max(f[mask ^ (1 << i)]);
+                }
+            }
+        }
+        f
+    }
+}
+
+pub fn parse(input: &str) -> Result<ValveNetwork, ParseError> {
+    Ok(NetworkBuilder::parse(input)?.build())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = ValveNetwork;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(network: &Self::Input<'_>) -> i32 {
+        let subset_pressures = network.solve_for_subsets(30);
+        *subset_pressures.iter().max().unwrap_or(&0)
+    }
+
+    fn part2(network: &Self::Input<'_>) -> i32 {
+        let subset_pressures = network.solve_for_subsets(26);
+
+        let max_mask = network.max_mask();
+        network
+            .iter_masks()
+            .map(|mask| {
+                let p1 = subset_pressures[mask.as_usize()];
+                let p2 = subset_pressures[mask.complement(max_mask).as_usize()];
+                p1 + p2
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    parse(input).map(|network| Day16::part1(&network))
+}
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    parse(input).map(|network| Day16::part2(&network))
+}
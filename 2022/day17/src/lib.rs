@@ -0,0 +1,315 @@
+use aoc_cycle::Detector;
+use aoc_geometry::Vec2;
+use aoc_grid::{Grid, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use aoc_visualize::Simulation;
+use std::collections::HashSet;
+
+const CHAMBER_WIDTH: i64 = 7;
+const INITIAL_X_OFFSET: i64 = 2;
+const INITIAL_Y_OFFSET: i64 = 4;
+/// How many rows of the top of the tower a visualization shows.
+const VIEW_HEIGHT: i64 = 40;
+
+type Point = Vec2<i64>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            _ => Err(format!("Invalid direction: {}", c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RockType {
+    HorizontalBar,
+    Plus,
+    L,
+    VerticalBar,
+    Square,
+}
+
+const ROCKS: [RockType; 5] = [
+    RockType::HorizontalBar,
+    RockType::Plus,
+    RockType::L,
+    RockType::VerticalBar,
+    RockType::Square,
+];
+
+impl RockType {
+    fn coords(&self) -> &'static [Point] {
+        match self {
+            RockType::HorizontalBar => &[
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 0 },
+                Point { x: 2, y: 0 },
+                Point { x: 3, y: 0 },
+            ],
+            RockType::Plus => &[
+                Point { x: 1, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 1, y: 1 },
+                Point { x: 2, y: 1 },
+                Point { x: 1, y: 2 },
+            ],
+            RockType::L => &[
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 0 },
+                Point { x: 2, y: 0 },
+                Point { x: 2, y: 1 },
+                Point { x: 2, y: 2 },
+            ],
+            RockType::VerticalBar => &[
+                Point { x: 0, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 0, y: 2 },
+                Point { x: 0, y: 3 },
+            ],
+            RockType::Square => &[
+                Point { x: 0, y: 0 },
+                Point { x: 1, y: 0 },
+                Point { x: 0, y: 1 },
+                Point { x: 1, y: 1 },
+            ],
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+struct State {
+    rock_type: RockType,
+    jet_idx: usize,
+    relative_heights: [i64; CHAMBER_WIDTH as usize],
+}
+
+pub struct Chamber {
+    stopped_rocks: HashSet<Point>,
+    max_height: i64,
+    jet_idx: usize,
+}
+
+impl Chamber {
+    pub fn new() -> Self {
+        Self {
+            stopped_rocks: HashSet::new(),
+            max_height: 0,
+            jet_idx: 0,
+        }
+    }
+}
+
+impl Default for Chamber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Chamber {
+    fn is_colliding(&self, pos: Point, rock_coords: &[Point]) -> bool {
+        rock_coords.iter().any(|&delta| {
+            let p = pos + delta;
+            !(0..CHAMBER_WIDTH).contains(&p.x) || p.y <= 0 || self.stopped_rocks.contains(&p)
+        })
+    }
+
+    pub fn simulate(&mut self, jets: &[Direction], num_rocks: i64) -> i64 {
+        // The tower height after each rock, so it can be extrapolated once
+        // the jets, the next rock and the top of the tower line up again.
+        let mut heights = Vec::new();
+        let mut detector = Detector::new();
+        for i in 0..num_rocks {
+            let rock_type = ROCKS[(i % 5) as usize];
+
+            heights.push(self.max_height);
+            if let Some(cycle) = detector.record(self.state(rock_type)) {
+                return cycle.extrapolate(&heights, num_rocks as usize);
+            }
+
+            let mut curr_pos = self.spawn_point();
+            while !self.push_and_fall(jets, &mut curr_pos, rock_type.coords()) {}
+        }
+
+        self.max_height
+    }
+
+    /// What decides how the rest of the tower is built: the rock about to
+    /// fall, the next jet and the shape of the top of the tower.
+    fn state(&self, rock_type: RockType) -> State {
+        let mut rel_h = [0i64; CHAMBER_WIDTH as usize];
+        for (x, h) in rel_h.iter_mut().enumerate() {
+            let mut depth = 0;
+            while depth < 100 {
+                if self
+                    .stopped_rocks
+                    .contains(&Point::new(x as i64, self.max_height - depth))
+                {
+                    break;
+                }
+                depth += 1;
+            }
+            *h = depth;
+        }
+
+        State {
+            rock_type,
+            jet_idx: self.jet_idx,
+            relative_heights: rel_h,
+        }
+    }
+
+    /// Where the next rock appears, by its bottom left corner.
+    fn spawn_point(&self) -> Point {
+        Point::new(INITIAL_X_OFFSET, self.max_height + INITIAL_Y_OFFSET)
+    }
+
+    /// Pushes the rock at `pos` with the next jet, then lets it fall a unit.
+    /// Returns true once it has come to rest on the tower.
+    fn push_and_fall(
+        &mut self,
+        jets: &[Direction],
+        pos: &mut Point,
+        rock_coords: &[Point],
+    ) -> bool {
+        let jet = jets[self.jet_idx];
+        self.jet_idx = (self.jet_idx + 1) % jets.len();
+
+        let next_x = match jet {
+            Direction::Left => pos.x - 1,
+            Direction::Right => pos.x + 1,
+        };
+
+        if !self.is_colliding(Point::new(next_x, pos.y), rock_coords) {
+            pos.x = next_x;
+        }
+
+        let next_y = pos.y - 1;
+        if self.is_colliding(Point::new(pos.x, next_y), rock_coords) {
+            for &delta in rock_coords {
+                let p = *pos + delta;
+                self.stopped_rocks.insert(p);
+                self.max_height = self.max_height.max(p.y);
+            }
+            true
+        } else {
+            pos.y = next_y;
+            false
+        }
+    }
+}
+
+/// Drops rocks into the chamber a jet push at a time, showing the top of the
+/// tower with the falling rock in it.
+pub struct Tower {
+    chamber: Chamber,
+    jets: Vec<Direction>,
+    rocks: i64,
+    falling: Point,
+}
+
+impl Simulation for Tower {
+    fn frame(&self) -> Grid<char> {
+        let rock_coords = ROCKS[(self.rocks % 5) as usize].coords();
+        let top = self.chamber.max_height + INITIAL_Y_OFFSET + 3;
+        let width = CHAMBER_WIDTH as usize + 2;
+
+        let mut frame = Grid::new(width, VIEW_HEIGHT as usize, ' ');
+        for (row, y) in (top - VIEW_HEIGHT + 1..=top).rev().enumerate() {
+            if y < 0 {
+                continue;
+            }
+            for x in -1..=CHAMBER_WIDTH {
+                let p = Point::new(x, y);
+                frame[Pos::new((x + 1) as usize, row)] = match (x, y) {
+                    (-1 | CHAMBER_WIDTH, 0) => '+',
+                    (_, 0) => '-',
+                    (-1 | CHAMBER_WIDTH, _) => '|',
+                    _ if self.chamber.stopped_rocks.contains(&p) => '#',
+                    _ if rock_coords.iter().any(|&d| self.falling + d == p) => '@',
+                    _ => '.',
+                };
+            }
+        }
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        let rock_coords = ROCKS[(self.rocks % 5) as usize].coords();
+        if self
+            .chamber
+            .push_and_fall(&self.jets, &mut self.falling, rock_coords)
+        {
+            self.rocks += 1;
+            self.falling = self.chamber.spawn_point();
+        }
+        self.rocks < 2022
+    }
+}
+
+/// The first 2022 rocks falling. Part 2 drops the same rocks, just far more
+/// of them, so both parts show the same thing.
+pub fn simulate(input: &str, _part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let jets = parse(input)?;
+    let chamber = Chamber::new();
+    Ok(Box::new(Tower {
+        falling: chamber.spawn_point(),
+        chamber,
+        jets,
+        rocks: 0,
+    }))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let pattern = input.trim();
+    if pattern.is_empty() {
+        return Err(ParseError::end(input, "a jet pattern of '<' and '>'"));
+    }
+    let start = input.len() - input.trim_start().len();
+    pattern
+        .char_indices()
+        .map(|(i, c)| {
+            Direction::try_from(c)
+                .map_err(|_| ParseError::at(input, start + i, c.len_utf8(), "'<' or '>'"))
+        })
+        .collect()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<Direction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(jets: &Self::Input<'_>) -> i64 {
+        Chamber::new().simulate(jets, 2022)
+    }
+
+    fn part2(jets: &Self::Input<'_>) -> i64 {
+        Chamber::new().simulate(jets, 1_000_000_000_000)
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day17::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day17::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,155 @@
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+use aoc_geometry::Vec3;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub type Point = Vec3<i32>;
+
+pub trait Neighborhood {
+    fn neighbors(&self, p: Point) -> Box<dyn Iterator<Item = Point>>;
+}
+
+#[derive(Default)]
+pub struct Orthogonal;
+impl Neighborhood for Orthogonal {
+    fn neighbors(&self, p: Point) -> Box<dyn Iterator<Item = Point>> {
+        let directions = [
+            Point::new(1, 0, 0),
+            Point::new(-1, 0, 0),
+            Point::new(0, 1, 0),
+            Point::new(0, -1, 0),
+            Point::new(0, 0, 1),
+            Point::new(0, 0, -1),
+        ];
+        Box::new(directions.into_iter().map(move |d| p + d))
+    }
+}
+
+struct BoundingBox {
+    min: Point,
+    max: Point,
+}
+
+impl BoundingBox {
+    fn contains(&self, p: Point) -> bool {
+        p.x >= self.min.x
+            && p.x <= self.max.x
+            && p.y >= self.min.y
+            && p.y <= self.max.y
+            && p.z >= self.min.z
+            && p.z <= self.max.z
+    }
+}
+
+pub struct Droplet<N: Neighborhood> {
+    cubes: HashSet<Point>,
+    neighborhood: N,
+}
+
+impl<N: Neighborhood + Default> FromStr for Droplet<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cubes = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.parse().within(s, line))
+            .collect::<Result<HashSet<Point>, _>>()?;
+        Ok(Self {
+            cubes,
+            neighborhood: N::default(),
+        })
+    }
+}
+
+impl<N: Neighborhood> Droplet<N> {
+    pub fn total_surface_area(&self) -> usize {
+        self.count_faces(|n| !self.cubes.contains(&n))
+    }
+
+    pub fn exterior_surface_area(&self) -> usize {
+        let bounds = match self.get_flood_bounds() {
+            Some(b) => b,
+            None => return 0,
+        };
+
+        let mut outside = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        outside.insert(bounds.min);
+        queue.push_back(bounds.min);
+
+        while let Some(curr) = queue.pop_front() {
+            for next in self.neighborhood.neighbors(curr) {
+                if bounds.contains(next) && !self.cubes.contains(&next) && outside.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        self.count_faces(|n| outside.contains(&n))
+    }
+
+    fn count_faces<F>(&self, predicate: F) -> usize
+    where
+        F: Fn(Point) -> bool,
+    {
+        self.cubes
+            .iter()
+            .flat_map(|c| self.neighborhood.neighbors(*c))
+            .filter(|&n| predicate(n))
+            .count()
+    }
+
+    fn get_flood_bounds(&self) -> Option<BoundingBox> {
+        if self.cubes.is_empty() {
+            return None;
+        }
+
+        let min_x = self.cubes.iter().map(|c| c.x).min()?;
+        let max_x = self.cubes.iter().map(|c| c.x).max()?;
+        let min_y = self.cubes.iter().map(|c| c.y).min()?;
+        let max_y = self.cubes.iter().map(|c| c.y).max()?;
+        let min_z = self.cubes.iter().map(|c| c.z).min()?;
+        let max_z = self.cubes.iter().map(|c| c.z).max()?;
+
+        Some(BoundingBox {
+            min: Point::new(min_x - 1, min_y - 1, min_z - 1),
+            max: Point::new(max_x + 1, max_y + 1, max_z + 1),
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Droplet<Orthogonal>, ParseError> {
+    input.parse()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Droplet<Orthogonal>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(droplet: &Self::Input<'_>) -> usize {
+        droplet.total_surface_area()
+    }
+
+    fn part2(droplet: &Self::Input<'_>) -> usize {
+        droplet.exterior_surface_area()
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day18::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day18::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,70 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|l| aoc_parse::number(input, l.trim()))
+        .collect()
+}
+
+/// Mixes the encrypted file `rounds` times after applying the decryption key
+/// and returns the sum of the grove coordinates.
+pub fn solve(numbers: &[i64], key: i64, rounds: usize) -> i64 {
+    let n = numbers.len();
+    let mut list: Vec<(usize, i64)> = numbers.iter().map(|&v| v * key).enumerate().collect();
+
+    for _ in 0..rounds {
+        for i in 0..n {
+            let current_pos = list
+                .iter()
+                .position(|&(original_idx, _)| original_idx == i)
+                .unwrap();
+
+            let item = list.remove(current_pos);
+            let val = item.1;
+
+            let new_pos = (current_pos as i64 + val).rem_euclid(n as i64 - 1);
+            list.insert(new_pos as usize, item);
+        }
+    }
+
+    let zero_pos = list.iter().position(|&(_, val)| val == 0).unwrap();
+
+    let v1 = list[(zero_pos + 1000) % n].1;
+    let v2 = list[(zero_pos + 2000) % n].1;
+    let v3 = list[(zero_pos + 3000) % n].1;
+
+    v1 + v2 + v3
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> i64 {
+        solve(numbers, 1, 1)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> i64 {
+        solve(numbers, DECRYPTION_KEY, 10)
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day20::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day20::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+const ROOT: &str = "root";
+const HUMN: &str = "humn";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Monkey(String);
+
+impl From<&str> for Monkey {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl AsRef<str> for Monkey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl TryFrom<char> for Operator {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '+' => Ok(Self::Add),
+            '-' => Ok(Self::Sub),
+            '*' => Ok(Self::Mul),
+            '/' => Ok(Self::Div),
+            _ => Err(format!("Unknown operator: {}", c)),
+        }
+    }
+}
+
+impl Operator {
+    fn apply(&self, left: i64, right: i64) -> i64 {
+        match self {
+            Self::Add => left + right,
+            Self::Sub => left - right,
+            Self::Mul => left * right,
+            Self::Div => left / right,
+        }
+    }
+
+    fn inverse_left(&self, target: i64, right_val: i64) -> i64 {
+        match self {
+            Self::Add => target - right_val,
+            Self::Sub => target + right_val,
+            Self::Mul => target / right_val,
+            Self::Div => target * right_val,
+        }
+    }
+
+    fn inverse_right(&self, target: i64, left_val: i64) -> i64 {
+        match self {
+            Self::Add => target - left_val,
+            Self::Sub => left_val - target,
+            Self::Mul => target / left_val,
+            Self::Div => left_val / target,
+        }
+    }
+}
+
+enum Job {
+    Number(i64),
+    Operation(Monkey, Operator, Monkey),
+}
+
+impl FromStr for Job {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(num) = s.parse::<i64>() {
+            Ok(Job::Number(num))
+        } else {
+            let mut parts = s.split_whitespace();
+            let left = aoc_parse::next(s, s, &mut parts, "a number or a monkey")?;
+            let op_str = aoc_parse::next(s, s, &mut parts, "an operator")?;
+            let right = aoc_parse::next(s, s, &mut parts, "a monkey")?;
+            if let Some(extra) = parts.next() {
+                return Err(ParseError::new(s, extra, "the end of the line"));
+            }
+
+            let op = match op_str.chars().collect::<Vec<_>>()[..] {
+                [c] => Operator::try_from(c).ok(),
+                _ => None,
+            }
+            .ok_or_else(|| ParseError::new(s, op_str, "+, -, * or /"))?;
+            Ok(Job::Operation(Monkey::from(left), op, Monkey::from(right)))
+        }
+    }
+}
+
+struct MonkeyEntry {
+    name: Monkey,
+    job: Job,
+}
+
+impl FromStr for MonkeyEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, job) = aoc_parse::split_once(s, s, ": ")?;
+        let job = Job::from_str(job).within(s, job)?;
+        Ok(Self {
+            name: Monkey::from(name),
+            job,
+        })
+    }
+}
+
+pub struct Monkeys(HashMap<Monkey, Job>);
+
+impl FromStr for Monkeys {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = s
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| {
+                let entry = MonkeyEntry::from_str(l).within(s, l)?;
+                Ok((entry.name, entry.job))
+            })
+            .collect::<Result<HashMap<_, _>, ParseError>>()?;
+        Ok(Self(map))
+    }
+}
+
+impl Monkeys {
+    pub fn evaluate(&self, name: &Monkey) -> i64 {
+        match self
+            .0
+            .get(name)
+            .unwrap_or_else(|| panic!("Monkey not found: {}", name.as_ref()))
+        {
+            Job::Number(n) => *n,
+            Job::Operation(left, op, right) => {
+                let left_val = self.evaluate(left);
+                let right_val = self.evaluate(right);
+                op.apply(left_val, right_val)
+            }
+        }
+    }
+
+    fn has_humn(&self, name: &Monkey) -> bool {
+        if name.as_ref() == HUMN {
+            return true;
+        }
+        match self
+            .0
+            .get(name)
+            .unwrap_or_else(|| panic!("Monkey not found: {}", name.as_ref()))
+        {
+            Job::Number(_) => false,
+            Job::Operation(left, _, right) => self.has_humn(left) || self.has_humn(right),
+        }
+    }
+
+    fn solve_for(&self, name: &Monkey, target: i64) -> i64 {
+        if name.as_ref() == HUMN {
+            return target;
+        }
+
+        match self
+            .0
+            .get(name)
+            .unwrap_or_else(|| panic!("Monkey not found: {}", name.as_ref()))
+        {
+            Job::Number(_) => panic!(
+                "Reached constant while solving for human path: {}",
+                name.as_ref()
+            ),
+            Job::Operation(left, op, right) => {
+                if self.has_humn(left) {
+                    let right_val = self.evaluate(right);
+                    let new_target = op.inverse_left(target, right_val);
+                    self.solve_for(left, new_target)
+                } else {
+                    let left_val = self.evaluate(left);
+                    let new_target = op.inverse_right(target, left_val);
+                    self.solve_for(right, new_target)
+                }
+            }
+        }
+    }
+
+    pub fn get_yell_number(&self) -> i64 {
+        let (left, right) = match self
+            .0
+            .get(&Monkey::from(ROOT))
+            .expect("Root monkey not found")
+        {
+            Job::Operation(l, _, r) => (l, r),
+            _ => panic!("Root monkey must have an operation job"),
+        };
+
+        if self.has_humn(left) {
+            let target = self.evaluate(right);
+            self.solve_for(left, target)
+        } else {
+            let target = self.evaluate(left);
+            self.solve_for(right, target)
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Monkeys, ParseError> {
+    Monkeys::from_str(input)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Monkeys;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(monkeys: &Self::Input<'_>) -> i64 {
+        monkeys.evaluate(&Monkey::from(ROOT))
+    }
+
+    fn part2(monkeys: &Self::Input<'_>) -> i64 {
+        monkeys.get_yell_number()
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day21::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day21::part2(&parse(input).expect("Failed to parse input"))
+}
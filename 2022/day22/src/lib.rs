@@ -0,0 +1,366 @@
+use aoc_grid::Direction;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+enum Instruction {
+    Move(i32),
+    Left,
+    Right,
+}
+
+impl Instruction {
+    /// Parses the path, a run of step counts and `L`/`R` turns, where `path`
+    /// is a slice of `input`.
+    fn parse_all(input: &str, path: &str) -> Result<Vec<Self>, ParseError> {
+        let path = path.trim_end();
+        let mut result = Vec::new();
+        let mut rest = path;
+        while let Some(c) = rest.chars().next() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (instruction, len) = match c {
+                'L' => (Instruction::Left, 1),
+                'R' => (Instruction::Right, 1),
+                '0'..='9' => (
+                    Instruction::Move(aoc_parse::number(input, &rest[..len])?),
+                    len,
+                ),
+                _ => {
+                    let found = &rest[..c.len_utf8()];
+                    return Err(ParseError::new(
+                        input,
+                        found,
+                        "a number of steps, 'L' or 'R'",
+                    ));
+                }
+            };
+            result.push(instruction);
+            rest = &rest[len..];
+        }
+        Ok(result)
+    }
+}
+
+struct Board {
+    grid: Vec<Vec<char>>,
+    height: usize,
+}
+
+impl Board {
+    /// Parses the map, where `map` is a slice of `input`.
+    fn parse(input: &str, map: &str) -> Result<Self, ParseError> {
+        if let Some(i) = map.find(|c| !matches!(c, ' ' | '.' | '#' | '\n')) {
+            return Err(ParseError::at(map, i, 1, "a tile (' ', '.' or '#')").within(input, map));
+        }
+        let grid: Vec<Vec<char>> = map.lines().map(|line| line.chars().collect()).collect();
+        if !grid.first().is_some_and(|row| row.contains(&'.')) {
+            return Err(ParseError::new(
+                input,
+                map,
+                "an open tile on the top row to start from",
+            ));
+        }
+        let height = grid.len();
+        Ok(Board { grid, height })
+    }
+
+    fn get(&self, r: usize, c: usize) -> char {
+        self.grid
+            .get(r)
+            .and_then(|row| row.get(c))
+            .copied()
+            .unwrap_or(' ')
+    }
+
+    fn is_valid(&self, r: isize, c: isize) -> bool {
+        if r < 0 || r >= self.height as isize {
+            return false;
+        }
+        let row = &self.grid[r as usize];
+        if c < 0 || c >= row.len() as isize {
+            return false;
+        }
+        row[c as usize] != ' '
+    }
+}
+
+trait WrappingStrategy {
+    fn next_pos(
+        &self,
+        board: &Board,
+        r: usize,
+        c: usize,
+        f: Direction,
+    ) -> (usize, usize, Direction);
+}
+
+trait FromBoard: WrappingStrategy {
+    fn from_board(board: &Board) -> Self;
+}
+
+struct FlatWrapping;
+impl WrappingStrategy for FlatWrapping {
+    fn next_pos(
+        &self,
+        board: &Board,
+        r: usize,
+        c: usize,
+        f: Direction,
+    ) -> (usize, usize, Direction) {
+        let (dr, dc) = f.row_col();
+        let nr = r as isize + dr;
+        let nc = c as isize + dc;
+
+        if board.is_valid(nr, nc) {
+            return (nr as usize, nc as usize, f);
+        }
+
+        match f {
+            Direction::East => {
+                let nc = board.grid[r].iter().position(|&ch| ch != ' ').unwrap();
+                (r, nc, f)
+            }
+            Direction::West => {
+                let nc = board.grid[r].iter().rposition(|&ch| ch != ' ').unwrap();
+                (r, nc, f)
+            }
+            Direction::South => {
+                let nr = (0..board.height)
+                    .find(|&row_idx| board.get(row_idx, c) != ' ')
+                    .unwrap();
+                (nr, c, f)
+            }
+            Direction::North => {
+                let nr = (0..board.height)
+                    .rev()
+                    .find(|&row_idx| board.get(row_idx, c) != ' ')
+                    .unwrap();
+                (nr, c, f)
+            }
+        }
+    }
+}
+
+impl FromBoard for FlatWrapping {
+    fn from_board(_: &Board) -> Self {
+        FlatWrapping
+    }
+}
+
+struct CubeWrapping {
+    face_size: usize,
+}
+
+impl FromBoard for CubeWrapping {
+    fn from_board(board: &Board) -> Self {
+        let total_tiles: usize = board
+            .grid
+            .iter()
+            .map(|row| row.iter().filter(|&&ch| ch == '.' || ch == '#').count())
+            .sum();
+        let face_size = ((total_tiles / 6) as f64).sqrt() as usize;
+        CubeWrapping { face_size }
+    }
+}
+
+impl CubeWrapping {
+    fn wrap_example(
+        &self,
+        _board: &Board,
+        r: usize,
+        c: usize,
+        f: Direction,
+    ) -> (usize, usize, Direction) {
+        let s = self.face_size;
+        let face_r = r / s;
+        let face_c = c / s;
+        let y = r % s;
+        let x = c % s;
+
+        let (nr, nc, nf) = match (face_r, face_c, f) {
+            // Face 1: (0,2)
+            (0, 2, Direction::North) => (s, s - 1 - x, Direction::South),
+            (0, 2, Direction::West) => (s, s + y, Direction::South),
+            (0, 2, Direction::East) => (3 * s - 1 - y, 4 * s - 1, Direction::West),
+            // Face 2: (1,0)
+            (1, 0, Direction::North) => (0, 3 * s - 1 - x, Direction::South),
+            (1, 0, Direction::South) => (3 * s - 1, 3 * s - 1 - x, Direction::North),
+            (1, 0, Direction::West) => (3 * s - 1, 4 * s - 1 - y, Direction::North),
+            // Face 3: (1,1)
+            (1, 1, Direction::North) => (x, 2 * s, Direction::East),
+            (1, 1, Direction::South) => (3 * s - 1 - x, 2 * s, Direction::East),
+            // Face 4: (1,2)
+            (1, 2, Direction::East) => (2 * s, 4 * s - 1 - y, Direction::South),
+            // Face 5: (2,2)
+            (2, 2, Direction::South) => (2 * s - 1, s - 1 - x, Direction::North),
+            (2, 2, Direction::West) => (2 * s - 1, 2 * s - 1 - y, Direction::North),
+            // Face 6: (2,3)
+            (2, 3, Direction::North) => (2 * s - 1 - x, 3 * s - 1, Direction::West),
+            (2, 3, Direction::South) => (2 * s - 1 - x, 0, Direction::East),
+            (2, 3, Direction::East) => (s - 1 - y, 3 * s - 1, Direction::West),
+            _ => unreachable!("Invalid wrap for example: ({}, {}, {:?})", r, c, f),
+        };
+        (nr, nc, nf)
+    }
+
+    fn wrap_input(
+        &self,
+        _board: &Board,
+        r: usize,
+        c: usize,
+        f: Direction,
+    ) -> (usize, usize, Direction) {
+        let s = self.face_size;
+        let face_r = r / s;
+        let face_c = c / s;
+        let y = r % s;
+        let x = c % s;
+
+        let (nr, nc, nf) = match (face_r, face_c, f) {
+            // Face 1: (0,1)
+            (0, 1, Direction::North) => (3 * s + x, 0, Direction::East),
+            (0, 1, Direction::West) => (3 * s - 1 - y, 0, Direction::East),
+            // Face 2: (0,2)
+            (0, 2, Direction::North) => (4 * s - 1, x, Direction::North),
+            (0, 2, Direction::South) => (s + x, 2 * s - 1, Direction::West),
+            (0, 2, Direction::East) => (3 * s - 1 - y, 2 * s - 1, Direction::West),
+            // Face 3: (1,1)
+            (1, 1, Direction::West) => (2 * s, y, Direction::South),
+            (1, 1, Direction::East) => (s - 1, 2 * s + y, Direction::North),
+            // Face 4: (2,1)
+            (2, 1, Direction::South) => (3 * s + x, s - 1, Direction::West),
+            (2, 1, Direction::East) => (s - 1 - y, 3 * s - 1, Direction::West),
+            // Face 5: (2,0)
+            (2, 0, Direction::North) => (s + x, s, Direction::East),
+            (2, 0, Direction::West) => (s - 1 - y, s, Direction::East),
+            // Face 6: (3,0)
+            (3, 0, Direction::South) => (0, 2 * s + x, Direction::South),
+            (3, 0, Direction::West) => (0, s + y, Direction::South),
+            (3, 0, Direction::East) => (3 * s - 1, s + y, Direction::North),
+            _ => unreachable!("Invalid wrap for input: ({}, {}, {:?})", r, c, f),
+        };
+        (nr, nc, nf)
+    }
+}
+
+impl WrappingStrategy for CubeWrapping {
+    fn next_pos(
+        &self,
+        board: &Board,
+        r: usize,
+        c: usize,
+        f: Direction,
+    ) -> (usize, usize, Direction) {
+        let (dr, dc) = f.row_col();
+        let nr = r as isize + dr;
+        let nc = c as isize + dc;
+
+        if board.is_valid(nr, nc) {
+            return (nr as usize, nc as usize, f);
+        }
+
+        if self.face_size == 4 {
+            self.wrap_example(board, r, c, f)
+        } else {
+            self.wrap_input(board, r, c, f)
+        }
+    }
+}
+
+struct Navigator {
+    r: usize,
+    c: usize,
+    f: Direction,
+}
+
+impl Navigator {
+    fn new(board: &Board) -> Self {
+        let r = 0;
+        let c = board.grid[0].iter().position(|&ch| ch == '.').unwrap();
+        let f = Direction::East;
+        Navigator { r, c, f }
+    }
+
+    fn follow_instructions(
+        &mut self,
+        board: &Board,
+        instructions: &[Instruction],
+        strategy: &impl WrappingStrategy,
+    ) {
+        for inst in instructions {
+            match inst {
+                Instruction::Left => self.f = self.f.turn_left(),
+                Instruction::Right => self.f = self.f.turn_right(),
+                Instruction::Move(steps) => {
+                    for _ in 0..*steps {
+                        let (nr, nc, nf) = strategy.next_pos(board, self.r, self.c, self.f);
+                        if board.grid[nr][nc] == '#' {
+                            break;
+                        }
+                        self.r = nr;
+                        self.c = nc;
+                        self.f = nf;
+                    }
+                }
+            }
+        }
+    }
+
+    fn password(&self) -> i32 {
+        // Facing is scored clockwise from east
+        let facing = (self.f.index() + 3) % 4;
+        1000 * (self.r as i32 + 1) + 4 * (self.c as i32 + 1) + facing as i32
+    }
+}
+
+/// The monkeys' map together with the path to follow across it.
+pub struct Notes {
+    board: Board,
+    instructions: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Result<Notes, ParseError> {
+    let (map_part, path_part) = aoc_parse::split_once(input, input, "\n\n")?;
+    Ok(Notes {
+        board: Board::parse(input, map_part)?,
+        instructions: Instruction::parse_all(input, path_part)?,
+    })
+}
+
+fn solve<S: FromBoard>(notes: &Notes) -> i32 {
+    let strategy = S::from_board(&notes.board);
+
+    let mut navigator = Navigator::new(&notes.board);
+    navigator.follow_instructions(&notes.board, &notes.instructions, &strategy);
+
+    navigator.password()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Notes;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(notes: &Self::Input<'_>) -> i32 {
+        solve::<FlatWrapping>(notes)
+    }
+
+    fn part2(notes: &Self::Input<'_>) -> i32 {
+        solve::<CubeWrapping>(notes)
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day22::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day22::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,290 @@
+use aoc_grid::{Grid, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use aoc_visualize::Simulation;
+
+const OFFSET: i32 = 1100;
+const GRID_SIZE: usize = 2500;
+
+#[derive(Clone)]
+struct Proposal {
+    target_idx: usize,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ProposalState {
+    None,
+    Conflict,
+    Source(u32),
+}
+
+#[derive(Clone)]
+pub struct Solver {
+    elves: Vec<(i32, i32)>,
+    grid: Vec<u8>,
+    proposals_grid: Vec<ProposalState>,
+    successful_proposals: Vec<Proposal>,
+    elf_proposals: Vec<Option<usize>>, // Stores target_idx for each elf
+}
+
+impl Solver {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut elves = Vec::new();
+        let mut grid = vec![0u8; GRID_SIZE * GRID_SIZE];
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                if c == '#' {
+                    // Leave as much room past the far edge as OFFSET leaves
+                    // before the near one for the elves to spread into.
+                    let px = x as i32 + OFFSET;
+                    let py = y as i32 + OFFSET;
+                    if px as usize >= GRID_SIZE - OFFSET as usize
+                        || py as usize >= GRID_SIZE - OFFSET as usize
+                    {
+                        return Err(ParseError::new(input, &line[i..i + 1], "a smaller grove"));
+                    }
+
+                    elves.push((px, py));
+                    grid[py as usize * GRID_SIZE + px as usize] = 1;
+                }
+            }
+        }
+
+        let elf_count = elves.len();
+        Ok(Self {
+            elves,
+            grid,
+            proposals_grid: vec![ProposalState::None; GRID_SIZE * GRID_SIZE],
+            successful_proposals: Vec::with_capacity(elf_count),
+            elf_proposals: vec![None; elf_count],
+        })
+    }
+
+    #[inline(always)]
+    fn has_elf(&self, x: i32, y: i32) -> bool {
+        self.grid[y as usize * GRID_SIZE + x as usize] == 1
+    }
+
+    #[inline(always)]
+    fn get_neighbor_mask(&self, x: i32, y: i32) -> u8 {
+        let mut mask = 0u8;
+        // [NW, N, NE, W, E, SW, S, SE]
+        if self.has_elf(x - 1, y - 1) {
+            mask |= 1 << 7;
+        }
+        if self.has_elf(x, y - 1) {
+            mask |= 1 << 6;
+        }
+        if self.has_elf(x + 1, y - 1) {
+            mask |= 1 << 5;
+        }
+        if self.has_elf(x - 1, y) {
+            mask |= 1 << 4;
+        }
+        if self.has_elf(x + 1, y) {
+            mask |= 1 << 3;
+        }
+        if self.has_elf(x - 1, y + 1) {
+            mask |= 1 << 2;
+        }
+        if self.has_elf(x, y + 1) {
+            mask |= 1 << 1;
+        }
+        if self.has_elf(x + 1, y + 1) {
+            mask |= 1 << 0;
+        }
+        mask
+    }
+
+    pub fn simulate_round(&mut self, round: usize) -> bool {
+        self.collect_proposals(round);
+        let moved = self.apply_moves();
+        self.reset_proposals();
+        moved
+    }
+
+    fn collect_proposals(&mut self, round: usize) {
+        self.successful_proposals.clear();
+        let directions = ['N', 'S', 'W', 'E'];
+        let start_dir = round % 4;
+
+        for (idx, &(x, y)) in self.elves.iter().enumerate() {
+            self.elf_proposals[idx] = None;
+            let mask = self.get_neighbor_mask(x, y);
+
+            if mask == 0 {
+                continue;
+            }
+
+            for i in 0..4 {
+                let dir = directions[(start_dir + i) % 4];
+                let (is_valid, tx, ty) = match dir {
+                    // All N bits NW, N, NE
+                    'N' => (mask & 0b11100000 == 0, x, y - 1),
+                    // All S bits SW, S, SE
+                    'S' => (mask & 0b00000111 == 0, x, y + 1),
+                    // All W bits NW, W, SW
+                    'W' => (mask & 0b10010100 == 0, x - 1, y),
+                    // All E bits NE, E, SE
+                    'E' => (mask & 0b00101001 == 0, x + 1, y),
+                    _ => unreachable!(),
+                };
+
+                if is_valid {
+                    let tidx = ty as usize * GRID_SIZE + tx as usize;
+                    match self.proposals_grid[tidx] {
+                        ProposalState::None => {
+                            self.proposals_grid[tidx] = ProposalState::Source(idx as u32);
+                            self.successful_proposals
+                                .push(Proposal { target_idx: tidx });
+                        }
+                        ProposalState::Source(_) => {
+                            self.proposals_grid[tidx] = ProposalState::Conflict;
+                        }
+                        ProposalState::Conflict => {}
+                    }
+                    self.elf_proposals[idx] = Some(tidx);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn apply_moves(&mut self) -> bool {
+        let mut moved = false;
+        for (idx, (ox, oy)) in self.elves.iter_mut().enumerate() {
+            if let Some(tidx) = self.elf_proposals[idx] {
+                if self.proposals_grid[tidx] == ProposalState::Source(idx as u32) {
+                    // Update grid
+                    self.grid[*oy as usize * GRID_SIZE + *ox as usize] = 0;
+                    self.grid[tidx] = 1;
+
+                    // Update Elf position
+                    *ox = (tidx % GRID_SIZE) as i32;
+                    *oy = (tidx / GRID_SIZE) as i32;
+                    moved = true;
+                }
+            }
+        }
+        moved
+    }
+
+    fn reset_proposals(&mut self) {
+        for prop in &self.successful_proposals {
+            self.proposals_grid[prop.target_idx] = ProposalState::None;
+        }
+    }
+}
+
+/// The elves spreading out a round a frame, over all the ground they have
+/// covered so far so the picture doesn't jump around as they move.
+pub struct Spreading {
+    solver: Solver,
+    round: usize,
+    rounds: Option<usize>,
+    min: (i32, i32),
+    max: (i32, i32),
+}
+
+impl Spreading {
+    fn cover_elves(&mut self) {
+        for &(x, y) in &self.solver.elves {
+            self.min = (self.min.0.min(x), self.min.1.min(y));
+            self.max = (self.max.0.max(x), self.max.1.max(y));
+        }
+    }
+}
+
+impl Simulation for Spreading {
+    fn frame(&self) -> Grid<char> {
+        let width = (self.max.0 - self.min.0 + 1).max(0) as usize;
+        let height = (self.max.1 - self.min.1 + 1).max(0) as usize;
+        let mut frame = Grid::new(width, height, '.');
+        for &(x, y) in &self.solver.elves {
+            frame[Pos::new((x - self.min.0) as usize, (y - self.min.1) as usize)] = '#';
+        }
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        if self.rounds.is_some_and(|rounds| self.round >= rounds) {
+            return false;
+        }
+        let moved = self.solver.simulate_round(self.round);
+        self.round += 1;
+        self.cover_elves();
+        moved
+    }
+}
+
+/// The first ten rounds for part 1, and every round until no elf moves for
+/// part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let mut spreading = Spreading {
+        solver: parse(input)?,
+        round: 0,
+        rounds: (part == 1).then_some(10),
+        min: (i32::MAX, i32::MAX),
+        max: (i32::MIN, i32::MIN),
+    };
+    spreading.cover_elves();
+    Ok(Box::new(spreading))
+}
+
+pub fn parse(input: &str) -> Result<Solver, ParseError> {
+    Solver::new(input)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Solver;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(solver: &Self::Input<'_>) -> i32 {
+        let mut solver = solver.clone();
+        for round in 0..10 {
+            solver.simulate_round(round);
+        }
+
+        let mut min_x = i32::MAX;
+        let mut max_x = i32::MIN;
+        let mut min_y = i32::MAX;
+        let mut max_y = i32::MIN;
+
+        for &(x, y) in &solver.elves {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+
+        (width * height) - solver.elves.len() as i32
+    }
+
+    fn part2(solver: &Self::Input<'_>) -> i32 {
+        let mut solver = solver.clone();
+        let mut round = 0;
+        while solver.simulate_round(round) {
+            round += 1;
+        }
+        round as i32 + 1
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day23::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day23::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,159 @@
+use aoc_math::number::lcm;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Point {
+    r: i32,
+    c: i32,
+}
+
+impl Point {
+    fn new(r: i32, c: i32) -> Self {
+        Self { r, c }
+    }
+
+    fn neighbors(&self) -> [Point; 5] {
+        [
+            Point::new(self.r, self.c),     // Wait
+            Point::new(self.r - 1, self.c), // Up
+            Point::new(self.r + 1, self.c), // Down
+            Point::new(self.r, self.c - 1), // Left
+            Point::new(self.r, self.c + 1), // Right
+        ]
+    }
+}
+
+pub struct BlizzardBasin {
+    width: usize,
+    height: usize,
+    cycle: usize,
+    /// Precomputed blizzard occupancy: [time % cycle][row][col]
+    occupied: Vec<bool>,
+}
+
+impl BlizzardBasin {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let valley = aoc_grid::Grid::parse_with(input.trim(), |c| {
+            matches!(c, '#' | '.' | '>' | '<' | 'v' | '^').then_some(c)
+        })?;
+        if valley.width() < 3 || valley.height() < 3 {
+            return Err(ParseError::end(input, "a valley inside the walls"));
+        }
+        let height = valley.height() - 2;
+        let width = valley.width() - 2;
+        let cycle = lcm(width, height);
+
+        let mut initial_blizzards = Vec::new();
+        for (r, line) in input.trim().lines().enumerate().skip(1).take(height) {
+            for (c, char) in line.chars().enumerate().skip(1).take(width) {
+                match char {
+                    '.' => {}
+                    '#' => {
+                        return Err(ParseError::at(line, c, 1, "open ground or a blizzard")
+                            .within(input, line))
+                    }
+                    _ => initial_blizzards.push((r - 1, c - 1, char)),
+                }
+            }
+        }
+
+        let mut occupied = vec![false; cycle * height * width];
+        for t in 0..cycle {
+            for &(r, c, dir) in &initial_blizzards {
+                let (nr, nc) = match dir {
+                    '>' => (r, (c + t) % width),
+                    '<' => (r, (c + width - (t % width)) % width),
+                    'v' => ((r + t) % height, c),
+                    '^' => ((height + r - (t % height)) % height, c),
+                    _ => unreachable!(),
+                };
+                occupied[t * height * width + nr * width + nc] = true;
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cycle,
+            occupied,
+        })
+    }
+
+    /// Checks if a blizzard occupies the given coordinate at a specific time.
+    fn has_blizzard(&self, p: Point, t: usize) -> bool {
+        if !self.in_valley(p) {
+            return false;
+        }
+        let t_mod = t % self.cycle;
+        self.occupied[t_mod * self.height * self.width + p.r as usize * self.width + p.c as usize]
+    }
+
+    fn in_valley(&self, p: Point) -> bool {
+        p.r >= 0 && p.r < self.height as i32 && p.c >= 0 && p.c < self.width as i32
+    }
+
+    /// Finds the shortest path from start to end beginning at start_time.
+    fn shortest_path(&self, start: Point, end: Point, start_time: usize) -> Option<usize> {
+        // The blizzards repeat every cycle, so a state only needs the time modulo it
+        let path = aoc_search::bfs(
+            [(start, start_time % self.cycle)],
+            |&(p, t)| {
+                let next_t = (t + 1) % self.cycle;
+                p.neighbors().into_iter().filter_map(move |next_p| {
+                    // Valid moves: staying at start/end, or moving into the valley if no blizzard
+                    let is_start_or_end = next_p == start || next_p == end;
+                    ((is_start_or_end || self.in_valley(next_p))
+                        && !self.has_blizzard(next_p, next_t))
+                    .then_some((next_p, next_t))
+                })
+            },
+            |&(p, _)| p == end,
+        )?;
+
+        Some(start_time + path.cost)
+    }
+}
+
+pub fn parse(input: &str) -> Result<BlizzardBasin, ParseError> {
+    BlizzardBasin::new(input)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = BlizzardBasin;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(basin: &Self::Input<'_>) -> usize {
+        let start = Point::new(-1, 0);
+        let end = Point::new(basin.height as i32, (basin.width - 1) as i32);
+        basin.shortest_path(start, end, 0).unwrap_or(0)
+    }
+
+    fn part2(basin: &Self::Input<'_>) -> usize {
+        let start = Point::new(-1, 0);
+        let end = Point::new(basin.height as i32, (basin.width - 1) as i32);
+
+        let t1 = basin.shortest_path(start, end, 0).expect("No path to goal");
+        let t2 = basin
+            .shortest_path(end, start, t1)
+            .expect("No path back to start");
+        basin
+            .shortest_path(start, end, t2)
+            .expect("No path to goal again")
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day24::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day24::part2(&parse(input).expect("Failed to parse input"))
+}
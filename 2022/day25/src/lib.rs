@@ -0,0 +1,79 @@
+use aoc_parse::{ParseError, Within};
+use aoc_solution::{NoPuzzle, Solution};
+
+/// Returns the decimal value of every fuel requirement.
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .map(|line| snafu_to_decimal(line).within(input, line))
+        .collect()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = String;
+    type Answer2 = NoPuzzle;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(requirements: &Self::Input<'_>) -> String {
+        decimal_to_snafu(requirements.iter().sum())
+    }
+
+    fn part2(_: &Self::Input<'_>) -> NoPuzzle {
+        NoPuzzle
+    }
+}
+
+pub fn part1(input: &str) -> String {
+    Day25::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn snafu_to_decimal(snafu: &str) -> Result<i64, ParseError> {
+    snafu.char_indices().try_fold(0i64, |acc, (i, c)| {
+        let digit = match c {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => {
+                return Err(ParseError::at(
+                    snafu,
+                    i,
+                    1,
+                    "a SNAFU digit ('2', '1', '0', '-' or '=')",
+                ))
+            }
+        };
+        acc.checked_mul(5)
+            .and_then(|acc| acc.checked_add(digit))
+            .ok_or_else(|| ParseError::new(snafu, snafu, "a SNAFU number that fits in 64 bits"))
+    })
+}
+
+pub fn decimal_to_snafu(mut n: i64) -> String {
+    if n == 0 {
+        return "0".to_string();
+    }
+    let mut res = String::new();
+    while n > 0 {
+        let (digit, carry) = match n % 5 {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            4 => ('-', 1),
+            _ => unreachable!(),
+        };
+        res.push(digit);
+        n = n / 5 + carry;
+    }
+    res.chars().rev().collect()
+}
@@ -0,0 +1,168 @@
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> u32 {
+        let mut sum = 0;
+
+        lines.iter().for_each(|line| {
+            let c1 = line.chars().find(|ch| ch.is_ascii_digit());
+            let c2 = line.chars().rev().find(|ch| ch.is_ascii_digit());
+
+            let d1 = match c1 {
+                Some(c) => c.to_digit(10).unwrap(),
+                None => 0,
+            };
+
+            let d2 = match c2 {
+                Some(c) => c.to_digit(10).unwrap(),
+                None => 0,
+            };
+
+            sum += d1 * 10 + d2;
+        });
+
+        return sum;
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> u32 {
+        let mut sum = 0;
+        lines.iter().for_each(|line| {
+            sum += get_number_from_string(line);
+        });
+        return sum;
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    return Day01::part1(&parse(input).expect("Failed to parse input"));
+}
+
+pub fn part2(input: &str) -> u32 {
+    return Day01::part2(&parse(input).expect("Failed to parse input"));
+}
+
+/// Returns the lines of the calibration document, which may only hold
+/// lowercase letters and digits once any indentation is trimmed.
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    return input
+        .lines()
+        .map(|line| parse_line(line.trim()).within(input, line.trim()))
+        .collect();
+}
+
+fn parse_line(line: &str) -> Result<String, ParseError> {
+    let invalid = line
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_lowercase() && !c.is_ascii_digit());
+    if let Some((i, c)) = invalid {
+        return Err(ParseError::at(
+            line,
+            i,
+            c.len_utf8(),
+            "a lowercase letter or a digit",
+        ));
+    }
+    return Ok(line.to_string());
+}
+
+fn check_pattern(mut iter: impl Iterator<Item = char>, pattern: &str, value: u32) -> Option<u32> {
+    for expected_char in pattern.chars() {
+        if let Some(ch) = iter.next() {
+            if ch != expected_char {
+                return None;
+            }
+        } else {
+            return None;
+        }
+    }
+
+    iter.next(); // Consume the last character of the pattern
+    return Some(value);
+}
+
+fn check_pattern_rev(
+    iter: &mut std::iter::Peekable<std::iter::Rev<std::str::Chars>>,
+    pattern: &str,
+    value: u32,
+) -> Option<u32> {
+    let mut iter_clone = iter.clone();
+    for expected_char in pattern.chars() {
+        if let Some(ch) = iter_clone.next() {
+            if ch != expected_char {
+                return None;
+            }
+        } else {
+            return None;
+        }
+    }
+
+    iter_clone.next(); // Consume the last character of the pattern
+    return Some(value);
+}
+
+fn get_number_from_string(line: &str) -> u32 {
+    let mut d1 = 0;
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if let Some(value) = match ch {
+            'o' => check_pattern(&mut chars.clone(), "ne", 1),
+            't' => check_pattern(&mut chars.clone(), "wo", 2)
+                .or_else(|| check_pattern(&mut chars.clone(), "hree", 3)),
+            'f' => check_pattern(&mut chars.clone(), "our", 4)
+                .or_else(|| check_pattern(&mut chars.clone(), "ive", 5)),
+            's' => check_pattern(&mut chars.clone(), "ix", 6)
+                .or_else(|| check_pattern(&mut chars.clone(), "even", 7)),
+            'e' => check_pattern(&mut chars.clone(), "ight", 8),
+            'n' => check_pattern(&mut chars.clone(), "ine", 9),
+            _ => None,
+        } {
+            d1 = value;
+            break;
+        }
+
+        if ch.is_ascii_digit() {
+            d1 = ch.to_digit(10).expect("couldn't get number");
+            break;
+        }
+    }
+
+    let mut d2 = 0;
+    let mut chars_rev = line.chars().rev().peekable();
+
+    while let Some(ch) = chars_rev.next() {
+        if let Some(value) = match ch {
+            'e' => check_pattern_rev(&mut chars_rev, "no", 1)
+                .or_else(|| check_pattern_rev(&mut chars_rev, "erht", 3))
+                .or_else(|| check_pattern_rev(&mut chars_rev, "vif", 5))
+                .or_else(|| check_pattern_rev(&mut chars_rev, "nin", 9)),
+            'o' => check_pattern_rev(&mut chars_rev, "wt", 2),
+            'r' => check_pattern_rev(&mut chars_rev, "uof", 4),
+            'x' => check_pattern_rev(&mut chars_rev, "is", 6),
+            'n' => check_pattern_rev(&mut chars_rev, "eves", 7),
+            't' => check_pattern_rev(&mut chars_rev, "hgie", 8),
+            _ => None,
+        } {
+            d2 = value;
+            break;
+        }
+
+        if ch.is_ascii_digit() {
+            d2 = ch.to_digit(10).expect("couldn't get number");
+            break;
+        }
+    }
+
+    return d1 * 10 + d2;
+}
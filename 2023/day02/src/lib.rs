@@ -0,0 +1,107 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::cmp::max;
+
+const RED_LIMIT: u32 = 12;
+const GREEN_LIMIT: u32 = 13;
+const BLUE_LIMIT: u32 = 14;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(games: &Self::Input<'_>) -> u32 {
+        return games
+            .iter()
+            .filter(|game| !game.sets.iter().any(RGB::exceeds_limit))
+            .map(|game| game.id)
+            .sum();
+    }
+
+    fn part2(games: &Self::Input<'_>) -> u32 {
+        return games
+            .iter()
+            .map(|game| {
+                let max_rgb = game
+                    .sets
+                    .iter()
+                    .fold(RGB::default(), |acc, x| get_max(&acc, x));
+                max_rgb.r * max_rgb.g * max_rgb.b
+            })
+            .sum();
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    return Day02::part1(&parse(input).expect("Failed to parse input"));
+}
+
+pub fn part2(input: &str) -> u32 {
+    return Day02::part2(&parse(input).expect("Failed to parse input"));
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    return input.lines().map(|line| get_game(input, line)).collect();
+}
+
+fn get_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (game, sets) = aoc_parse::split_once(input, line, ":")?;
+    let id = aoc_parse::strip_prefix(input, game, "Game ")?;
+    let id = aoc_parse::number(input, id.trim())?;
+    let sets = sets
+        .split(';')
+        .map(|set| get_rgb(input, set))
+        .collect::<Result<_, _>>()?;
+    return Ok(Game { id, sets });
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub sets: Vec<RGB>,
+}
+
+#[derive(Debug, Default)]
+pub struct RGB {
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+}
+
+impl RGB {
+    pub fn exceeds_limit(&self) -> bool {
+        return self.r > RED_LIMIT || self.g > GREEN_LIMIT || self.b > BLUE_LIMIT;
+    }
+}
+
+fn get_rgb(input: &str, set: &str) -> Result<RGB, ParseError> {
+    let mut rgb = RGB::default();
+    for roll in set.split(',') {
+        let mut words = roll.split_whitespace();
+        let amount = aoc_parse::next(input, roll, &mut words, "a cube count")?;
+        let amount = aoc_parse::number(input, amount)?;
+        match aoc_parse::next(input, roll, &mut words, "a colour")? {
+            "red" => rgb.r = amount,
+            "green" => rgb.g = amount,
+            "blue" => rgb.b = amount,
+            color => return Err(ParseError::new(input, color, "red, green or blue")),
+        };
+    }
+    return Ok(rgb);
+}
+
+fn get_max(a: &RGB, b: &RGB) -> RGB {
+    let rgb = RGB {
+        r: max(a.r, b.r),
+        g: max(a.g, b.g),
+        b: max(a.b, b.b),
+    };
+    return rgb;
+}
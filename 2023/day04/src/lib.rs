@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    return Game::from_str(input);
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(game: &Self::Input<'_>) -> usize {
+        return game.scratch_cards.iter().map(|x| x.points).sum();
+    }
+
+    fn part2(game: &Self::Input<'_>) -> usize {
+        let mut duplicates = vec![1_usize; game.scratch_cards.len()];
+        for index in 0..duplicates.len() {
+            let card = game.scratch_cards.get(index).expect("no cards?!");
+            for i in 1..=card.overlap_count {
+                duplicates[index + i] += duplicates[index];
+            }
+        }
+
+        return duplicates.iter().sum();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day04::part1(&Game::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day04::part2(&Game::new(input));
+}
+
+pub struct Game {
+    scratch_cards: Vec<ScratchCard>,
+}
+
+impl Game {
+    pub fn new(str: &str) -> Game {
+        return Game::from_str(str).expect("Ctor from string failed");
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let mut scratch_cards = Vec::new();
+        for line in str.lines() {
+            scratch_cards.push(ScratchCard::from_str(line).within(str, line)?);
+        }
+        return Ok(Game { scratch_cards });
+    }
+}
+
+#[derive(Clone)]
+struct ScratchCard {
+    overlap_count: usize,
+    points: usize,
+}
+
+impl FromStr for ScratchCard {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (card_str, numbers_str) = aoc_parse::split_once(str, str, ":")?;
+
+        let id_str = card_str.split_whitespace().nth(1).unwrap_or(card_str);
+        let _id = aoc_parse::number::<usize>(str, id_str)?;
+
+        let (winning_str, number_str) = aoc_parse::split_once(str, numbers_str, "|")?;
+        let winning_numbers: Vec<u32> = aoc_parse::numbers(str, winning_str)?;
+        let numbers: Vec<u32> = aoc_parse::numbers(str, number_str)?;
+
+        let overlap: Vec<u32> = numbers
+            .iter()
+            .filter(|&value| winning_numbers.contains(value))
+            .cloned()
+            .collect();
+
+        let points = match overlap.len() {
+            0 => 0,
+            len => 2_usize
+                .checked_pow((len - 1) as u32)
+                .ok_or_else(|| ParseError::new(str, number_str, "fewer matching numbers"))?,
+        };
+
+        return Ok(ScratchCard {
+            overlap_count: overlap.len(),
+            points,
+        });
+    }
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use aoc_intervals::{Interval, IntervalSet, PiecewiseMap};
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    return Almanac::from_str(input);
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Almanac;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> usize {
+        let seed_destinations = almanac.get_seed_destinations();
+        return seed_destinations
+            .into_iter()
+            .min()
+            .expect("there will be a map");
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> usize {
+        let seed_destinations = almanac.get_seed_range_destinations();
+
+        return seed_destinations.min().unwrap_or(0);
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day05::part1(&Almanac::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day05::part2(&Almanac::new(input));
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn new(str: &str) -> Almanac {
+        return Almanac::from_str(str).expect("Ctor from string failed");
+    }
+
+    pub fn get_seed_destinations(&self) -> Vec<usize> {
+        let mut destinations = Vec::new();
+        let mut seed_destination: usize;
+        for seed in self.seeds.iter() {
+            seed_destination = *seed;
+            for map in self.maps.iter() {
+                seed_destination = map.get_map_destination(seed_destination);
+            }
+            destinations.push(seed_destination);
+        }
+
+        return destinations;
+    }
+
+    pub fn get_seed_range_destinations(&self) -> IntervalSet<usize> {
+        let mut seed_ranges: IntervalSet<usize> = self
+            .seeds
+            .chunks(2)
+            .map(|chunks| Interval::with_len(chunks[0], chunks[1]))
+            .collect();
+        for map in self.maps.iter() {
+            seed_ranges = map.get_map_destination_ranges(&seed_ranges);
+        }
+        return seed_ranges;
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        const MAPS: [&str; 7] = [
+            "a seed-to-soil map",
+            "a soil-to-fertilizer map",
+            "a fertilizer-to-water map",
+            "a water-to-light map",
+            "a light-to-temperature map",
+            "a temperature-to-humidity map",
+            "a humidity-to-location map",
+        ];
+
+        let mut blocks = aoc_parse::blocks(str);
+        let seed_str = aoc_parse::next(str, str, &mut blocks, "a list of seeds")?;
+        let (_, seed_numbers) = aoc_parse::split_once(str, seed_str, ":")?;
+        let seeds: Vec<usize> = aoc_parse::numbers(str, seed_numbers)?;
+
+        let maps = MAPS
+            .iter()
+            .map(|expected| {
+                let block = aoc_parse::next(str, str, &mut blocks, expected)?;
+                return Map::from_str(block).within(str, block);
+            })
+            .collect::<Result<Vec<Map>, ParseError>>()?;
+
+        if let Some(extra) = blocks.next() {
+            return Err(ParseError::new(str, extra, "the end of the almanac"));
+        }
+
+        return Ok(Almanac { seeds, maps });
+    }
+}
+
+struct Map {
+    source_to_destinations: PiecewiseMap<usize>,
+}
+
+impl Map {
+    pub fn get_map_destination(&self, start: usize) -> usize {
+        return self.source_to_destinations.map(start);
+    }
+
+    pub fn get_map_destination_ranges(
+        &self,
+        seed_ranges: &IntervalSet<usize>,
+    ) -> IntervalSet<usize> {
+        return self.source_to_destinations.map_set(seed_ranges);
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let mut source_to_destinations = PiecewiseMap::new();
+        // Skip 'seed-to-soil map:' name line
+        for line in str.lines().skip(1) {
+            let range = SourceToDestination::from_str(line).within(str, line)?;
+            source_to_destinations.push(
+                Interval::with_len(range.source_start, range.range_length),
+                range.destination_start,
+            );
+        }
+
+        return Ok(Map {
+            source_to_destinations,
+        });
+    }
+}
+
+struct SourceToDestination {
+    destination_start: usize,
+    source_start: usize,
+    range_length: usize,
+}
+
+impl FromStr for SourceToDestination {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        if let [destination_start, source_start, range_length] =
+            aoc_parse::numbers::<usize>(str, str)?[..]
+        {
+            return Ok(SourceToDestination {
+                destination_start,
+                source_start,
+                range_length,
+            });
+        }
+
+        return Err(ParseError::new(
+            str,
+            str,
+            "a destination start, source start and range length",
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Game, ParseError> {
+    return Game::from_str(input);
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(game: &Self::Input<'_>) -> usize {
+        let total_wins = game
+            .races
+            .iter()
+            .fold(1, |acc, e| acc * e.get_number_of_wins());
+        return total_wins;
+    }
+
+    fn part2(game: &Self::Input<'_>) -> usize {
+        return game.unkerned().get_number_of_wins();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day06::part1(&Game::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day06::part2(&Game::new(input));
+}
+
+#[derive(Debug)]
+pub struct Game {
+    races: Vec<Race>,
+}
+
+impl Game {
+    pub fn new(str: &str) -> Game {
+        return Game::from_str(str).expect("Ctor from string failed");
+    }
+
+    /// The single race read by ignoring the spaces between the numbers on each line.
+    fn unkerned(&self) -> Race {
+        return self.races.iter().fold(
+            Race {
+                time: 0,
+                distance: 0,
+            },
+            |acc, race| Race {
+                time: concat(acc.time, race.time),
+                distance: concat(acc.distance, race.distance),
+            },
+        );
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let mut lines = str.lines();
+        let times_line = aoc_parse::next(str, str, &mut lines, "a line of times")?;
+        let distances_line = aoc_parse::next(str, str, &mut lines, "a line of distances")?;
+
+        let (_, times_str) = aoc_parse::split_once(str, times_line, ":")?;
+        let (_, distances_str) = aoc_parse::split_once(str, distances_line, ":")?;
+        let times: Vec<usize> = aoc_parse::numbers(str, times_str)?;
+        let distances: Vec<usize> = aoc_parse::numbers(str, distances_str)?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::new(
+                str,
+                distances_str,
+                format!("{} distances", times.len()),
+            ));
+        }
+
+        let races: Vec<Race> = times
+            .iter()
+            .zip(distances.iter())
+            .map(|(&t, &d)| Race {
+                time: t,
+                distance: d,
+            })
+            .collect();
+
+        return Ok(Game { races });
+    }
+}
+
+/// Appends the decimal digits of `b` to `a`.
+fn concat(a: usize, b: usize) -> usize {
+    return a * 10_usize.pow(b.to_string().len() as u32) + b;
+}
+
+#[derive(Debug)]
+struct Race {
+    time: usize,
+    distance: usize,
+}
+
+impl Race {
+    pub fn get_number_of_wins(&self) -> usize {
+        let b = self.time as f32;
+        let c = self.distance as f32;
+
+        // Get the lower square root of
+        // x^2 - time*x+ distance = 0
+        let mut l = ((b - f32::sqrt(b * b - 4.0 * c)) / 2.0).ceil() as usize;
+
+        // We need to beat the current distance record
+        if (self.time - l) * l <= self.distance {
+            l += 1;
+        }
+        let mid = self.time / 2;
+        return match self.time % 2 {
+            0 => (mid - l + 1) * 2 - 1,
+            1 => (mid - l + 1) * 2,
+            _ => 0,
+        };
+    }
+}
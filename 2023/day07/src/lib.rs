@@ -0,0 +1,305 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse<T>(input: &str) -> Result<Game<T>, ParseError>
+where
+    T: FromStr<Err = ParseError> + fmt::Debug,
+{
+    return Game::from_str(input);
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Game<Hand>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(game: &Self::Input<'_>) -> usize {
+        let mut hands: Vec<&Hand> = game.hands.iter().collect();
+        hands.sort_by(|a, b| a.cmp(b));
+
+        let sum = hands
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(pos, e)| e.bet * (pos + 1))
+            .sum();
+        return sum;
+    }
+
+    fn part2(game: &Self::Input<'_>) -> usize {
+        let game = game.with_jokers();
+        let mut hands: Vec<&JHand> = game.hands.iter().collect();
+        hands.sort_by(|a, b| a.cmp(b));
+
+        let sum = hands
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(pos, e)| e.bet * (pos + 1))
+            .sum();
+        return sum;
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day07::part1(&Game::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day07::part2(&Game::new(input));
+}
+
+#[derive(Debug)]
+pub struct Game<T> {
+    hands: Vec<T>,
+}
+
+impl<T: FromStr<Err = ParseError>> Game<T>
+where
+    T: fmt::Debug,
+{
+    pub fn new(str: &str) -> Game<T> {
+        return Game::<T>::from_str(str).expect("Ctor from string failed");
+    }
+}
+
+impl Game<Hand> {
+    /// The same hands read with J as a joker.
+    pub fn with_jokers(&self) -> Game<JHand> {
+        let hands = self.hands.iter().map(JHand::from).collect();
+        return Game { hands };
+    }
+}
+
+impl<T: FromStr<Err = ParseError>> FromStr for Game<T>
+where
+    T: fmt::Debug,
+{
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let hands: Vec<T> = str
+            .lines()
+            .map(|x| T::from_str(x).within(str, x))
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Game { hands });
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    hand: String,
+    hand_type: HandType,
+    bet: usize,
+}
+
+impl Hand {
+    fn cmp(&self, other: &Hand) -> Ordering {
+        let hand_type_cmp = self.hand_type.cmp(&other.hand_type);
+
+        if hand_type_cmp != Ordering::Equal {
+            return hand_type_cmp;
+        }
+
+        for (a, b) in self.hand.chars().zip(other.hand.chars()) {
+            let card_cmp = Hand::card_cmp(&a, &b);
+            if card_cmp != Ordering::Equal {
+                return card_cmp;
+            }
+        }
+
+        return Ordering::Equal;
+    }
+
+    fn card_cmp(a: &char, b: &char) -> Ordering {
+        const RANK: &str = "AKQJT98765432";
+        let a_index = RANK.find(*a);
+        let b_index = RANK.find(*b);
+        return a_index.cmp(&b_index);
+    }
+
+    pub fn get_hand_type(str: &str) -> HandType {
+        let mut char_counts = HashMap::new();
+
+        for c in str.chars() {
+            let count = char_counts.entry(c).or_insert(0);
+            *count += 1;
+        }
+
+        return match char_counts.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if let Some(4) = char_counts.values().max() {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if let Some(3) = char_counts.values().max() {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => HandType::HighCard,
+        };
+    }
+}
+
+/// Splits a line into its cards and its bet.
+fn split_hand(str: &str) -> Result<(&str, usize), ParseError> {
+    let mut parts = str.split_whitespace();
+    let hand = aoc_parse::next(str, str, &mut parts, "a hand of cards")?;
+    let bet = aoc_parse::number(str, aoc_parse::next(str, str, &mut parts, "a bet")?)?;
+    if let Some(extra) = parts.next() {
+        return Err(ParseError::new(str, extra, "the end of the line"));
+    }
+    return Ok((hand, bet));
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (hand, bet) = split_hand(str)?;
+        return Ok(Hand {
+            hand: hand.to_string(),
+            hand_type: Hand::get_hand_type(hand),
+            bet,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct JHand {
+    hand: String,
+    hand_type: HandType,
+    bet: usize,
+}
+
+impl FromStr for JHand {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let (hand, bet) = split_hand(str)?;
+        return Ok(JHand {
+            hand: hand.to_string(),
+            hand_type: JHand::get_hand_type(hand),
+            bet,
+        });
+    }
+}
+
+impl From<&Hand> for JHand {
+    fn from(hand: &Hand) -> Self {
+        return JHand {
+            hand: hand.hand.clone(),
+            hand_type: JHand::get_hand_type(&hand.hand),
+            bet: hand.bet,
+        };
+    }
+}
+
+impl JHand {
+    fn cmp(&self, other: &JHand) -> Ordering {
+        let hand_type_cmp = self.hand_type.cmp(&other.hand_type);
+
+        if hand_type_cmp != Ordering::Equal {
+            return hand_type_cmp;
+        }
+
+        for (a, b) in self.hand.chars().zip(other.hand.chars()) {
+            let card_cmp = JHand::card_cmp(&a, &b);
+            if card_cmp != Ordering::Equal {
+                return card_cmp;
+            }
+        }
+
+        return Ordering::Equal;
+    }
+
+    fn card_cmp(a: &char, b: &char) -> Ordering {
+        const RANK: &str = "AKQT98765432J";
+        let a_index = RANK.find(*a);
+        let b_index = RANK.find(*b);
+        return a_index.cmp(&b_index);
+    }
+
+    pub fn get_hand_type(str: &str) -> HandType {
+        let mut char_counts = HashMap::new();
+
+        for c in str.chars() {
+            char_counts
+                .entry(c)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+
+        // Give the jokers to the highest current count
+        // and remove it from the set
+        if let Some(&joker_count) = char_counts.get(&'J') {
+            if char_counts.len() > 1 {
+                let (largest_key, _largest_value) = char_counts
+                    .iter()
+                    .filter(|(k, _)| *k != &'J')
+                    .max_by_key(|(_, &v)| v)
+                    .map(|(k, &v)| (*k, v))
+                    .unwrap_or(('J', 0));
+
+                char_counts
+                    .entry(largest_key)
+                    .and_modify(|count| *count += joker_count)
+                    .or_insert(joker_count);
+
+                char_counts.remove(&'J');
+            }
+        }
+
+        return match char_counts.len() {
+            1 => HandType::FiveOfAKind,
+            2 => {
+                if let Some(4) = char_counts.values().max() {
+                    HandType::FourOfAKind
+                } else {
+                    HandType::FullHouse
+                }
+            }
+            3 => {
+                if let Some(3) = char_counts.values().max() {
+                    HandType::ThreeOfAKind
+                } else {
+                    HandType::TwoPair
+                }
+            }
+            4 => HandType::OnePair,
+            5 => HandType::HighCard,
+            _ => HandType::HighCard,
+        };
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aoc_cycle::Cycle;
+use aoc_math::number::Congruence;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Network, ParseError> {
+    return Network::from_str(input);
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Network;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(network: &Self::Input<'_>) -> usize {
+        let path = network.get_path("AAA", "ZZZ");
+
+        return path.len() - 1;
+    }
+
+    fn part2(network: &Self::Input<'_>) -> Result<usize, &'static str> {
+        let path_length = network.get_ghost_path_length();
+        return path_length;
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day08::part1(&Network::new(input));
+}
+
+pub fn part2(input: &str) -> Result<usize, &'static str> {
+    return Day08::part2(&Network::new(input));
+}
+
+#[derive(Debug)]
+pub struct Network {
+    instructions: String,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    pub fn new(str: &str) -> Network {
+        return Network::from_str(str).expect("Ctor from string failed");
+    }
+
+    pub fn get_path(&self, start: &str, end: &str) -> Vec<String> {
+        let mut path = vec![start.to_string()];
+        let mut current = start;
+
+        let mut direction = self.instructions.chars().cycle();
+
+        while current != end {
+            let next_direction = direction.next().expect("direction not empty");
+            let (left, right) = match self.nodes.get(current) {
+                Some((left, right)) => (left, right),
+                None => break, // No valid path found, break out of the loop
+            };
+
+            current = match next_direction {
+                'L' => left,
+                'R' => right,
+                _ => panic!("path must continue"),
+            };
+            path.push(current.to_string());
+        }
+
+        return path;
+    }
+
+    pub fn get_ghost_path_length(&self) -> Result<usize, &'static str> {
+        let ghosts = self
+            .nodes
+            .keys()
+            .filter(|x| x.ends_with('A'))
+            .map(|start| self.get_ghost_visits(start))
+            .collect::<Option<Vec<_>>>();
+        let Some(ghosts) = ghosts else {
+            return Err("a ghost walks off the map");
+        };
+
+        // A step before some ghost has started going round its cycle has to
+        // be one of that ghost's early visits, so check those one by one.
+        let early = ghosts
+            .iter()
+            .flat_map(|(cycle, visits)| visits.iter().filter(|&&step| step < cycle.start))
+            .copied()
+            .filter(|&step| {
+                ghosts
+                    .iter()
+                    .all(|(cycle, visits)| visits.contains(&cycle.index(step)))
+            })
+            .min();
+
+        // After that every ghost is back on each end node of its cycle every
+        // cycle length, and the first step they all are is a CRT solution
+        // for one choice of end node per ghost, past all of those nodes.
+        let mut later = vec![Congruence::ANY];
+        for (cycle, visits) in &ghosts {
+            later = later
+                .iter()
+                .flat_map(|&steps| {
+                    visits
+                        .iter()
+                        .filter(|&&step| step >= cycle.start)
+                        .filter_map(move |&step| {
+                            steps.and(Congruence::new(step as i64, cycle.len as i64))
+                        })
+                })
+                .collect();
+        }
+        let later = later.iter().map(|steps| steps.first_from(0) as usize).min();
+
+        return early
+            .into_iter()
+            .chain(later)
+            .min()
+            .ok_or("the ghosts are never all on nodes ending in Z at once");
+    }
+
+    /// Follows the instructions from `start` until the ghost is back on a
+    /// node at the same point in the instructions, returning that cycle and
+    /// the steps before it closes on which the ghost is on a node ending in
+    /// Z. Returns `None` if the ghost walks off the map.
+    fn get_ghost_visits(&self, start: &str) -> Option<(Cycle, Vec<usize>)> {
+        let instructions = self.instructions.as_bytes();
+        let (cycle, history) = aoc_cycle::find(
+            (start, 0),
+            |&(node, i)| {
+                let (left, right) = self.nodes.get(node)?;
+                let next = if instructions[i] == b'L' { left } else { right };
+                return Some((next.as_str(), (i + 1) % instructions.len()));
+            },
+            |&state| state,
+        )?;
+
+        let visits = history
+            .iter()
+            .enumerate()
+            .filter(|(_, (node, _))| node.ends_with('Z'))
+            .map(|(step, _)| step)
+            .collect();
+        return Some((cycle, visits));
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let mut lines = str.lines();
+        let instructions = aoc_parse::next(str, str, &mut lines, "a line of instructions")?;
+        if instructions.is_empty() {
+            return Err(ParseError::new(str, instructions, "a line of instructions"));
+        }
+        if let Some((i, c)) = instructions
+            .char_indices()
+            .find(|&(_, c)| c != 'L' && c != 'R')
+        {
+            return Err(ParseError::new(
+                str,
+                &instructions[i..i + c.len_utf8()],
+                "L or R",
+            ));
+        }
+
+        let mut nodes = HashMap::new();
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let (node, map) = aoc_parse::split_once(str, line, "=")?;
+            let map = map.trim();
+            let pair = map
+                .strip_prefix('(')
+                .and_then(|m| m.strip_suffix(')'))
+                .ok_or_else(|| ParseError::new(str, map, "a pair of nodes in parentheses"))?;
+            let (left, right) = aoc_parse::split_once(str, pair, ",")?;
+            nodes.insert(
+                node.trim().to_string(),
+                (left.trim().to_string(), right.trim().to_string()),
+            );
+        }
+        return Ok(Network {
+            instructions: instructions.to_string(),
+            nodes,
+        });
+    }
+}
@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Oasis, ParseError> {
+    return Oasis::from_str(input);
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Oasis;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(oasis: &Self::Input<'_>) -> i32 {
+        let predictions = oasis.get_predictions();
+
+        return predictions.iter().sum();
+    }
+
+    fn part2(oasis: &Self::Input<'_>) -> i32 {
+        let predictions = oasis.get_left_predictions();
+        return predictions.iter().sum();
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    return Day09::part1(&Oasis::new(input));
+}
+
+pub fn part2(input: &str) -> i32 {
+    return Day09::part2(&Oasis::new(input));
+}
+
+#[derive(Debug)]
+pub struct Oasis {
+    reports: Vec<Vec<i32>>,
+}
+
+impl Oasis {
+    pub fn new(str: &str) -> Oasis {
+        return Oasis::from_str(str).expect("Ctor from string failed");
+    }
+
+    pub fn get_predictions(&self) -> Vec<i32> {
+        return self.reports.iter().map(Oasis::get_prediction).collect();
+    }
+
+    pub fn get_left_predictions(&self) -> Vec<i32> {
+        return self
+            .reports
+            .iter()
+            .map(Oasis::get_left_prediction)
+            .collect();
+    }
+
+    pub fn get_prediction(report: &Vec<i32>) -> i32 {
+        let pyramid: Vec<Vec<i32>> = Oasis::get_pyramid(report);
+        let sum: i32 = pyramid
+            .iter()
+            .map(|x| x.last().expect("has elements"))
+            .sum();
+        return report.last().expect("has elements") + sum;
+    }
+
+    pub fn get_left_prediction(report: &Vec<i32>) -> i32 {
+        let pyramid: Vec<Vec<i32>> = Oasis::get_pyramid(report);
+        let sum = pyramid
+            .iter()
+            .rev()
+            .fold(0, |acc, e| e.first().expect("has element") - acc);
+        return report.first().expect("has elements") - sum;
+    }
+
+    pub fn get_pyramid(report: &Vec<i32>) -> Vec<Vec<i32>> {
+        let mut pyramid: Vec<Vec<i32>> = Vec::new();
+
+        let mut current_line: &Vec<i32> = report;
+
+        while !current_line.iter().all(|x| *x == 0) {
+            let mut line = Vec::new();
+            for window in current_line.windows(2) {
+                let left = window[0];
+                let right = window[1];
+                line.push(right - left);
+            }
+            pyramid.push(line.clone());
+            current_line = pyramid.last().expect("line exists");
+        }
+
+        return pyramid;
+    }
+}
+
+impl FromStr for Oasis {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let reports: Vec<Vec<i32>> = str
+            .lines()
+            .map(|x| match aoc_parse::numbers(str, x)? {
+                report if report.is_empty() => Err(ParseError::new(str, x, "a report")),
+                report => Ok(report),
+            })
+            .collect::<Result<_, _>>()?;
+        return Ok(Oasis { reports });
+    }
+}
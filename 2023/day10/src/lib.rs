@@ -0,0 +1,235 @@
+use aoc_grid::{Grid, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<PipeMap, ParseError> {
+    return PipeMap::from_str(input);
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = PipeMap;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(pipe_map: &Self::Input<'_>) -> usize {
+        let loop_length = pipe_map.get_loop_length();
+        return loop_length;
+    }
+
+    fn part2(pipe_map: &Self::Input<'_>) -> i32 {
+        return pipe_map.get_loop_area();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day10::part1(&PipeMap::new(input));
+}
+
+pub fn part2(input: &str) -> i32 {
+    return Day10::part2(&PipeMap::new(input));
+}
+
+#[derive(Debug)]
+pub struct PipeMap {
+    start_location: Pos,
+    map: Grid<char>,
+}
+
+impl PipeMap {
+    pub fn new(str: &str) -> PipeMap {
+        return PipeMap::from_str(str).expect("Ctor from string failed");
+    }
+
+    pub fn get(&self, p: Pos) -> Option<char> {
+        return self.map.get(p).copied();
+    }
+
+    pub fn get_loop_length(&self) -> usize {
+        return *self
+            .get_loop()
+            .iter()
+            .flat_map(|x| x.iter())
+            .max()
+            .expect("needs an element") as usize;
+    }
+
+    pub fn get_loop_area(&self) -> i32 {
+        let mut visited_map = vec![-1; self.map.len()];
+        visited_map[self.map.index_of(self.start_location)] = 0;
+
+        let mut search_queue = VecDeque::new();
+
+        let connected_neighbors = self.get_start_neighbors(self.start_location);
+
+        search_queue.push_back((*connected_neighbors.first().unwrap(), self.start_location));
+
+        let mut area: i32 = 0;
+
+        while let Some((point, previous)) = search_queue.pop_front() {
+            // already visited
+            if visited_map[self.map.index_of(point)] != -1 {
+                continue;
+            }
+
+            // Shoelace formula https://en.wikipedia.org/wiki/Shoelace_formula
+            area += (previous.y as i32 + point.y as i32) * (previous.x as i32 - point.x as i32);
+
+            visited_map[self.map.index_of(point)] = visited_map[self.map.index_of(previous)] + 1;
+            let neighbors = self.get_connected_neighbors(point);
+
+            for neighbor in neighbors {
+                if visited_map[self.map.index_of(neighbor)] != -1 {
+                    continue;
+                }
+                search_queue.push_back((neighbor, point));
+            }
+        }
+
+        // Pick's theorem https://en.wikipedia.org/wiki/Pick%27s_theorem
+        let loop_length = visited_map.iter().max().unwrap() + 1;
+        return area.abs() / 2 + 1 - loop_length / 2;
+    }
+
+    pub fn get_loop(&self) -> Vec<Vec<i32>> {
+        let mut visited_map = vec![-1; self.map.len()];
+        visited_map[self.map.index_of(self.start_location)] = 0;
+
+        let mut search_queue = VecDeque::new();
+
+        let connected_neighbors = self.get_start_neighbors(self.start_location);
+
+        for neighbor in connected_neighbors {
+            search_queue.push_back((neighbor, self.start_location));
+        }
+
+        while let Some((point, previous)) = search_queue.pop_front() {
+            // already visited
+            if visited_map[self.map.index_of(point)] != -1 {
+                continue;
+            }
+            visited_map[self.map.index_of(point)] = visited_map[self.map.index_of(previous)] + 1;
+            let neighbors = self.get_connected_neighbors(point);
+
+            for neighbor in neighbors {
+                search_queue.push_back((neighbor, point));
+            }
+        }
+
+        let loop_map = visited_map
+            .chunks(self.map.width())
+            .map(|x| x.to_vec())
+            .collect::<Vec<Vec<i32>>>();
+
+        return loop_map;
+    }
+
+    pub fn get_start_neighbors(&self, start: Pos) -> Vec<Pos> {
+        let mut start_neighbors = Vec::new();
+
+        // A neighbour joins the start if its pipe points back at it
+        for direction in [
+            Directions::West,
+            Directions::East,
+            Directions::North,
+            Directions::South,
+        ] {
+            let (dx, dy) = direction.delta();
+            let Some(neighbor) = self.map.step(start, dx, dy) else {
+                continue;
+            };
+            if PipeMap::get_connection_directions(self.map[neighbor])
+                .contains(&direction.opposite())
+            {
+                start_neighbors.push(neighbor);
+            }
+        }
+
+        return start_neighbors;
+    }
+
+    pub fn get_connection_directions(pipe: char) -> Vec<Directions> {
+        return match pipe {
+            'S' => vec![
+                Directions::North,
+                Directions::East,
+                Directions::South,
+                Directions::West,
+            ],
+            '|' => vec![Directions::North, Directions::South],
+            '-' => vec![Directions::East, Directions::West],
+            'L' => vec![Directions::North, Directions::East],
+            'J' => vec![Directions::North, Directions::West],
+            '7' => vec![Directions::South, Directions::West],
+            'F' => vec![Directions::East, Directions::South],
+            '.' => vec![],
+            _ => panic!("unexpected character"),
+        };
+    }
+
+    pub fn get_connected_neighbors(&self, p: Pos) -> Vec<Pos> {
+        let pipe1_connections = PipeMap::get_connection_directions(self.get(p).unwrap_or('.'));
+        return self.get_neighbors_from_directions(p, pipe1_connections);
+    }
+
+    pub fn get_neighbors_from_directions(&self, p: Pos, directions: Vec<Directions>) -> Vec<Pos> {
+        return directions
+            .iter()
+            .filter_map(|direction| {
+                let (dx, dy) = direction.delta();
+                self.map.step(p, dx, dy)
+            })
+            .collect();
+    }
+}
+
+#[derive(PartialEq)]
+pub enum Directions {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Directions {
+    pub fn delta(&self) -> (isize, isize) {
+        return match self {
+            Directions::North => (0, -1),
+            Directions::East => (1, 0),
+            Directions::South => (0, 1),
+            Directions::West => (-1, 0),
+        };
+    }
+
+    pub fn opposite(&self) -> Directions {
+        return match self {
+            Directions::North => Directions::South,
+            Directions::East => Directions::West,
+            Directions::South => Directions::North,
+            Directions::West => Directions::East,
+        };
+    }
+}
+
+impl FromStr for PipeMap {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let map: Grid<char> = str.parse()?;
+        let start_location = map
+            .find(&'S')
+            .ok_or_else(|| ParseError::end(str, "a start location 'S'"))?;
+
+        return Ok(PipeMap {
+            start_location,
+            map,
+        });
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<GalaxyMap, ParseError> {
+    return GalaxyMap::from_str(input);
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = GalaxyMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(galaxy_map: &Self::Input<'_>) -> usize {
+        let distances = galaxy_map.get_galaxy_distances_after_expansion(2);
+        return distances.iter().sum();
+    }
+
+    fn part2(galaxy_map: &Self::Input<'_>) -> usize {
+        let distances = galaxy_map.get_galaxy_distances_after_expansion(1_000_000);
+        return distances.iter().sum();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day11::part1(&GalaxyMap::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day11::part2(&GalaxyMap::new(input));
+}
+
+#[derive(Debug)]
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug)]
+pub struct GalaxyMap {
+    galaxies: Vec<Point>,
+    expanded_rows: Vec<usize>,
+    expanded_cols: Vec<usize>,
+}
+
+impl GalaxyMap {
+    pub fn new(str: &str) -> GalaxyMap {
+        return GalaxyMap::from_str(str).expect("Ctor from string failed");
+    }
+
+    pub fn get_galaxy_distances_after_expansion(&self, expansion_size: usize) -> Vec<usize> {
+        return self
+            .galaxies
+            .iter()
+            .enumerate()
+            .flat_map(|(i, galaxy1)| {
+                self.galaxies.iter().skip(i + 1).map(|galaxy2| {
+                    let max_x = galaxy1.x.max(galaxy2.x);
+                    let min_x = galaxy1.x.min(galaxy2.x);
+                    let max_y = galaxy1.y.max(galaxy2.y);
+                    let min_y = galaxy1.y.min(galaxy2.y);
+
+                    let expanded_col_count = self
+                        .expanded_cols
+                        .iter()
+                        .filter(|&&x| x > min_x && x < max_x)
+                        .count();
+                    let expanded_row_count = self
+                        .expanded_rows
+                        .iter()
+                        .filter(|&&y| y > min_y && y < max_y)
+                        .count();
+
+                    return (max_x - min_x + max_y - min_y)
+                        + expanded_col_count * (expansion_size - 1)
+                        + expanded_row_count * (expansion_size - 1);
+                })
+            })
+            .collect();
+    }
+}
+
+impl FromStr for GalaxyMap {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(str, |ch| matches!(ch, '#' | '.').then_some(ch))?;
+        let map_width = grid.width();
+        let map_height = grid.height();
+        let map: Vec<char> = grid.iter().map(|(_, &ch)| ch).collect();
+
+        let galaxies: Vec<Point> = map
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &ch)| {
+                if ch == '#' {
+                    return Some(Point {
+                        x: i % map_width,
+                        y: i / map_width,
+                    });
+                } else {
+                    return None;
+                }
+            })
+            .collect();
+
+        let expanded_rows: Vec<usize> = grid
+            .rows()
+            .enumerate()
+            .filter_map(|(row_index, row)| {
+                Some(row_index).filter(|_| row.iter().all(|&x| x == '.'))
+            })
+            .collect();
+
+        let expanded_cols: Vec<usize> = (0..map_width)
+            .filter(|&x| (0..map_height).all(|y| map[x + y * map_width] == '.'))
+            .collect();
+
+        return Ok(GalaxyMap {
+            galaxies,
+            expanded_rows,
+            expanded_cols,
+        });
+    }
+}
@@ -0,0 +1,186 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use aoc_grid::Grid;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Puzzle, ParseError> {
+    return Puzzle::from_str(input);
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(puzzle: &Self::Input<'_>) -> usize {
+        return puzzle.get_mirror_location();
+    }
+
+    fn part2(puzzle: &Self::Input<'_>) -> usize {
+        return puzzle.get_mirror_location_with_smudge();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day13::part1(&Puzzle::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day13::part2(&Puzzle::new(input));
+}
+
+pub struct Puzzle {
+    rows: Vec<AshRockMap>,
+}
+
+impl Puzzle {
+    pub fn new(str: &str) -> Puzzle {
+        return Puzzle::from_str(str).expect("");
+    }
+
+    pub fn get_mirror_location(&self) -> usize {
+        return self.rows.iter().map(|x| x.get_mirror_location(0)).sum();
+    }
+
+    pub fn get_mirror_location_with_smudge(&self) -> usize {
+        let scores: Vec<usize> = self.rows.iter().map(|x| x.get_mirror_location(1)).collect();
+        return scores.iter().sum();
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = aoc_parse::blocks(s)
+            .map(|block| AshRockMap::from_str(block).within(s, block))
+            .collect::<Result<_, _>>()?;
+        return Ok(Puzzle { rows });
+    }
+}
+
+struct AshRockMap {
+    map: Vec<String>,
+}
+
+impl AshRockMap {
+    pub fn get_mirror_location(&self, allowed_differences: usize) -> usize {
+        // Horizontal mirror
+        if let Some(match_index) =
+            AshRockMap::get_mirror_location_from_lines(&self.map, allowed_differences)
+        {
+            return match_index * 100;
+        }
+
+        let map_width = self.map.first().unwrap().len();
+        let map_height = self.map.len();
+        let vertical_map: Vec<String> = (0..map_width)
+            .map(|x| {
+                (0..map_height)
+                    .map(|y| {
+                        self.map
+                            .get(y)
+                            .unwrap_or(&"".to_string())
+                            .chars()
+                            .nth(x)
+                            .unwrap_or(' ')
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Vertical mirror
+        if let Some(match_index) =
+            AshRockMap::get_mirror_location_from_lines(&vertical_map, allowed_differences)
+        {
+            return match_index;
+        }
+
+        return 0;
+    }
+
+    pub fn get_mirror_location_from_lines(
+        lines: &[String],
+        allowed_differences: usize,
+    ) -> Option<usize> {
+        let mut prev = lines.first().unwrap();
+
+        for (index, line) in lines.iter().enumerate().skip(1) {
+            let mut acc_differences = 0;
+
+            let num_differences = AshRockMap::get_differences(line, prev);
+
+            match num_differences.cmp(&allowed_differences) {
+                Ordering::Greater => {
+                    prev = line;
+                    continue;
+                }
+                Ordering::Less => (),
+                Ordering::Equal => {
+                    acc_differences = num_differences;
+                }
+            }
+
+            for i in 1..lines.len() {
+                if i > index - 1 {
+                    if acc_differences != allowed_differences {
+                        break;
+                    }
+                    return Some(index);
+                }
+                if let Some(check_up) = lines.get(index - i - 1) {
+                    if let Some(check_down) = lines.get(index + i) {
+                        let num_differences = AshRockMap::get_differences(check_up, check_down);
+
+                        if num_differences + acc_differences <= allowed_differences {
+                            acc_differences += num_differences;
+                            continue;
+                        } else {
+                            break;
+                        }
+                    } else {
+                        if acc_differences != allowed_differences {
+                            break;
+                        }
+                        return Some(index);
+                    }
+                } else {
+                    if acc_differences != allowed_differences {
+                        break;
+                    }
+                    return Some(index);
+                }
+            }
+
+            prev = line;
+        }
+
+        return None;
+    }
+
+    pub fn get_differences(str1: &str, str2: &str) -> usize {
+        return str1
+            .chars()
+            .zip(str2.chars())
+            .filter(|&(c1, c2)| c1 != c2)
+            .count();
+    }
+}
+
+impl FromStr for AshRockMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |c| matches!(c, '#' | '.').then_some(c))?;
+        return Ok(AshRockMap {
+            map: grid.rows().map(|x| x.iter().collect()).collect(),
+        });
+    }
+}
@@ -0,0 +1,190 @@
+use std::{fmt::Display, str::FromStr};
+
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Platform, ParseError> {
+    return Platform::from_str(input);
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(platform: &Self::Input<'_>) -> usize {
+        return platform.get_load_after_tilt();
+    }
+
+    fn part2(platform: &Self::Input<'_>) -> usize {
+        return platform.get_load_after_cycles(1_000_000_000);
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day14::part1(&Platform::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day14::part2(&Platform::new(input));
+}
+
+#[derive(Clone)]
+pub struct Platform {
+    map: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+impl Platform {
+    pub fn new(str: &str) -> Platform {
+        return Platform::from_str(str).expect("");
+    }
+
+    pub fn get_load_after_tilt(&self) -> usize {
+        let mut load = 0;
+        for y in 0..self.height {
+            load += (0..self.width)
+                .filter_map(|x| self.find_resting_place(x, y))
+                .sum::<usize>();
+        }
+
+        return load;
+    }
+
+    pub fn find_resting_place(&self, x: usize, y: usize) -> Option<usize> {
+        if self.map[y][x] != 'O' {
+            return None;
+        }
+
+        let mut resting_height = y;
+
+        if y == 0 {
+            return Some(self.height - resting_height);
+        }
+
+        for y_next in (0..y).rev() {
+            if self.map[y_next][x] == '.' {
+                resting_height -= 1;
+            }
+            if self.map[y_next][x] == '#' {
+                break;
+            }
+        }
+
+        return Some(self.height - resting_height);
+    }
+
+    pub fn get_load_after_cycles(&self, n: usize) -> usize {
+        // The rocks soon settle into a loop of arrangements, so only spin
+        // until one comes round again and look up where the nth spin lands.
+        let (cycle, history) = aoc_cycle::find(
+            self.clone(),
+            |platform| {
+                let mut platform = platform.clone();
+                platform.cycle();
+                return Some(platform);
+            },
+            |platform| platform.map.clone(),
+        )
+        .expect("spinning never stops");
+
+        return history[cycle.index(n)].get_load();
+    }
+
+    fn cycle(&mut self) {
+        // North -> West -> South -> East
+        self.tilt_y(0, 1);
+        self.tilt_x(0, 1);
+        self.tilt_y(self.height - 1, -1);
+        self.tilt_x(self.width - 1, -1);
+    }
+
+    fn tilt_y(&mut self, start_row_index: usize, delta: i64) {
+        for x in 0..self.width {
+            let mut rock_stack_start_y = start_row_index as i64;
+            let mut y: i64 = start_row_index as i64;
+
+            while y >= 0 && y < self.height as i64 {
+                match self.map[y as usize][x] {
+                    '.' => (),
+                    '#' => rock_stack_start_y = y + delta,
+                    'O' => {
+                        self.map[y as usize][x] = '.';
+                        self.map[rock_stack_start_y as usize][x] = 'O';
+                        rock_stack_start_y += delta;
+                    }
+                    _ => panic!("invalid"),
+                }
+
+                y += delta;
+            }
+        }
+    }
+
+    fn tilt_x(&mut self, start_col_index: usize, delta: i64) {
+        for y in 0..self.height {
+            let mut rock_stack_start_x = start_col_index as i64;
+            let mut x = start_col_index as i64;
+
+            while x >= 0 && x < self.width as i64 {
+                match self.map[y][x as usize] {
+                    '.' => (),
+                    '#' => rock_stack_start_x = x + delta,
+                    'O' => {
+                        self.map[y][x as usize] = '.';
+                        self.map[y][rock_stack_start_x as usize] = 'O';
+                        rock_stack_start_x += delta;
+                    }
+                    _ => panic!("invalid"),
+                }
+
+                x += delta;
+            }
+        }
+    }
+
+    fn get_load(&self) -> usize {
+        self.map
+            .iter()
+            .enumerate()
+            .map(|(i, row)| (self.width - i) * row.iter().filter(|&&x| x == 'O').count())
+            .sum()
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |c| matches!(c, 'O' | '#' | '.').then_some(c))?;
+        let width = grid.width();
+        let height = grid.height();
+        let map = grid
+            .rows()
+            .map(|row| row.to_vec())
+            .collect::<Vec<Vec<char>>>();
+        return Ok(Platform { map, width, height });
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.map
+                .iter()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+}
@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Sequence, ParseError> {
+    return Sequence::from_str(input);
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Sequence;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(sequence: &Self::Input<'_>) -> usize {
+        return sequence.get_hashes();
+    }
+
+    fn part2(sequence: &Self::Input<'_>) -> usize {
+        return sequence.get_box_score();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day15::part1(&Sequence::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day15::part2(&Sequence::new(input));
+}
+
+pub struct Sequence {
+    calcs: Vec<String>,
+}
+
+#[derive(Clone)]
+struct Lens {
+    label: String,
+    focal_length: usize,
+}
+
+impl Sequence {
+    pub fn new(str: &str) -> Sequence {
+        return Sequence::from_str(str).expect("");
+    }
+
+    fn get_hashes(&self) -> usize {
+        self.calcs.iter().map(|calc| Sequence::get_hash(calc)).sum()
+    }
+
+    fn get_box_score(&self) -> usize {
+        let mut boxes: Vec<Vec<Lens>> = vec![vec![]; 256];
+        for calc in self.calcs.iter() {
+            if calc.contains('-') {
+                if let Some((label, _operation)) = calc.split_once('-') {
+                    let hash = Sequence::get_hash(label);
+
+                    match boxes[hash]
+                        .iter()
+                        .enumerate()
+                        .find(|(_i, x)| x.label == label)
+                    {
+                        Some((index, _)) => {
+                            boxes[hash].remove(index);
+                        }
+                        None => continue,
+                    }
+                }
+            } else if let Some((label, focal_length_str)) = calc.split_once('=') {
+                let hash = Sequence::get_hash(label);
+                let lens = Lens {
+                    label: label.to_string(),
+                    focal_length: focal_length_str.parse::<usize>().unwrap(),
+                };
+                match boxes[hash]
+                    .iter()
+                    .enumerate()
+                    .find(|(_i, x)| x.label == label)
+                {
+                    Some((index, _)) => {
+                        boxes[hash][index].focal_length = lens.focal_length;
+                    }
+                    None => {
+                        boxes[hash].push(lens);
+                    }
+                }
+            }
+        }
+
+        return boxes
+            .iter()
+            .enumerate()
+            .map(|(box_id, x)| {
+                x.iter()
+                    .enumerate()
+                    .map(|(slot_id, lens)| (box_id + 1) * (slot_id + 1) * lens.focal_length)
+                    .sum::<usize>()
+            })
+            .sum();
+    }
+
+    fn get_hash(s: &str) -> usize {
+        let mut sum = 0;
+
+        for c in s.chars() {
+            sum = (sum + c as usize) * 17 % 256;
+        }
+
+        return sum;
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s.trim_end_matches(['\n', '\r']);
+        for step in steps.split(',') {
+            if let Some((_, focal_length)) = step.split_once('=') {
+                aoc_parse::number::<usize>(s, focal_length)?;
+            } else if !step.ends_with('-') {
+                return Err(ParseError::new(s, step, "a step ending in '-' or '=N'"));
+            }
+        }
+
+        let calcs = steps.split(',').map(String::from).collect::<Vec<String>>();
+        return Ok(Sequence { calcs });
+    }
+}
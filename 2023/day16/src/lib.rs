@@ -0,0 +1,166 @@
+use std::str::FromStr;
+
+use aoc_grid::{Direction, Grid};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Contraption, ParseError> {
+    return Contraption::from_str(input);
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Contraption;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(contraption: &Self::Input<'_>) -> usize {
+        return contraption.get_num_energized(Beam {
+            x: 0,
+            y: 0,
+            traveling: Direction::East,
+        });
+    }
+
+    fn part2(contraption: &Self::Input<'_>) -> usize {
+        return contraption.get_max_energized();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day16::part1(&Contraption::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day16::part2(&Contraption::new(input));
+}
+
+pub struct Contraption {
+    grid: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Beam {
+    x: usize,
+    y: usize,
+    traveling: Direction,
+}
+
+impl Contraption {
+    pub fn new(str: &str) -> Contraption {
+        return Contraption::from_str(str).expect("");
+    }
+
+    fn get_max_energized(&self) -> usize {
+        let mut starts = Vec::with_capacity(2 * (self.width + self.height));
+
+        for x in 0..self.width {
+            starts.push(Beam {
+                x,
+                y: 0,
+                traveling: Direction::South,
+            });
+            starts.push(Beam {
+                x,
+                y: self.height - 1,
+                traveling: Direction::North,
+            });
+        }
+        for y in 0..self.height {
+            starts.push(Beam {
+                x: 0,
+                y,
+                traveling: Direction::East,
+            });
+            starts.push(Beam {
+                x: self.width - 1,
+                y,
+                traveling: Direction::West,
+            });
+        }
+
+        starts
+            .into_iter()
+            .map(|beam| self.get_num_energized(beam))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn get_num_energized(&self, start_beam: Beam) -> usize {
+        let mut queue = vec![start_beam];
+        let mut visited = vec![0u8; self.width * self.height];
+
+        while let Some(beam) = queue.pop() {
+            let idx = beam.y * self.width + beam.x;
+            let bit = beam.traveling.bit();
+
+            if visited[idx] & bit != 0 {
+                continue;
+            }
+            visited[idx] |= bit;
+
+            self.push_next_beams(&beam, &mut queue);
+        }
+
+        visited.iter().filter(|&&v| v != 0).count()
+    }
+
+    fn push_next_beams(&self, beam: &Beam, queue: &mut Vec<Beam>) {
+        let traveling = beam.traveling;
+
+        // Mirrors turn the beam, and splitters split one that hits their flat side
+        match (self.grid[beam.y][beam.x], traveling.is_vertical()) {
+            ('\\', true) | ('/', false) => self.push_beam(beam, traveling.turn_left(), queue),
+            ('\\', false) | ('/', true) => self.push_beam(beam, traveling.turn_right(), queue),
+            ('|', false) | ('-', true) => {
+                self.push_beam(beam, traveling.turn_left(), queue);
+                self.push_beam(beam, traveling.turn_right(), queue);
+            }
+            ('.' | '|' | '-', _) => self.push_beam(beam, traveling, queue),
+            _ => panic!("unexpected character"),
+        }
+    }
+
+    fn push_beam(&self, beam: &Beam, traveling: Direction, queue: &mut Vec<Beam>) {
+        let (dx, dy) = traveling.delta();
+        if let Some(new_x) = beam.x.checked_add_signed(dx) {
+            if let Some(new_y) = beam.y.checked_add_signed(dy) {
+                if new_x < self.width && new_y < self.height {
+                    queue.push(Beam {
+                        x: new_x,
+                        y: new_y,
+                        traveling,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for Contraption {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse_with(s, |c| {
+            matches!(c, '.' | '/' | '\\' | '|' | '-').then_some(c)
+        })?;
+        let width = tiles.width();
+        let height = tiles.height();
+        let grid = tiles
+            .rows()
+            .map(|row| row.to_vec())
+            .collect::<Vec<Vec<char>>>();
+        return Ok(Contraption {
+            grid,
+            width,
+            height,
+        });
+    }
+}
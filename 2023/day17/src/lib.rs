@@ -0,0 +1,181 @@
+use aoc_grid::{Direction, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    return Grid::from_str(input);
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(heat_map: &Self::Input<'_>) -> usize {
+        return heat_map.get_path(
+            vec![
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::East,
+                    num_step: 0,
+                },
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::South,
+                    num_step: 0,
+                },
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::North,
+                    num_step: 0,
+                },
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::West,
+                    num_step: 0,
+                },
+            ],
+            0,
+            3,
+        );
+    }
+
+    fn part2(heat_map: &Self::Input<'_>) -> usize {
+        return heat_map.get_path(
+            vec![
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::East,
+                    num_step: 0,
+                },
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::South,
+                    num_step: 0,
+                },
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::North,
+                    num_step: 0,
+                },
+                State {
+                    x: 0,
+                    y: 0,
+                    traveling: Direction::West,
+                    num_step: 0,
+                },
+            ],
+            4,
+            10,
+        );
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day17::part1(&Grid::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day17::part2(&Grid::new(input));
+}
+
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct State {
+    x: usize,
+    y: usize,
+    traveling: Direction,
+    num_step: usize,
+}
+
+pub struct Grid {
+    grid: aoc_grid::Grid<usize>,
+}
+
+impl Grid {
+    pub fn new(str: &str) -> Grid {
+        return Grid::from_str(str).expect("");
+    }
+
+    fn get_path(&self, start_states: Vec<State>, min_steps: usize, max_steps: usize) -> usize {
+        let path = aoc_search::dijkstra(
+            start_states,
+            |state| self.get_connected_states(state, min_steps, max_steps),
+            |state| state.x == self.grid.width() - 1 && state.y == self.grid.height() - 1,
+        );
+
+        return path.map_or(0, |path| path.cost);
+    }
+
+    pub fn get_connected_states(
+        &self,
+        state: &State,
+        min_steps: usize,
+        max_steps: usize,
+    ) -> Vec<(State, usize)> {
+        let traveling = state.traveling;
+
+        return [traveling, traveling.turn_left(), traveling.turn_right()]
+            .into_iter()
+            .filter_map(|traveling| self.get_state(state, traveling, min_steps, max_steps))
+            .collect();
+    }
+
+    /// The state after one step towards `traveling`, with the heat lost
+    /// entering its block.
+    pub fn get_state(
+        &self,
+        b: &State,
+        traveling: Direction,
+        min_steps: usize,
+        max_steps: usize,
+    ) -> Option<(State, usize)> {
+        // Still in bounds
+        let next = self.grid.neighbor(Pos::new(b.x, b.y), traveling)?;
+
+        // Transition conditions are met
+        if (traveling == b.traveling && b.num_step == max_steps)
+            || (traveling != b.traveling && b.num_step < min_steps)
+        {
+            return None;
+        }
+
+        let mut num_step = 1;
+
+        if b.traveling == traveling {
+            num_step += b.num_step;
+        }
+
+        return Some((
+            State {
+                x: next.x,
+                y: next.y,
+                traveling,
+                num_step,
+            },
+            self.grid[next],
+        ));
+    }
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = aoc_grid::Grid::parse_with(s, |c| c.to_digit(10).map(|d| d as usize))?;
+        return Ok(Grid { grid });
+    }
+}
@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use aoc_grid::Direction;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<DigPlan, ParseError> {
+    return DigPlan::from_str(input);
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = DigPlan;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(dig_plan: &Self::Input<'_>) -> usize {
+        return dig_plan.get_dig_area();
+    }
+
+    fn part2(dig_plan: &Self::Input<'_>) -> usize {
+        return dig_plan.decode_colors().get_dig_area();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day18::part1(&DigPlan::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day18::part2(&DigPlan::new(input));
+}
+
+/// The direction a colour's last digit stands for: 0 is east, and the
+/// rest follow clockwise.
+fn direction_from_hex_digit(ch: char) -> Option<Direction> {
+    return ch
+        .to_digit(4)
+        .map(|digit| Direction::from_index(digit as usize + 1));
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct DigStep {
+    traveling: Direction,
+    steps: usize,
+    hex_color: String,
+}
+
+pub struct DigPlan {
+    dig_plan: Vec<DigStep>,
+}
+
+impl DigPlan {
+    pub fn new(str: &str) -> DigPlan {
+        return DigPlan::from_str(str).expect("");
+    }
+
+    /// The real plan, hidden in the colours: five hex digits of distance
+    /// followed by a direction digit.
+    pub fn decode_colors(&self) -> DigPlan {
+        let dig_plan = self
+            .dig_plan
+            .iter()
+            .map(|step| DigStep {
+                traveling: direction_from_hex_digit(step.hex_color.chars().nth(6).unwrap())
+                    .expect("colour checked while parsing"),
+                steps: usize::from_str_radix(&step.hex_color[1..=5], 16)
+                    .expect("colour checked while parsing"),
+                hex_color: step.hex_color.clone(),
+            })
+            .collect::<Vec<DigStep>>();
+
+        return DigPlan { dig_plan };
+    }
+
+    pub fn get_dig_area(&self) -> usize {
+        let mut area = 0;
+        let mut boundary: isize = 0;
+
+        let mut current_x = 0;
+        let mut current_y = 0;
+        let mut previous_x = 0;
+        let mut previous_y = 0;
+
+        for step in self.dig_plan.iter() {
+            let (dx, dy) = step.traveling.delta();
+            current_x += dx * step.steps as isize;
+            current_y += dy * step.steps as isize;
+            boundary += step.steps as isize;
+            area += (previous_y + current_y) * (previous_x - current_x);
+            previous_x = current_x;
+            previous_y = current_y;
+        }
+
+        // Pick's theorem https://en.wikipedia.org/wiki/Pick%27s_theorem
+        return ((boundary + area) / 2) as usize + 1;
+    }
+}
+
+impl FromStr for DigPlan {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dig_plan = s
+            .lines()
+            .map(|line| {
+                let mut split = line.split_whitespace();
+                let direction = aoc_parse::next(s, line, &mut split, "a direction")?;
+                let traveling = direction
+                    .parse()
+                    .ok()
+                    .and_then(Direction::from_letter)
+                    .ok_or_else(|| ParseError::new(s, direction, "a direction (U, R, D or L)"))?;
+                let steps =
+                    aoc_parse::number(s, aoc_parse::next(s, line, &mut split, "a distance")?)?;
+                let color = aoc_parse::next(s, line, &mut split, "a colour")?;
+                let hex_color = color
+                    .strip_prefix('(')
+                    .and_then(|x| x.strip_suffix(')'))
+                    .filter(|x| {
+                        x.len() == 7
+                            && x.starts_with('#')
+                            && x[1..].chars().all(|c| c.is_ascii_hexdigit())
+                            && direction_from_hex_digit(x.chars().nth(6).unwrap()).is_some()
+                    })
+                    .ok_or_else(|| ParseError::new(s, color, "a colour like (#70c710)"))?
+                    .to_string();
+                return Ok(DigStep {
+                    traveling,
+                    steps,
+                    hex_color,
+                });
+            })
+            .collect::<Result<Vec<DigStep>, ParseError>>()?;
+
+        return Ok(DigPlan { dig_plan });
+    }
+}
@@ -0,0 +1,315 @@
+use std::str::FromStr;
+
+use aoc_intervals::Interval;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Machine, ParseError> {
+    return Machine::from_str(input);
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Machine;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(machine: &Self::Input<'_>) -> usize {
+        return machine.get_accepted_sum();
+    }
+
+    fn part2(machine: &Self::Input<'_>) -> usize {
+        let part_range: Vec<PartRange> = vec![PartRange {
+            start_label: "in".to_string(),
+            x: Interval::inclusive(1, 4000),
+            m: Interval::inclusive(1, 4000),
+            a: Interval::inclusive(1, 4000),
+            s: Interval::inclusive(1, 4000),
+        }];
+        return machine.get_accepted_sum_range(&part_range);
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day19::part1(&Machine::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day19::part2(&Machine::new(input));
+}
+
+#[derive(Clone)]
+pub struct PartRange {
+    x: Interval<usize>,
+    m: Interval<usize>,
+    a: Interval<usize>,
+    s: Interval<usize>,
+    start_label: String,
+}
+
+impl PartRange {
+    pub fn get_range(&self, s: &str) -> Interval<usize> {
+        return match s {
+            "x" => self.x,
+            "m" => self.m,
+            "a" => self.a,
+            "s" => self.s,
+            _ => panic!("no char"),
+        };
+    }
+
+    pub fn set_range(&mut self, s: &str, v: Interval<usize>) {
+        return match s {
+            "x" => self.x = v,
+            "m" => self.m = v,
+            "a" => self.a = v,
+            "s" => self.s = v,
+            _ => panic!("no char"),
+        };
+    }
+
+    /// Splits off the parts whose `s` rating is in `matched`, sending them to
+    /// `label`, and keeps the rest of `remaining` here. Returns false once
+    /// nothing is left.
+    fn split(
+        &mut self,
+        s: &str,
+        matched: Interval<usize>,
+        remaining: Interval<usize>,
+        label: &str,
+        queue: &mut Vec<PartRange>,
+    ) -> bool {
+        if !matched.is_empty() {
+            let mut part = self.clone();
+            part.set_range(s, matched);
+            part.start_label = label.to_string();
+            queue.push(part);
+        }
+        self.set_range(s, remaining);
+        return !remaining.is_empty();
+    }
+
+    pub fn combinations(&self) -> usize {
+        return self.x.len() * self.m.len() * self.a.len() * self.s.len();
+    }
+}
+
+pub struct Machine {
+    workflows: Vec<Workflow>,
+    input_conditions: Vec<Vec<(String, usize)>>,
+}
+
+impl Machine {
+    pub fn new(str: &str) -> Machine {
+        return Machine::from_str(str).expect("");
+    }
+
+    pub fn get_accepted_sum(&self) -> usize {
+        let mut sum = 0;
+
+        for input_condition in self.input_conditions.iter() {
+            if self.is_accepted(input_condition) {
+                sum += input_condition.iter().map(|x| x.1).sum::<usize>();
+            }
+        }
+
+        return sum;
+    }
+
+    pub fn is_accepted(&self, input_condition: &[(String, usize)]) -> bool {
+        let mut current_label = "in";
+
+        while let Some(workflow) = self.workflows.iter().find(|&x| x.label == current_label) {
+            for rule in workflow.rules.iter() {
+                if rule.1.is_none() {
+                    current_label = rule.0.as_str();
+                    break;
+                }
+
+                if let [label_str, amount_str] =
+                    &rule.0.split('<').map(String::from).collect::<Vec<String>>()[..]
+                {
+                    let input_value = match input_condition.iter().find(|&x| x.0.eq(label_str)) {
+                        Some(x) => x.1,
+                        None => 0,
+                    };
+
+                    let amount = usize::from_str(amount_str).unwrap();
+
+                    if input_value < amount {
+                        current_label = rule.1.as_ref().unwrap();
+                        break;
+                    }
+                }
+
+                if let [label_str, amount_str] =
+                    &rule.0.split('>').map(String::from).collect::<Vec<String>>()[..]
+                {
+                    let input_value = match input_condition.iter().find(|&x| x.0.eq(label_str)) {
+                        Some(x) => x.1,
+                        None => 0,
+                    };
+
+                    let amount = usize::from_str(amount_str).unwrap();
+
+                    if input_value > amount {
+                        current_label = rule.1.as_ref().unwrap();
+                        break;
+                    }
+                }
+            }
+        }
+
+        return current_label == "A";
+    }
+
+    pub fn get_accepted_sum_range(&self, part_ranges: &[PartRange]) -> usize {
+        let mut queue: Vec<PartRange> = part_ranges.to_vec();
+        let mut result: Vec<PartRange> = vec![];
+
+        while let Some(mut part_range) = queue.pop() {
+            let workflow = self
+                .workflows
+                .iter()
+                .find(|&x| x.label == part_range.start_label);
+
+            if workflow.is_none() {
+                result.push(part_range);
+                continue;
+            }
+
+            for rule in workflow.unwrap().rules.iter() {
+                if rule.1.is_none() {
+                    let mut part = part_range.clone();
+                    part.start_label = rule.0.to_string();
+                    queue.push(part);
+                    break;
+                }
+                let destination = rule.1.as_ref().unwrap();
+
+                if let [rule_variable_str, amount_str] =
+                    &rule.0.split('<').map(String::from).collect::<Vec<String>>()[..]
+                {
+                    let rule_amount = usize::from_str(amount_str).unwrap();
+                    let (below, above) = part_range
+                        .get_range(rule_variable_str)
+                        .split_at(rule_amount);
+
+                    if !part_range.split(rule_variable_str, below, above, destination, &mut queue) {
+                        break;
+                    }
+                }
+
+                if let [rule_variable_str, amount_str] =
+                    &rule.0.split('>').map(String::from).collect::<Vec<String>>()[..]
+                {
+                    let rule_amount = usize::from_str(amount_str).unwrap();
+                    let (below, above) = part_range
+                        .get_range(rule_variable_str)
+                        .split_at(rule_amount + 1);
+
+                    if !part_range.split(rule_variable_str, above, below, destination, &mut queue) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let sum = result
+            .iter()
+            .filter(|x| x.start_label == "A")
+            .map(|x| x.combinations())
+            .sum::<usize>();
+
+        return sum;
+    }
+}
+
+/// Parses a part like `{x=787,m=2655,a=1222,s=2876}`.
+fn parse_ratings(s: &str) -> Result<Vec<(String, usize)>, ParseError> {
+    let ratings = aoc_parse::strip_prefix(s, s, "{")?;
+    let ratings = ratings
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::end(s, "\"}\""))?;
+
+    return ratings
+        .split(',')
+        .map(|rating| {
+            let (category, amount) = aoc_parse::split_once(s, rating, "=")?;
+            return Ok((category.to_string(), aoc_parse::number(s, amount)?));
+        })
+        .collect();
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut blocks = aoc_parse::blocks(s);
+
+        let workflows: Vec<Workflow> = aoc_parse::next(s, s, &mut blocks, "a list of workflows")?
+            .lines()
+            .map(|line| Workflow::from_str(line).within(s, line))
+            .collect::<Result<_, _>>()?;
+        let input_conditions: Vec<Vec<(String, usize)>> =
+            aoc_parse::next(s, s, &mut blocks, "a list of parts")?
+                .lines()
+                .map(|line| parse_ratings(line).within(s, line))
+                .collect::<Result<_, _>>()?;
+
+        if let Some(extra) = blocks.next() {
+            return Err(ParseError::new(s, extra, "the end of the parts"));
+        }
+
+        return Ok(Machine {
+            workflows,
+            input_conditions,
+        });
+    }
+}
+
+struct Workflow {
+    label: String,
+    rules: Vec<(String, Option<String>)>,
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, rules) = aoc_parse::split_once(s, s, "{")?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::end(s, "\"}\""))?;
+
+        let rules = rules
+            .split(',')
+            .map(|rule| {
+                let Some((condition, destination)) = rule.split_once(':') else {
+                    return Ok((rule.to_string(), None));
+                };
+
+                let bytes = condition.as_bytes();
+                if bytes.len() < 3
+                    || !b"xmas".contains(&bytes[0])
+                    || !matches!(bytes[1], b'<' | b'>')
+                {
+                    return Err(ParseError::new(s, condition, "a condition like a<2006"));
+                }
+                aoc_parse::number::<usize>(s, &condition[2..])?;
+
+                return Ok((condition.to_string(), Some(destination.to_string())));
+            })
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Workflow {
+            label: label.to_string(),
+            rules,
+        });
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use aoc_math::number::{crt, Congruence};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Machine, ParseError> {
+    return Machine::from_str(input);
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Machine;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(machine: &Self::Input<'_>) -> usize {
+        machine.clone().get_output()
+    }
+
+    fn part2(machine: &Self::Input<'_>) -> Result<usize, &'static str> {
+        machine.clone().first_low_pulse_press()
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day20::part1(&Machine::new(input))
+}
+
+pub fn part2(input: &str) -> Result<usize, &'static str> {
+    Day20::part2(&Machine::new(input))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct ModuleId(usize);
+
+#[derive(Clone, Debug)]
+struct Signal {
+    to: ModuleId,
+    from: ModuleId,
+    state: bool,
+}
+
+#[derive(Clone)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop {
+        state: bool,
+    },
+    Conjunction {
+        remembered_pulses: HashMap<ModuleId, bool>,
+    },
+    Sink,
+}
+
+#[derive(Clone)]
+struct Module {
+    id: ModuleId,
+    module_type: ModuleType,
+    destinations: Vec<ModuleId>,
+}
+
+#[derive(Clone)]
+pub struct Machine {
+    modules: Vec<Module>,
+    label_to_id: HashMap<String, ModuleId>,
+    broadcaster_id: ModuleId,
+}
+
+impl Machine {
+    pub fn new(str: &str) -> Machine {
+        Machine::from_str(str).expect("Failed to parse machine")
+    }
+
+    pub fn get_output(&mut self) -> usize {
+        let mut high = 0;
+        let mut low = 0;
+        let mut queue: VecDeque<Signal> = VecDeque::with_capacity(1024);
+        let button_id = ModuleId(usize::MAX);
+
+        for _ in 0..1000 {
+            low += 1;
+            queue.push_back(Signal {
+                to: self.broadcaster_id,
+                from: button_id,
+                state: false,
+            });
+
+            while let Some(signal) = queue.pop_front() {
+                if signal.to.0 >= self.modules.len() {
+                    continue;
+                }
+
+                let module = &mut self.modules[signal.to.0];
+                let next_state = match &mut module.module_type {
+                    ModuleType::Broadcaster => Some(signal.state),
+                    ModuleType::FlipFlop { state } => {
+                        if signal.state {
+                            None
+                        } else {
+                            *state = !*state;
+                            Some(*state)
+                        }
+                    }
+                    ModuleType::Conjunction { remembered_pulses } => {
+                        remembered_pulses.insert(signal.from, signal.state);
+                        Some(!remembered_pulses.values().all(|&v| v))
+                    }
+                    ModuleType::Sink => None,
+                };
+
+                if let Some(state) = next_state {
+                    for &dest in &module.destinations {
+                        if state {
+                            high += 1;
+                        } else {
+                            low += 1;
+                        }
+                        queue.push_back(Signal {
+                            to: dest,
+                            from: module.id,
+                            state,
+                        });
+                    }
+                }
+            }
+        }
+
+        high * low
+    }
+
+    /// The first press of the button that sends rx a low pulse, which needs
+    /// rx to be fed by a single conjunction whose inputs each send it high
+    /// pulses in a cycle.
+    pub fn first_low_pulse_press(&mut self) -> Result<usize, &'static str> {
+        let rx_id = match self.label_to_id.get("rx") {
+            Some(&id) => id,
+            None => return Err("there is no rx module"),
+        };
+
+        let rx_parent_idx = self
+            .modules
+            .iter()
+            .position(|m| m.destinations.contains(&rx_id));
+        let rx_parent_id = match rx_parent_idx {
+            Some(idx) => ModuleId(idx),
+            None => return Err("nothing sends pulses to rx"),
+        };
+
+        // The presses on which each input to rx's parent sent it a high
+        // pulse, until each has done so twice and so shown its period.
+        let mut visited: HashMap<ModuleId, Vec<usize>> = HashMap::new();
+        if let ModuleType::Conjunction { remembered_pulses } =
+            &self.modules[rx_parent_id.0].module_type
+        {
+            for &input_id in remembered_pulses.keys() {
+                visited.insert(input_id, Vec::new());
+            }
+        } else {
+            return Err("rx isn't fed by a conjunction");
+        }
+
+        let mut queue: VecDeque<Signal> = VecDeque::with_capacity(1024);
+        let button_id = ModuleId(usize::MAX);
+
+        for i in 1..=usize::MAX {
+            queue.push_back(Signal {
+                to: self.broadcaster_id,
+                from: button_id,
+                state: false,
+            });
+
+            while let Some(signal) = queue.pop_front() {
+                if signal.to == rx_parent_id && signal.state {
+                    if let Some(presses) = visited.get_mut(&signal.from) {
+                        if presses.len() < 2 && presses.last() != Some(&i) {
+                            presses.push(i);
+                        }
+                    }
+
+                    if visited.values().all(|presses| presses.len() == 2) {
+                        return rx_press(visited.values())
+                            .ok_or("the inputs to rx's conjunction are never all high at once");
+                    }
+                }
+
+                if signal.to.0 >= self.modules.len() {
+                    continue;
+                }
+
+                let module = &mut self.modules[signal.to.0];
+                let next_state = match &mut module.module_type {
+                    ModuleType::Broadcaster => Some(signal.state),
+                    ModuleType::FlipFlop { state } => {
+                        if signal.state {
+                            None
+                        } else {
+                            *state = !*state;
+                            Some(*state)
+                        }
+                    }
+                    ModuleType::Conjunction { remembered_pulses } => {
+                        remembered_pulses.insert(signal.from, signal.state);
+                        Some(!remembered_pulses.values().all(|&v| v))
+                    }
+                    ModuleType::Sink => None,
+                };
+
+                if let Some(state) = next_state {
+                    for &dest in &module.destinations {
+                        queue.push_back(Signal {
+                            to: dest,
+                            from: module.id,
+                            state,
+                        });
+                    }
+                }
+            }
+        }
+        Err("the presses ran past usize::MAX")
+    }
+}
+
+/// The first press on which every input sends its high pulse, given the
+/// first two presses each one did. Inputs needn't start their cycle on the
+/// first press, so this is the Chinese remainder theorem rather than an lcm.
+fn rx_press<'a>(presses: impl Iterator<Item = &'a Vec<usize>>) -> Option<usize> {
+    let congruences =
+        presses.map(|presses| Congruence::new(presses[0] as i64, (presses[1] - presses[0]) as i64));
+
+    return crt(congruences).map(|press| press.first_from(0) as usize);
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut label_to_id = HashMap::new();
+        let mut raw_lines = Vec::new();
+
+        for line in s.lines() {
+            if line.is_empty() {
+                continue;
+            }
+            let (mut label, dest_str) = aoc_parse::split_once(s, line, " -> ")?;
+            let prefix = if label.starts_with(['%', '&']) {
+                let p = label.chars().next().unwrap();
+                label = &label[1..];
+                Some(p)
+            } else if label == "broadcaster" {
+                None
+            } else {
+                return Err(ParseError::new(
+                    s,
+                    label,
+                    "a module like %a, &b or broadcaster",
+                ));
+            };
+
+            let next_id = ModuleId(label_to_id.len());
+            let id = *label_to_id.entry(label.to_string()).or_insert(next_id);
+            raw_lines.push((id, label.to_string(), prefix, dest_str));
+        }
+
+        let mut modules_map: HashMap<ModuleId, Module> = HashMap::new();
+        for (id, _, prefix, dest_str) in raw_lines {
+            let mut destinations = Vec::new();
+            for d in dest_str.split(", ") {
+                let next_id = ModuleId(label_to_id.len());
+                let did = *label_to_id.entry(d.to_string()).or_insert(next_id);
+                destinations.push(did);
+            }
+
+            let module_type = match prefix {
+                Some('%') => ModuleType::FlipFlop { state: false },
+                Some('&') => ModuleType::Conjunction {
+                    remembered_pulses: HashMap::new(),
+                },
+                _ => ModuleType::Broadcaster,
+            };
+
+            modules_map.insert(
+                id,
+                Module {
+                    id,
+                    module_type,
+                    destinations,
+                },
+            );
+        }
+
+        let mut modules = Vec::with_capacity(label_to_id.len());
+        for i in 0..label_to_id.len() {
+            let id = ModuleId(i);
+            if let Some(m) = modules_map.remove(&id) {
+                modules.push(m);
+            } else {
+                modules.push(Module {
+                    id,
+                    module_type: ModuleType::Sink,
+                    destinations: vec![],
+                });
+            }
+        }
+
+        let module_count = modules.len();
+        for i in 0..module_count {
+            let mut inputs = Vec::new();
+            let current_id = modules[i].id;
+            for sender in &modules {
+                if sender.destinations.contains(&current_id) {
+                    inputs.push(sender.id);
+                }
+            }
+
+            if let ModuleType::Conjunction { remembered_pulses } = &mut modules[i].module_type {
+                for input_id in inputs {
+                    remembered_pulses.insert(input_id, false);
+                }
+            }
+        }
+
+        let broadcaster_id = *label_to_id
+            .get("broadcaster")
+            .ok_or_else(|| ParseError::end(s, "a broadcaster module"))?;
+
+        Ok(Machine {
+            modules,
+            label_to_id,
+            broadcaster_id,
+        })
+    }
+}
@@ -0,0 +1,343 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use aoc_grid::{Direction, Grid};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Garden, ParseError> {
+    return Garden::from_str(input);
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Garden;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(garden: &Self::Input<'_>) -> usize {
+        return garden.get_reached(&garden.get_start_step(), 64, &Garden::get_connected);
+    }
+
+    fn part2(garden: &Self::Input<'_>) -> usize {
+        return garden.get_reached_extrapolated(26501365);
+    }
+}
+
+pub fn part1(input: &str, steps: usize) -> usize {
+    let garden = Garden::new(input);
+    return garden.get_reached(&garden.get_start_step(), steps, &Garden::get_connected);
+}
+
+pub fn part2_1(input: &str, steps: usize) -> usize {
+    return Garden::new(input).get_reached_wrapping(steps);
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day21::part2(&Garden::new(input));
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Step {
+    count: usize,
+    x: isize,
+    y: isize,
+}
+
+pub struct Garden {
+    grid: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+impl Garden {
+    pub fn new(str: &str) -> Garden {
+        return Garden::from_str(str).expect("");
+    }
+
+    /// Counts the plots reached on the infinitely repeating garden after
+    /// `steps` by fitting a quadratic to the counts 0, 2 and 4 gardens out.
+    /// This only holds for a square garden with the start in the middle, an
+    /// open middle row, middle column and border, and `steps` an even number
+    /// of gardens past the middle.
+    pub fn get_reached_extrapolated(&self, steps: usize) -> usize {
+        let start_step = self.get_start_step();
+
+        let x_max = steps / self.width;
+        let rem = steps % self.width;
+
+        let mut y = vec![];
+        for x in [0, 2, 4] {
+            y.push(self.get_reached(
+                &start_step,
+                self.width * x + rem,
+                &Garden::get_connected_wrapping,
+            ));
+        }
+
+        // Equation is in the following form
+        // y(x) = a*x^2 + bx + c
+        // find a, b, c using x and y
+        // points for my input are {x, y} => {0, 3921}, {2, 96749}, {4, 312993}
+        // when x = 0, c = y[0] = 3921
+        // y = 0a + 0b + c
+        // c = 3921                        -- used below
+        //
+        // when x = 2, y = y[1] = 96749
+        // y = 4a + 2b + c
+        // rearrange
+        // 2b = y - 4a - c
+        // 2b = 96749 - 4a - c             -- Equation 1
+        //
+        // when x = 4, y = y[2] = 312993
+        // y = 16a + 4b + c
+        // sub in Equation 1 and then we solve for a
+        // y = 16a + 2(96749 - 4a - c) + c
+        // y = 8a + 2(96749) - c
+        // 8a = y - 2(96749) + c
+        // a = (y - 2(96749) + c)/8
+        // a = (y[2] - 2 * y[1] + c)/ 8    -- used below
+        //
+        // use this for b in Equation 1
+        // 2b = 96749 - 4a - c
+        // b = (96749 - 4a - c)/ 2
+        // b = (y[1] - 4a - c)/ 2          -- used below
+
+        let c = y[0]; // 3921;
+        let a = (y[2] - 2 * y[1] + c) / 8; // (312993 - 2 * 96749 + c ) / 8
+        let b = (y[1] - 4 * a - c) / 2; // (96749 - 4 * a - c)/2
+
+        return a * x_max * x_max + b * x_max + c;
+    }
+
+    /// Counts the plots reached on the infinitely repeating garden after
+    /// `steps` by walking every one of them.
+    pub fn get_reached_wrapping(&self, steps: usize) -> usize {
+        return self.get_reached(
+            &self.get_start_step(),
+            steps,
+            &Garden::get_connected_wrapping,
+        );
+    }
+
+    fn get_reached(
+        &self,
+        start: &Step,
+        max_steps: usize,
+        get_neighbors: &dyn Fn(&Self, &Step) -> Vec<Option<Step>>,
+    ) -> usize {
+        let mut queue = VecDeque::new();
+        queue.push_back(start.clone());
+
+        let map_count = ((max_steps / self.width) + 1) * 4;
+        let mut visited_map: Vec<Vec<usize>> =
+            vec![vec![usize::MAX; self.width * map_count]; self.height * map_count];
+
+        let (start_x, start_y) = self.get_start();
+        let start_x_offset = (self.width * map_count) / 2 + start_x;
+        let start_y_offset = (self.height * map_count) / 2 + start_y;
+
+        while let Some(step) = queue.pop_front() {
+            if step.count == max_steps {
+                break;
+            }
+
+            let steps = get_neighbors(self, &step);
+
+            for step in steps.into_iter().flatten() {
+                let grid_x = start_x_offset.checked_add_signed(step.x).unwrap();
+                let grid_y = start_y_offset.checked_add_signed(step.y).unwrap();
+                if step.count < visited_map[grid_y][grid_x] {
+                    visited_map[grid_y][grid_x] = step.count;
+                    queue.push_back(step);
+                }
+            }
+        }
+
+        let mut even_count: usize = 0;
+        let mut odd_count: usize = 0;
+        for line in visited_map.iter() {
+            let _out: String = line
+                .iter()
+                .map(|&x| {
+                    if x == usize::MAX {
+                        return '#';
+                    }
+                    match x % 2 {
+                        0 => {
+                            even_count += 1;
+                            return 'E';
+                        }
+                        1 => {
+                            odd_count += 1;
+                            return 'O';
+                        }
+                        _ => panic!(),
+                    }
+                })
+                .collect();
+            //println!("{}", _out);
+        }
+
+        if max_steps % 2 == 1 {
+            return odd_count;
+        } else {
+            return even_count;
+        }
+    }
+
+    pub fn get_connected(&self, step: &Step) -> Vec<Option<Step>> {
+        return Direction::ALL
+            .into_iter()
+            .map(|traveling| self.get_next(step, traveling))
+            .collect();
+    }
+
+    pub fn get_next(&self, previous: &Step, traveling: Direction) -> Option<Step> {
+        let (dx, dy) = traveling.delta();
+
+        let new_x = previous.x + dx;
+        let new_y = previous.y + dy;
+        if new_x >= 0
+            && new_x < self.width.try_into().unwrap()
+            && new_y >= 0
+            && new_y < self.height.try_into().unwrap()
+            && self.grid[new_y as usize][new_x as usize] != '#'
+        {
+            return Some(Step {
+                x: new_x,
+                y: new_y,
+                count: previous.count + 1,
+            });
+        }
+
+        return None;
+    }
+
+    pub fn get_connected_wrapping(&self, step: &Step) -> Vec<Option<Step>> {
+        return Direction::ALL
+            .into_iter()
+            .map(|traveling| self.get_next_wrapping(step, traveling))
+            .collect();
+    }
+
+    pub fn get_next_wrapping(&self, previous: &Step, traveling: Direction) -> Option<Step> {
+        let (dx, dy) = traveling.delta();
+
+        let new_x: isize = previous.x + dx;
+        let new_y: isize = previous.y + dy;
+        let grid_x: usize = usize::try_from(new_x.rem_euclid(self.width.try_into().unwrap()))
+            .expect("this is usize");
+        let grid_y: usize = usize::try_from(new_y.rem_euclid(self.height.try_into().unwrap()))
+            .expect("this is usize");
+        if self.grid[grid_y][grid_x] != '#' {
+            return Some(Step {
+                x: new_x,
+                y: new_y,
+                count: previous.count + 1,
+            });
+        }
+
+        return None;
+    }
+
+    pub fn get_start(&self) -> (usize, usize) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.grid[y][x] == 'S' {
+                    return (x, y);
+                }
+            }
+        }
+        return (0, 0);
+    }
+
+    fn get_start_step(&self) -> Step {
+        let (start_x, start_y) = self.get_start();
+        return Step {
+            count: 0,
+            x: start_x as isize,
+            y: start_y as isize,
+        };
+    }
+}
+
+impl FromStr for Garden {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let plots = Grid::parse_with(s, |c| matches!(c, '.' | '#' | 'S').then_some(c))?;
+        if plots.find(&'S').is_none() {
+            return Err(ParseError::end(s, "a starting position 'S'"));
+        }
+
+        let width = plots.width();
+        let height = plots.height();
+        let grid = plots
+            .rows()
+            .map(|row| row.to_vec())
+            .collect::<Vec<Vec<char>>>();
+        return Ok(Garden {
+            grid,
+            width,
+            height,
+        });
+    }
+}
+
+#[test]
+pub fn part1_test1() {
+    let ans = part1(include_str!("../input/test1.txt"), 6);
+    assert_eq!(ans, 16);
+}
+
+#[test]
+pub fn part2_test1() {
+    let ans = part2_1(include_str!("../input/test1.txt"), 500);
+    assert_eq!(ans, 167004);
+}
+
+/// Checks the quadratic extrapolation against walking the repeating garden on
+/// random gardens shaped like the puzzle's: square, with the start in the
+/// middle and an open middle row, middle column and border. Rocks only go on
+/// cells with both coordinates odd, so none of them can wall anything off.
+#[cfg(test)]
+mod differential {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn garden() -> impl Strategy<Value = String> {
+        return prop::sample::select(vec![5usize, 9, 13]).prop_flat_map(|size| {
+            let rocks = proptest::collection::vec(any::<bool>(), (size / 2) * (size / 2));
+            return rocks.prop_map(move |rocks| {
+                let mut grid = vec![vec!['.'; size]; size];
+                grid[size / 2][size / 2] = 'S';
+                for (i, &rock) in rocks.iter().enumerate() {
+                    if rock {
+                        grid[2 * (i / (size / 2)) + 1][2 * (i % (size / 2)) + 1] = '#';
+                    }
+                }
+                return grid
+                    .iter()
+                    .map(|row| row.iter().collect::<String>())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+            });
+        });
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn extrapolated_and_walked_counts_agree(input in garden(), gardens in prop::sample::select(vec![6usize, 8])) {
+            let garden = Garden::from_str(&input).unwrap();
+            let steps = gardens * garden.width + garden.width / 2;
+            prop_assert_eq!(garden.get_reached_extrapolated(steps), garden.get_reached_wrapping(steps));
+        }
+    }
+}
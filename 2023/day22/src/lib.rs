@@ -0,0 +1,176 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+use aoc_geometry::Vec3;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<BrickStack, ParseError> {
+    return BrickStack::from_str(input);
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = BrickStack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(brick_stack: &Self::Input<'_>) -> usize {
+        brick_stack.get_disintegration_count_and_fallen_sum().0
+    }
+
+    fn part2(brick_stack: &Self::Input<'_>) -> usize {
+        brick_stack.get_disintegration_count_and_fallen_sum().1
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day22::part1(&BrickStack::new(input))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day22::part2(&BrickStack::new(input))
+}
+
+#[derive(Debug, Clone)]
+struct Brick {
+    start_point: Vec3<usize>,
+    end_point: Vec3<usize>,
+}
+
+impl Brick {
+    fn x_range(&self) -> std::ops::RangeInclusive<usize> {
+        self.start_point.x.min(self.end_point.x)..=self.start_point.x.max(self.end_point.x)
+    }
+
+    fn y_range(&self) -> std::ops::RangeInclusive<usize> {
+        self.start_point.y.min(self.end_point.y)..=self.start_point.y.max(self.end_point.y)
+    }
+
+    fn min_z(&self) -> usize {
+        self.start_point.z.min(self.end_point.z)
+    }
+
+    fn max_z(&self) -> usize {
+        self.start_point.z.max(self.end_point.z)
+    }
+
+    fn height(&self) -> usize {
+        self.max_z() - self.min_z() + 1
+    }
+}
+
+impl FromStr for Brick {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = aoc_parse::split_once(s, s, "~")?;
+        let start_point = Vec3::from_str(start).within(s, start)?;
+        let end_point = Vec3::from_str(end).within(s, end)?;
+        Ok(Brick {
+            start_point,
+            end_point,
+        })
+    }
+}
+
+pub struct BrickStack {
+    bricks: Vec<Brick>,
+}
+
+impl BrickStack {
+    pub fn new(str: &str) -> BrickStack {
+        BrickStack::from_str(str).expect("")
+    }
+
+    pub fn get_disintegration_count_and_fallen_sum(&self) -> (usize, usize) {
+        let mut bricks = self.bricks.clone();
+        bricks.sort_by_key(|b| b.min_z());
+
+        let max_x = bricks.iter().map(|b| *b.x_range().end()).max().unwrap_or(0);
+        let max_y = bricks.iter().map(|b| *b.y_range().end()).max().unwrap_or(0);
+
+        let mut height_map = vec![vec![(0, None); max_x + 1]; max_y + 1];
+        let mut supported_by: Vec<HashSet<usize>> = vec![HashSet::new(); bricks.len()];
+        let mut supports: Vec<HashSet<usize>> = vec![HashSet::new(); bricks.len()];
+
+        for i in 0..bricks.len() {
+            let b = &bricks[i];
+            let x_range = b.x_range();
+            let y_range = b.y_range();
+            let height = b.height();
+
+            let mut max_h = 0;
+            for y in y_range.clone() {
+                for x in x_range.clone() {
+                    max_h = max_h.max(height_map[y][x].0);
+                }
+            }
+
+            let new_top_h = max_h + height;
+            for y in y_range {
+                for x in x_range.clone() {
+                    if let (h, Some(prev_idx)) = height_map[y][x] {
+                        if h == max_h {
+                            supported_by[i].insert(prev_idx);
+                            supports[prev_idx].insert(i);
+                        }
+                    }
+                    height_map[y][x] = (new_top_h, Some(i));
+                }
+            }
+        }
+
+        let mut can_disintegrate_count = 0;
+        for support in supports.iter().take(bricks.len()) {
+            if support
+                .iter()
+                .all(|&supported_idx| supported_by[supported_idx].len() > 1)
+            {
+                can_disintegrate_count += 1;
+            }
+        }
+
+        let mut fallen_sum = 0;
+        for i in 0..bricks.len() {
+            let mut q = VecDeque::new();
+            q.push_back(i);
+
+            let mut removed_supports = vec![0; bricks.len()];
+            let mut fallen_count = 0;
+
+            while let Some(curr) = q.pop_front() {
+                for &next in &supports[curr] {
+                    removed_supports[next] += 1;
+                    if removed_supports[next] == supported_by[next].len() {
+                        fallen_count += 1;
+                        q.push_back(next);
+                    }
+                }
+            }
+            fallen_sum += fallen_count;
+        }
+
+        (can_disintegrate_count, fallen_sum)
+    }
+}
+
+impl FromStr for BrickStack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bricks: Vec<Brick> = s
+            .lines()
+            .map(|line| Brick::from_str(line).within(s, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(BrickStack { bricks })
+    }
+}
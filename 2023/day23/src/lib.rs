@@ -0,0 +1,345 @@
+#![allow(clippy::needless_return)]
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_graph::Graph;
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<HikingTrail, ParseError> {
+    return HikingTrail::from_str(input);
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = HikingTrail;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(hiking_trail: &Self::Input<'_>) -> usize {
+        let (start_x, start_y) = hiking_trail.get_start();
+        let start_step = Step {
+            count: 0,
+            x: start_x,
+            y: start_y,
+            history: HashSet::new(),
+        };
+        return hiking_trail.get_reached(&start_step, &HikingTrail::get_connected);
+    }
+
+    fn part2(hiking_trail: &Self::Input<'_>) -> usize {
+        return hiking_trail.dfs();
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    return Day23::part1(&HikingTrail::new(input));
+}
+
+pub fn part2(input: &str) -> usize {
+    return Day23::part2(&HikingTrail::new(input));
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Step {
+    count: usize,
+    x: usize,
+    y: usize,
+    history: HashSet<(usize, usize)>,
+}
+
+pub struct HikingTrail {
+    grid: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+impl HikingTrail {
+    pub fn new(str: &str) -> HikingTrail {
+        return HikingTrail::from_str(str).expect("");
+    }
+
+    fn get_reached(
+        &self,
+        start: &Step,
+        get_neighbors: &dyn Fn(&Self, &Step) -> Vec<Option<Step>>,
+    ) -> usize {
+        let mut queue = Vec::new();
+        queue.push(start.clone());
+
+        let mut current_max = 0;
+        let (goal_x, goal_y) = self.get_end();
+
+        while let Some(step) = queue.pop() {
+            if step.x == goal_x && step.y == goal_y && current_max < step.count {
+                current_max = step.count;
+            }
+
+            let steps = get_neighbors(self, &step);
+
+            for step in steps.into_iter().flatten() {
+                queue.push(step);
+            }
+        }
+
+        return current_max;
+    }
+
+    fn dfs(&self) -> usize {
+        let junctions = self.get_junctions();
+
+        return junctions.get_longest();
+    }
+
+    pub fn get_connected(&self, step: &Step) -> Vec<Option<Step>> {
+        return Direction::ALL
+            .into_iter()
+            .map(|traveling| self.get_next(step, traveling))
+            .collect();
+    }
+
+    pub fn get_next(&self, previous: &Step, traveling: Direction) -> Option<Step> {
+        let (dx, dy) = traveling.delta();
+
+        let new_x = previous.x.checked_add_signed(dx)?;
+        let new_y = previous.y.checked_add_signed(dy)?;
+
+        if previous.history.contains(&(new_x, new_y)) {
+            return None;
+        }
+
+        if new_x >= self.width || new_y >= self.height || self.grid[new_y][new_x] == '#' {
+            return None;
+        }
+
+        match self.grid[previous.y][previous.x] {
+            '#' => return None,
+            '.' => (),
+            slope => {
+                // Slopes can only be walked down
+                let downhill = Direction::from_arrow(slope).expect("not a valid hiking trail");
+                if traveling != downhill {
+                    return None;
+                }
+            }
+        }
+
+        let mut history = previous.history.clone();
+        history.insert((new_x, new_y));
+        return Some(Step {
+            x: new_x,
+            y: new_y,
+            count: previous.count + 1,
+            history,
+        });
+    }
+
+    pub fn get_start(&self) -> (usize, usize) {
+        for x in 0..self.width {
+            if self.grid[0][x] == '.' {
+                return (x, 0);
+            }
+        }
+        return (0, 0);
+    }
+
+    pub fn get_end(&self) -> (usize, usize) {
+        for x in 0..self.width {
+            if self.grid[self.height - 1][x] == '.' {
+                return (x, self.height - 1);
+            }
+        }
+        return (0, 0);
+    }
+
+    pub fn get_junctions(&self) -> Junctions {
+        let (sx, sy) = self.get_start();
+        let (ex, ey) = self.get_end();
+
+        let mut paths: Graph<Pos, usize> = Graph::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.grid[y][x] == '#' {
+                    continue;
+                }
+                let p = paths.node(&Pos::new(x, y));
+                for direction in Direction::ALL {
+                    let Some(np) = direction.step(Pos::new(x, y)) else {
+                        continue;
+                    };
+                    if np.x < self.width && np.y < self.height && self.grid[np.y][np.x] != '#' {
+                        let np = paths.node(&np);
+                        paths.add_edge(p, np, 1);
+                    }
+                }
+            }
+        }
+
+        // Only the start, the end and the junctions between them matter;
+        // every corridor becomes a single edge as long as the corridor
+        let graph = paths.contract(|p| paths.degree(p) != 2);
+        assert!(graph.len() <= 128, "Graph too large for u128 bitmask");
+
+        let mut adj: Vec<Vec<(usize, usize)>> =
+            (0..graph.len()).map(|i| graph.edges(i).to_vec()).collect();
+        let mut adj_masks = vec![0u128; graph.len()];
+        for (i, neighbors) in adj.iter_mut().enumerate() {
+            for &(j, _) in neighbors.iter() {
+                adj_masks[i] |= 1u128 << j;
+            }
+            // Sort neighbors by weight descending to find longer paths sooner
+            neighbors.sort_by_key(|b| std::cmp::Reverse(b.1));
+        }
+
+        let max_edge_weights = adj
+            .iter()
+            .map(|neighbors| neighbors.iter().map(|&(_, w)| w).max().unwrap_or(0))
+            .collect();
+
+        let start_idx = graph
+            .id(&Pos::new(sx, sy))
+            .expect("the start should be a dead end");
+        let end_idx = graph
+            .id(&Pos::new(ex, ey))
+            .expect("the end should be a dead end");
+
+        return Junctions {
+            adj,
+            adj_masks,
+            max_edge_weights,
+            start_idx,
+            end_idx,
+        };
+    }
+}
+
+impl FromStr for HikingTrail {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse_with(s, |c| {
+            matches!(c, '.' | '#' | '^' | '>' | 'v' | '<').then_some(c)
+        })?;
+        let width = tiles.width();
+        let height = tiles.height();
+        let grid = tiles
+            .rows()
+            .map(|row| row.to_vec())
+            .collect::<Vec<Vec<char>>>();
+        return Ok(HikingTrail {
+            grid,
+            width,
+            height,
+        });
+    }
+}
+
+pub struct Junctions {
+    adj: Vec<Vec<(usize, usize)>>,
+    adj_masks: Vec<u128>,
+    max_edge_weights: Vec<usize>,
+    start_idx: usize,
+    end_idx: usize,
+}
+
+impl Junctions {
+    pub fn get_longest(&self) -> usize {
+        let mut max_length = 0;
+
+        // Precompute total potential to avoid redundant calculations
+        let total_potential: usize = self.max_edge_weights.iter().sum();
+
+        self.dfs_recursive(self.start_idx, 0, 0, total_potential, &mut max_length);
+
+        return max_length;
+    }
+
+    fn get_reachable_potential(&self, current: usize, visited: u128) -> (bool, usize) {
+        let target = 1u128 << self.end_idx;
+        let mut seen = visited | (1u128 << current);
+        let mut queue = self.adj_masks[current] & !seen;
+
+        let mut potential = self.max_edge_weights[current];
+        let mut reached_target = (self.adj_masks[current] & !visited) & target != 0;
+
+        seen |= queue;
+        let mut all_reachable = queue;
+        while queue != 0 {
+            let u = queue.trailing_zeros() as usize;
+            queue &= !(1u128 << u);
+
+            let neighbors = self.adj_masks[u] & !seen;
+            if neighbors & target != 0 {
+                reached_target = true;
+            }
+            queue |= neighbors;
+            seen |= neighbors;
+            all_reachable |= neighbors;
+        }
+
+        // Sum weights of all nodes in the reachable component
+        let mut temp = all_reachable;
+        while temp != 0 {
+            let u = temp.trailing_zeros() as usize;
+            temp &= !(1u128 << u);
+            potential += self.max_edge_weights[u];
+        }
+
+        (reached_target, potential)
+    }
+
+    fn dfs_recursive(
+        &self,
+        current: usize,
+        visited: u128,
+        length: usize,
+        remaining_potential: usize,
+        max_length: &mut usize,
+    ) {
+        if current == self.end_idx {
+            *max_length = (*max_length).max(length);
+            return;
+        }
+
+        // Pruning: if current length + theoretical max remaining < current max, stop
+        if length + remaining_potential <= *max_length {
+            return;
+        }
+
+        let mut available_neighbors: u128 = 0;
+        let mut num_neighbors = 0;
+        for &(next, _) in &self.adj[current] {
+            if (visited & (1u128 << next)) == 0 {
+                available_neighbors |= 1u128 << next;
+                num_neighbors += 1;
+            }
+        }
+
+        if num_neighbors == 0 {
+            return;
+        }
+
+        // Reachability and potential pruning - only if we have choices or to check dead ends
+        if num_neighbors > 1 {
+            let (reachable, potential) = self.get_reachable_potential(current, visited);
+            if !reachable || length + potential <= *max_length {
+                return;
+            }
+        }
+
+        let mask = 1u128 << current;
+        let new_visited = visited | mask;
+        let next_potential = remaining_potential - self.max_edge_weights[current];
+
+        for &(next, dist) in &self.adj[current] {
+            if (available_neighbors & (1u128 << next)) != 0 {
+                self.dfs_recursive(next, new_visited, length + dist, next_potential, max_length);
+            }
+        }
+    }
+}
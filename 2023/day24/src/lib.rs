@@ -0,0 +1,206 @@
+use std::str::FromStr;
+
+use aoc_geometry::Vec3;
+use aoc_math::{linalg, rational::Rational};
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<HailStorm, ParseError> {
+    return HailStorm::from_str(input);
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = HailStorm;
+    type Answer1 = usize;
+    type Answer2 = Result<i64, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        return parse(input);
+    }
+
+    fn part1(hail_storm: &Self::Input<'_>) -> usize {
+        return hail_storm.get_collision_count(200000000000000.0, 400000000000000.0);
+    }
+
+    fn part2(hail_storm: &Self::Input<'_>) -> Result<i64, &'static str> {
+        return hail_storm
+            .get_magic_stone()
+            .ok_or("no single rock thrown from whole coordinates hits every hailstone");
+    }
+}
+
+pub fn part1(input: &str, min_bound: f64, max_bound: f64) -> usize {
+    let hail_storm = HailStorm::new(input);
+    return hail_storm.get_collision_count(min_bound, max_bound);
+}
+
+pub fn part2(input: &str) -> Result<i64, &'static str> {
+    return Day24::part2(&HailStorm::new(input));
+}
+
+pub struct HailStorm {
+    hail: Vec<Hail>,
+}
+
+impl HailStorm {
+    pub fn new(str: &str) -> HailStorm {
+        return HailStorm::from_str(str).expect("");
+    }
+
+    fn get_collision_count(&self, min: f64, max: f64) -> usize {
+        let mut collisions = 0;
+        for i in 0..self.hail.len() {
+            for j in i + 1..self.hail.len() {
+                let hail1 = &self.hail[i];
+                let hail2 = &self.hail[j];
+                if let Some(p) = hail1.find_intersection(hail2) {
+                    if p.x >= min && p.x <= max && p.y >= min && p.y <= max {
+                        collisions += 1;
+                        //println!("intersects in bounds {:?}, {:?} @ {:?}", hail1, hail2, p);
+                    }
+                }
+            }
+        }
+        return collisions;
+    }
+
+    /// The sum of the coordinates the rock starts at, or `None` unless
+    /// exactly one rock thrown from whole coordinates hits every hailstone.
+    fn get_magic_stone(&self) -> Option<i64> {
+        // Seen from the first hailstone, it sits still at the origin, so the
+        // rock must pass through the origin with its position p parallel to
+        // its velocity v, p × v = 0. Hitting each other hailstone as well
+        // means (p - p_i) × (v - v_i) = 0, which without p × v is linear:
+        // p × v_i + p_i × v = p_i × v_i
+        let first = self.hail.first()?;
+        let relative: Vec<(Vec3<i128>, Vec3<i128>)> = self.hail[1..]
+            .iter()
+            .map(|hail| {
+                (
+                    widen(hail.position - first.position),
+                    widen(hail.velocity - first.velocity),
+                )
+            })
+            .collect();
+
+        // The z part of each cross product only involves px, py, vx and vy
+        let (rows, target): (Vec<Vec<Rational>>, Vec<Rational>) = relative
+            .iter()
+            .map(|&(p, v)| {
+                let row = [v.y, -v.x, -p.y, p.x].map(Rational::from).to_vec();
+                (row, Rational::from(p.x * v.y - p.y * v.x))
+            })
+            .unzip();
+        if !pins_down(&rows) {
+            return None;
+        }
+        let xy = linalg::solve(&rows, &target)?;
+        let (px, py, vx) = (&xy[0], &xy[1], &xy[2]);
+
+        // The y part then only has pz and vz left to find
+        let (rows, target): (Vec<Vec<Rational>>, Vec<Rational>) = relative
+            .iter()
+            .map(|&(p, v)| {
+                let row = [v.x, -p.x].map(Rational::from).to_vec();
+                let known = Rational::from(p.z * v.x - p.x * v.z) + px * Rational::from(v.z)
+                    - vx * Rational::from(p.z);
+                (row, known)
+            })
+            .unzip();
+        if !pins_down(&rows) {
+            return None;
+        }
+        let z = linalg::solve(&rows, &target)?;
+        let pz = &z[0];
+
+        let rock = [px, py, pz]
+            .into_iter()
+            .zip([first.position.x, first.position.y, first.position.z])
+            .fold(Rational::zero(), |sum, (offset, start)| {
+                sum + offset + Rational::from(start)
+            });
+        return rock.to_i64();
+    }
+}
+
+/// Whether the equations leave no unknown free. The first few usually do
+/// on their own, which is quicker to check than all of them.
+fn pins_down(rows: &[Vec<Rational>]) -> bool {
+    return linalg::null_space(&rows[..rows.len().min(8)]).is_empty()
+        || linalg::null_space(rows).is_empty();
+}
+
+fn widen(point: Point3D) -> Vec3<i128> {
+    return Vec3::new(point.x.into(), point.y.into(), point.z.into());
+}
+
+fn to_f64(point: Point3D) -> Vec3<f64> {
+    return Vec3::new(point.x as f64, point.y as f64, point.z as f64);
+}
+
+impl FromStr for HailStorm {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hail = s
+            .lines()
+            .map(|line| Hail::from_str(line).within(s, line))
+            .collect::<Result<Vec<Hail>, ParseError>>()?;
+        return Ok(HailStorm { hail });
+    }
+}
+
+type Point3D = Vec3<i64>;
+
+#[derive(Debug, Clone)]
+struct Hail {
+    position: Point3D,
+    velocity: Point3D,
+}
+
+impl Hail {
+    // Find the intersection point with another line
+    fn find_intersection(&self, other: &Hail) -> Option<Vec3<f64>> {
+        let (p1, v1) = (to_f64(self.position), to_f64(self.velocity));
+        let (p2, v2) = (to_f64(other.position), to_f64(other.velocity));
+
+        // Solve for t values at the intersection point
+        let det = v1.x * v2.y - v1.y * v2.x;
+
+        if det.abs() < 1e-10 {
+            // Lines are parallel
+            None
+        } else {
+            let t1 = ((p2.x - p1.x) * v2.y - (p2.y - p1.y) * v2.x) / det;
+            let t2 = ((p2.x - p1.x) * v1.y - (p2.y - p1.y) * v1.x) / det;
+
+            // Check if the intersection point is within the line segments
+            if t1 >= 0.0 && t2 >= 0.0 {
+                Some(p1 + v1 * t1)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl FromStr for Hail {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position_str, velocity_str) = aoc_parse::split_once(s, s, "@")?;
+
+        return Ok(Hail {
+            position: Point3D::from_str(position_str).within(s, position_str)?,
+            velocity: Point3D::from_str(velocity_str).within(s, velocity_str)?,
+        });
+    }
+}
+
+#[test]
+pub fn part1_test1() {
+    let ans = part1(include_str!("../input/test1.txt"), 7.0, 27.0);
+    assert_eq!(ans, 2);
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+use aoc_solution::{NoPuzzle, Solution};
+
+pub fn parse(input: &str) -> Result<WireDiagram, ParseError> {
+    return WireDiagram::from_str(input);
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = WireDiagram;
+    type Answer1 = usize;
+    type Answer2 = NoPuzzle;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(wire_diagram: &Self::Input<'_>) -> usize {
+        wire_diagram.get_sum()
+    }
+
+    fn part2(_: &Self::Input<'_>) -> NoPuzzle {
+        NoPuzzle
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day25::part1(&WireDiagram::new(input))
+}
+
+pub struct WireDiagram {
+    graph: Graph<String, u64>,
+}
+
+impl WireDiagram {
+    pub fn new(str: &str) -> WireDiagram {
+        WireDiagram::from_str(str).expect("")
+    }
+
+    fn get_sum(&self) -> usize {
+        let n = self.graph.len();
+
+        // Try each vertex as the sink until one is on the far side of a cut
+        // of size 3
+        let source = 0;
+        for sink in 1..n {
+            let flow = self.graph.max_flow(source, sink);
+            if flow.value == 3 {
+                let partition = flow.source_side.len();
+                return partition * (n - partition);
+            }
+        }
+
+        panic!("No cut of size 3 found");
+    }
+}
+
+impl FromStr for WireDiagram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut graph = Graph::new();
+        for line in s.lines() {
+            let (label_str, children_str) = aoc_parse::split_once(s, line, ":")?;
+            let label = graph.node(label_str);
+            for link in children_str.split_whitespace() {
+                let link = graph.node(link);
+                graph.add_undirected_edge(label, link, 1);
+            }
+        }
+        Ok(WireDiagram { graph })
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in input.lines() {
+        let mut numbers = line.split_whitespace();
+        let l = aoc_parse::next(input, line, &mut numbers, "a left location ID")?;
+        let r = aoc_parse::next(input, line, &mut numbers, "a right location ID")?;
+
+        left.push(aoc_parse::number(input, l)?);
+        right.push(aoc_parse::number(input, r)?);
+    }
+
+    Ok((left, right))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((left, right): &Self::Input<'_>) -> i32 {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
+        // Sort both lists
+        left.sort();
+        right.sort();
+
+        // Calculate total distance between paired numbers
+        left.iter()
+            .zip(right.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum()
+    }
+
+    fn part2((left, right): &Self::Input<'_>) -> i32 {
+        // Calculate similarity score
+        left.iter()
+            .map(|&num| {
+                // Count how many times this number appears in right list
+                let count = right.iter().filter(|&&x| x == num).count() as i32;
+                // Multiply number by its count in right list
+                num * count
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day01::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day01::part2(&parse(input).expect("Failed to parse input"))
+}
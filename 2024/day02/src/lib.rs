@@ -0,0 +1,124 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+struct Report {
+    levels: Vec<i32>,
+}
+
+impl Report {
+    fn new(levels: Vec<i32>) -> Self {
+        Report { levels }
+    }
+
+    fn is_safe(&self) -> bool {
+        if self.levels.len() < 2 {
+            return true;
+        }
+
+        // Check if sequence is increasing or decreasing
+        let mut increasing = None;
+
+        for window in self.levels.windows(2) {
+            let diff = window[1] - window[0];
+
+            // Check if difference is between 1 and 3 (inclusive)
+            if diff.abs() < 1 || diff.abs() > 3 {
+                return false;
+            }
+
+            match increasing {
+                None => {
+                    // First pair determines if sequence should be increasing or decreasing
+                    increasing = Some(diff > 0);
+                }
+                Some(should_increase) => {
+                    // Check if direction matches what we expect
+                    if (diff > 0) != should_increase {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    fn can_be_made_safe(&self) -> bool {
+        // If it's already safe, no need to remove anything
+        if self.is_safe() {
+            return true;
+        }
+
+        // Try removing each level one at a time
+        for i in 0..self.levels.len() {
+            let mut modified = self.levels.clone();
+            modified.remove(i);
+            let modified_report = Report::new(modified);
+            if modified_report.is_safe() {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+pub struct ReportAnalyzer {
+    reports: Vec<Report>,
+}
+
+impl ReportAnalyzer {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let reports = input
+            .lines()
+            .map(|line| aoc_parse::numbers(input, line).map(Report::new))
+            .collect::<Result<_, _>>()?;
+        Ok(ReportAnalyzer { reports })
+    }
+
+    fn count_safe_reports(&self) -> usize {
+        self.reports
+            .iter()
+            .filter(|report| report.is_safe())
+            .count()
+    }
+
+    fn count_potentially_safe_reports(&self) -> usize {
+        self.reports
+            .iter()
+            .filter(|report| report.can_be_made_safe())
+            .count()
+    }
+}
+
+pub fn parse(input: &str) -> Result<ReportAnalyzer, ParseError> {
+    ReportAnalyzer::from_input(input)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = ReportAnalyzer;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(analyzer: &Self::Input<'_>) -> usize {
+        analyzer.count_safe_reports()
+    }
+
+    fn part2(analyzer: &Self::Input<'_>) -> usize {
+        analyzer.count_potentially_safe_reports()
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day02::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day02::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,88 @@
+use aoc_solution::{ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// Every instruction in the corrupted memory, skipping the noise around
+/// them. Any text is valid memory, so this can't fail; the operands are
+/// matched as ASCII digits since `\d` would also take other scripts' digits,
+/// which `u32` can't parse.
+pub fn parse(input: &str) -> Vec<Instruction> {
+    let re = Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|do\(\)|don't\(\)").unwrap();
+    re.captures_iter(input)
+        .map(|cap| match &cap[0] {
+            "do()" => Instruction::Do,
+            "don't()" => Instruction::Dont,
+            _ => {
+                let x = cap[1].parse::<u32>().unwrap();
+                let y = cap[2].parse::<u32>().unwrap();
+                Instruction::Mul(x, y)
+            }
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse(input))
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> u32 {
+        find_mul_instructions(instructions)
+            .into_iter()
+            .map(|(x, y)| x * y)
+            .sum()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> u32 {
+        find_mul_instructions_with_state(instructions)
+            .into_iter()
+            .map(|(x, y)| x * y)
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> u32 {
+    Day03::part1(&parse(input))
+}
+
+pub fn part2(input: &str) -> u32 {
+    Day03::part2(&parse(input))
+}
+
+fn find_mul_instructions(program: &[Instruction]) -> Vec<(u32, u32)> {
+    program
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Mul(x, y) => Some((*x, *y)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn find_mul_instructions_with_state(program: &[Instruction]) -> Vec<(u32, u32)> {
+    let mut instructions = Vec::new();
+    let mut enabled = true; // Start with multiplications enabled
+
+    for instruction in program {
+        match instruction {
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+            Instruction::Mul(x, y) if enabled => instructions.push((*x, *y)),
+            Instruction::Mul(..) => {}
+        }
+    }
+
+    instructions
+}
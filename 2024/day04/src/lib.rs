@@ -0,0 +1,81 @@
+use aoc_grid::{Grid, Pos, ADJACENT};
+use aoc_solution::{ParseError, Solution};
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    input.parse()
+}
+
+fn check_direction(grid: &Grid<char>, pos: Pos, dx: isize, dy: isize) -> bool {
+    let mut letters = "XMAS".chars();
+    if letters.next() != Some(grid[pos]) {
+        return false;
+    }
+
+    let mut ray = grid.ray(pos, dx, dy);
+    letters.all(|letter| ray.next().is_some_and(|next| grid[next] == letter))
+}
+
+fn check_xmas(grid: &Grid<char>, pos: Pos) -> bool {
+    // Center must be 'A'
+    if grid[pos] != 'A' {
+        return false;
+    }
+
+    // Check all possible combinations of MAS and SAM in X shape
+    let patterns = [
+        // MAS, MAS
+        [(-1, -1, 'M'), (1, 1, 'S'), (1, -1, 'M'), (-1, 1, 'S')],
+        // MAS, SAM
+        [(-1, -1, 'M'), (1, 1, 'S'), (1, -1, 'S'), (-1, 1, 'M')],
+        // SAM, MAS
+        [(-1, -1, 'S'), (1, 1, 'M'), (1, -1, 'M'), (-1, 1, 'S')],
+        // SAM, SAM
+        [(-1, -1, 'S'), (1, 1, 'M'), (1, -1, 'S'), (-1, 1, 'M')],
+    ];
+
+    patterns.iter().any(|pattern| {
+        pattern.iter().all(|&(dy, dx, expected)| {
+            grid.step(pos, dx, dy)
+                .is_some_and(|next| grid[next] == expected)
+        })
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<char>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> i32 {
+        let mut count = 0;
+
+        for pos in grid.positions() {
+            for &(dx, dy) in &ADJACENT {
+                if check_direction(grid, pos, dx, dy) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> i32 {
+        grid.positions()
+            .filter(|&pos| check_xmas(grid, pos))
+            .count() as i32
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day04::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day04::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,143 @@
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Input {
+    rules: Vec<(i32, i32)>,
+    updates: Vec<Vec<i32>>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (rules_str, updates_str) = aoc_parse::split_once(input, input, "\n\n")?;
+
+    // Parse rules
+    let rules: Vec<(i32, i32)> = rules_str
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (before, after) = aoc_parse::split_once(input, line, "|")?;
+            Ok((
+                aoc_parse::number(input, before)?,
+                aoc_parse::number(input, after)?,
+            ))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    // Parse updates
+    let updates: Vec<Vec<i32>> = updates_str
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split(',')
+                .map(|n| aoc_parse::number(input, n))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Input { rules, updates })
+}
+
+fn is_valid_order(update: &[i32], rules: &[(i32, i32)]) -> bool {
+    // Build a map of positions for O(1) lookup
+    let positions: HashMap<i32, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(i, &num)| (num, i))
+        .collect();
+
+    // Check each applicable rule
+    for &(before, after) in rules {
+        // Skip rules that don't apply to this update
+        if !positions.contains_key(&before) || !positions.contains_key(&after) {
+            continue;
+        }
+
+        // If the rule applies, check that 'before' comes before 'after'
+        if positions[&before] >= positions[&after] {
+            return false;
+        }
+    }
+    true
+}
+
+/// The pages in an order every rule between them agrees with, or which
+/// pages the rules put in a loop.
+fn sort_by_rules(pages: &[i32], rules: &[(i32, i32)]) -> Result<Vec<i32>, String> {
+    let mut graph: Graph<i32> = Graph::new();
+    for page in pages {
+        graph.node(page);
+    }
+
+    // Only consider rules where both pages are in our update
+    for (before, after) in rules {
+        if let (Some(before), Some(after)) = (graph.id(before), graph.id(after)) {
+            graph.add_edge(before, after, ());
+        }
+    }
+
+    match graph.topological_sort() {
+        Ok(order) => Ok(order.into_iter().map(|id| *graph.key(id)).collect()),
+        Err(cycle) => {
+            let pages: Vec<String> = cycle
+                .nodes
+                .iter()
+                .map(|&id| graph.key(id).to_string())
+                .collect();
+            Err(format!(
+                "the rules put pages {} in a loop",
+                pages.join(", ")
+            ))
+        }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Input;
+    type Answer1 = i32;
+    type Answer2 = Result<i32, String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Input<'_>) -> i32 {
+        // Process each update
+        let mut sum = 0;
+        for update in &data.updates {
+            if is_valid_order(update, &data.rules) {
+                // For valid updates, add the middle number
+                let mid_idx = update.len() / 2;
+                sum += update[mid_idx];
+            }
+        }
+
+        sum
+    }
+
+    fn part2(data: &Self::Input<'_>) -> Result<i32, String> {
+        // Process each update
+        let mut sum = 0;
+        for update in &data.updates {
+            if !is_valid_order(update, &data.rules) {
+                // For invalid updates, sort them and get the middle number
+                let sorted = sort_by_rules(update, &data.rules)?;
+                let mid_idx = sorted.len() / 2;
+                sum += sorted[mid_idx];
+            }
+        }
+
+        Ok(sum)
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day05::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> Result<i32, String> {
+    Day05::part2(&parse(input).expect("Failed to parse input"))
+}
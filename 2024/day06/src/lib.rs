@@ -0,0 +1,109 @@
+use aoc_cycle::loops;
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_solution::{ParseError, Solution};
+use std::collections::HashSet;
+
+fn is_obstacle(grid: &Grid<char>, pos: Pos) -> bool {
+    grid[pos] == '#'
+}
+
+fn simulate_guard_movement(
+    grid: &Grid<char>,
+    start_pos: Pos,
+    start_dir: Direction,
+) -> HashSet<Pos> {
+    let mut visited = HashSet::new();
+    let mut current_pos = start_pos;
+    let mut current_dir = start_dir;
+
+    visited.insert(current_pos);
+
+    while let Some(next_pos) = grid.neighbor(current_pos, current_dir) {
+        if is_obstacle(grid, next_pos) {
+            current_dir = current_dir.turn_right();
+        } else {
+            current_pos = next_pos;
+            visited.insert(current_pos);
+        }
+
+        if visited.len() > grid.len() * 4 {
+            break;
+        }
+    }
+
+    visited
+}
+
+/// Whether the guard walks in a loop with an extra obstacle at
+/// `obstacle_pos`, rather than off the edge of the map.
+fn check_loop(grid: &Grid<char>, start_pos: Pos, start_dir: Direction, obstacle_pos: Pos) -> bool {
+    loops((start_pos, start_dir), |&(pos, dir)| {
+        let next_pos = grid.neighbor(pos, dir)?;
+        if next_pos == obstacle_pos || is_obstacle(grid, next_pos) {
+            Some((pos, dir.turn_right()))
+        } else {
+            Some((next_pos, dir))
+        }
+    })
+}
+
+pub fn parse(input: &str) -> Result<(Grid<char>, Pos, Direction), ParseError> {
+    let grid: Grid<char> = input.parse()?;
+    let start_pos = grid
+        .find(&'^')
+        .ok_or_else(|| ParseError::end(input, "a guard '^'"))?;
+    Ok((grid, start_pos, Direction::North))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = (Grid<char>, Pos, Direction);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&(ref grid, start_pos, start_dir): &Self::Input<'_>) -> i32 {
+        let visited = simulate_guard_movement(grid, start_pos, start_dir);
+        visited.len() as i32
+    }
+
+    fn part2(&(ref grid, start_pos, start_dir): &Self::Input<'_>) -> i32 {
+        let mut loop_positions = 0;
+
+        let mut visited_main = vec![false; grid.len()];
+        visited_main[grid.index_of(start_pos)] = true;
+
+        let mut current_pos = start_pos;
+        let mut current_dir = start_dir;
+
+        while let Some(next_pos) = grid.neighbor(current_pos, current_dir) {
+            if is_obstacle(grid, next_pos) {
+                current_dir = current_dir.turn_right();
+            } else {
+                let idx = grid.index_of(next_pos);
+                if !visited_main[idx]
+                    && check_loop(grid, current_pos, current_dir.turn_right(), next_pos)
+                {
+                    loop_positions += 1;
+                }
+
+                visited_main[idx] = true;
+                current_pos = next_pos;
+            }
+        }
+
+        loop_positions
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day06::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day06::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,129 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+#[derive(Debug)]
+pub struct Expression {
+    target: i64,
+    numbers: Vec<i64>,
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Expression, ParseError> {
+    let (target, numbers) = aoc_parse::split_once(input, line, ":")?;
+    let target = aoc_parse::number(input, target.trim())?;
+    let numbers: Vec<i64> = aoc_parse::numbers(input, numbers)?;
+    if numbers.is_empty() {
+        return Err(ParseError::new(input, &line[line.len()..], "a number"));
+    }
+    Ok(Expression {
+        target: target,
+        numbers: numbers,
+    })
+}
+
+fn can_make_target_part1(expr: &Expression) -> bool {
+    fn recurse(target: i64, nums: &[i64]) -> bool {
+        if nums.len() == 1 {
+            return nums[0] == target;
+        }
+
+        let last = nums[nums.len() - 1];
+        let remaining = &nums[..nums.len() - 1];
+
+        if target % last == 0 && recurse(target / last, remaining) {
+            return true;
+        }
+
+        if target > last && recurse(target - last, remaining) {
+            return true;
+        }
+
+        false
+    }
+    recurse(expr.target, &expr.numbers)
+}
+
+fn can_make_target_part2(expr: &Expression) -> bool {
+    fn recurse(target: i64, nums: &[i64]) -> bool {
+        if nums.len() == 1 {
+            return nums[0] == target;
+        }
+
+        let last = nums[nums.len() - 1];
+        let remaining = &nums[..nums.len() - 1];
+
+        if target % last == 0 && recurse(target / last, remaining) {
+            return true;
+        }
+
+        let mut divisor = 1i64;
+        let mut temp = last;
+        loop {
+            divisor *= 10;
+            temp /= 10;
+            if temp == 0 {
+                break;
+            }
+        }
+
+        if target > last && (target - last) % divisor == 0 && recurse(target / divisor, remaining) {
+            return true;
+        }
+
+        if target > last && recurse(target - last, remaining) {
+            return true;
+        }
+
+        false
+    }
+    recurse(expr.target, &expr.numbers)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Expression>, ParseError> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Expression>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(expressions: &Self::Input<'_>) -> i64 {
+        expressions
+            .iter()
+            .map(|expr| {
+                if can_make_target_part1(expr) {
+                    expr.target
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn part2(expressions: &Self::Input<'_>) -> i64 {
+        expressions
+            .iter()
+            .map(|expr| {
+                if can_make_target_part2(expr) {
+                    expr.target
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day07::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day07::part2(&parse(input).expect("Failed to parse input"))
+}
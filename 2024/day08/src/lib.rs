@@ -0,0 +1,114 @@
+use aoc_geometry::Vec2;
+use aoc_solution::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+type Point = Vec2<i32>;
+
+#[derive(Debug)]
+pub struct Grid {
+    antennas: HashMap<char, Vec<Point>>,
+    width: i32,
+    height: i32,
+}
+
+impl Grid {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let cells = aoc_grid::Grid::parse_with(input, Some)?;
+        let height = cells.height() as i32;
+        let width = cells.width() as i32;
+        let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+
+        for (y, row) in cells.rows().enumerate() {
+            for (x, &ch) in row.iter().enumerate() {
+                if ch != '.' {
+                    antennas
+                        .entry(ch)
+                        .or_default()
+                        .push(Point::new(x as i32, y as i32));
+                }
+            }
+        }
+
+        Ok(Grid {
+            antennas,
+            width,
+            height,
+        })
+    }
+
+    fn calculate_antinodes(&self, num_steps: i32) -> HashSet<Point> {
+        let mut antinodes = HashSet::new();
+
+        // For each frequency
+        for positions in self.antennas.values() {
+            // For each pair of antennas with the same frequency
+            for i in 0..positions.len() - 1 {
+                for j in (i + 1)..positions.len() {
+                    let a1 = positions[i];
+                    let a2 = positions[j];
+
+                    // Step away from each antenna, along the line between them
+                    let step = a2 - a1;
+                    antinodes.extend(self.generate_points_in_direction(a1, -step, num_steps));
+                    antinodes.extend(self.generate_points_in_direction(a2, step, num_steps));
+                }
+            }
+        }
+
+        antinodes
+    }
+
+    fn generate_points_in_direction(
+        &self,
+        start: Point,
+        step: Point,
+        num_steps: i32,
+    ) -> Vec<Point> {
+        let in_bounds = |p: Point| p.x >= 0 && p.x < self.width && p.y >= 0 && p.y < self.height;
+        (1..=num_steps)
+            .map(|n| start + step * n)
+            .take_while(|&p| in_bounds(p))
+            .collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    Grid::from_str(input)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Grid;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> i32 {
+        let antinodes = grid.calculate_antinodes(1);
+        antinodes.len() as i32
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> usize {
+        let antinodes = grid.calculate_antinodes(100);
+
+        let antennas: HashSet<Point> = grid
+            .antennas
+            .values()
+            .flat_map(|v| v.iter().copied())
+            .collect();
+
+        antinodes.len() + antennas.difference(&antinodes).count()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day08::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day08::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,105 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashMap;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    aoc_parse::numbers(input, input.lines().next().unwrap_or(""))
+}
+
+// If the stone is engraved with the number 0, it is replaced by a stone engraved with the number 1.
+// If the stone is engraved with a number that has an even number of digits, it is replaced by two stones. The left half of the digits are engraved on the new left stone, and the right half of the digits are engraved on the new right stone. (The new numbers don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
+// If none of the other rules apply, the stone is replaced by a new stone; the old stone's number multiplied by 2024 is engraved on the new stone.
+fn transform_single_stone(stone: i64) -> Vec<i64> {
+    if stone == 0 {
+        // Rule 1: 0 becomes 1
+        vec![1]
+    } else if stone.to_string().len().is_multiple_of(2) {
+        // Rule 2: Even number of digits splits into two stones
+        let num_digits = (stone as f64).log10().floor() as i32 + 1;
+        let divisor = 10_i64.pow((num_digits / 2) as u32);
+        let right_num = stone % divisor;
+        let left_num = stone / divisor;
+        vec![left_num, right_num]
+    } else {
+        // Rule 3: Multiply by 2024
+        vec![stone * 2024]
+    }
+}
+
+fn transform_stones(stones: Vec<i64>) -> Vec<i64> {
+    stones
+        .into_iter()
+        .flat_map(transform_single_stone)
+        .collect()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(stones: &Self::Input<'_>) -> i32 {
+        let mut stones = stones.clone();
+
+        // Blink 25 times
+        for _ in 0..25 {
+            stones = transform_stones(stones);
+        }
+
+        stones.len() as i32
+    }
+
+    fn part2(stones: &Self::Input<'_>) -> i64 {
+        let mut memo = HashMap::new();
+
+        stones
+            .iter()
+            .map(|&stone| count_stones(stone, 0, 75, &mut memo))
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day11::part1(&parse(input).expect("Failed to parse input"))
+}
+
+fn count_stones(
+    stone: i64,
+    depth: usize,
+    max: usize,
+    memo: &mut HashMap<(i64, usize), i64>,
+) -> i64 {
+    if depth == max {
+        return 1;
+    }
+
+    let key = (stone, depth);
+    if let Some(&cached) = memo.get(&key) {
+        return cached;
+    }
+
+    let stones = if stone == 0 {
+        count_stones(1, depth + 1, max, memo)
+    } else if stone.to_string().len().is_multiple_of(2) {
+        let num_digits = (stone as f64).log10().floor() as u32 + 1;
+        let divisor = 10_i64.pow(num_digits / 2);
+        let right_num = stone % divisor;
+        let left_num = stone / divisor;
+        count_stones(left_num, depth + 1, max, memo) + count_stones(right_num, depth + 1, max, memo)
+    } else {
+        count_stones(stone * 2024, depth + 1, max, memo)
+    };
+
+    memo.insert(key, stones);
+    stones
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day11::part2(&parse(input).expect("Failed to parse input"))
+}
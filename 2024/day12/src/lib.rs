@@ -0,0 +1,186 @@
+use aoc_geometry::Vec2;
+use aoc_grid::Direction;
+use aoc_solution::{ParseError, Solution};
+use std::collections::HashSet;
+
+type Grid = Vec<Vec<char>>;
+type Region = HashSet<Point>;
+
+type Point = Vec2<i32>;
+
+/// The garden map, a plant type letter per plot, with every row as long as
+/// the first.
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let plots = aoc_grid::Grid::parse_with(input, |c| c.is_ascii_uppercase().then_some(c))?;
+    Ok(plots.rows().map(<[char]>::to_vec).collect())
+}
+
+fn get_neighbors(point: Point, grid: &Grid) -> Vec<Point> {
+    let rows = grid.len() as i32;
+    let cols = grid[0].len() as i32;
+
+    Direction::ALL
+        .into_iter()
+        .map(|direction| neighbor(point, direction))
+        .filter(|p| p.y >= 0 && p.y < rows && p.x >= 0 && p.x < cols)
+        .collect()
+}
+
+fn find_region(start: Point, grid: &Grid) -> Region {
+    let mut region = Region::new();
+    let mut stack = vec![start];
+    let target_char = grid[start.y as usize][start.x as usize];
+
+    while let Some(point) = stack.pop() {
+        if !region.insert(point) {
+            continue;
+        }
+
+        stack.extend(get_neighbors(point, grid).into_iter().filter(|&neighbor| {
+            grid[neighbor.y as usize][neighbor.x as usize] == target_char
+                && !region.contains(&neighbor)
+        }));
+    }
+
+    region
+}
+
+fn calculate_perimeter(region: &Region) -> i32 {
+    let mut perimeter = 0;
+
+    for &point in region.iter() {
+        for direction in Direction::ALL {
+            if !region.contains(&neighbor(point, direction)) {
+                perimeter += 1;
+            }
+        }
+    }
+
+    perimeter
+}
+
+fn count_sides(region: &Region) -> i32 {
+    let mut continuous_sides = 0;
+
+    // Check horizontal sides
+    for &point in region.iter() {
+        // Check if this point has no region point above it
+        let above = neighbor(point, Direction::North);
+        if !region.contains(&above) {
+            // Check if this is the start of a new horizontal side
+            let left = neighbor(point, Direction::West);
+            if !region.contains(&left) || !is_exposed_edge(left, Direction::North, region) {
+                continuous_sides += 1;
+            }
+        }
+
+        // Check if this point has no region point below it
+        let below = neighbor(point, Direction::South);
+        if !region.contains(&below) {
+            // Check if this is the start of a new horizontal side
+            let left = neighbor(point, Direction::West);
+            if !region.contains(&left) || !is_exposed_edge(left, Direction::South, region) {
+                continuous_sides += 1;
+            }
+        }
+    }
+
+    // Check vertical sides
+    for &point in region.iter() {
+        // Check if this point has no region point to the left
+        let left = neighbor(point, Direction::West);
+        if !region.contains(&left) {
+            // Check if this is the start of a new vertical side
+            let above = neighbor(point, Direction::North);
+            if !region.contains(&above) || !is_exposed_edge(above, Direction::West, region) {
+                continuous_sides += 1;
+            }
+        }
+
+        // Check if this point has no region point to the right
+        let right = neighbor(point, Direction::East);
+        if !region.contains(&right) {
+            // Check if this is the start of a new vertical side
+            let above = neighbor(point, Direction::North);
+            if !region.contains(&above) || !is_exposed_edge(above, Direction::East, region) {
+                continuous_sides += 1;
+            }
+        }
+    }
+
+    continuous_sides
+}
+
+fn neighbor(point: Point, direction: Direction) -> Point {
+    let (dx, dy) = direction.delta();
+    point + Point::new(dx as i32, dy as i32)
+}
+
+fn is_exposed_edge(point: Point, direction: Direction, region: &Region) -> bool {
+    if !region.contains(&point) {
+        return false;
+    }
+
+    !region.contains(&neighbor(point, direction))
+}
+
+fn get_regions(grid: &Grid) -> Vec<Region> {
+    let mut visited = Region::new();
+    let mut regions = Vec::new();
+
+    for row in 0..grid.len() {
+        for col in 0..grid[0].len() {
+            let point = Point::new(col as i32, row as i32);
+
+            if !visited.contains(&point) {
+                let region = find_region(point, grid);
+                visited.extend(&region);
+                regions.push(region);
+            }
+        }
+    }
+
+    regions
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Grid;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> i32 {
+        get_regions(grid)
+            .iter()
+            .map(|region| {
+                let area = region.len() as i32;
+                let perimeter = calculate_perimeter(region);
+                area * perimeter
+            })
+            .sum()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> i32 {
+        get_regions(grid)
+            .iter()
+            .map(|region| {
+                let area = region.len() as i32;
+                let sides = count_sides(region);
+                area * sides
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day12::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day12::part2(&parse(input).expect("Failed to parse input"))
+}
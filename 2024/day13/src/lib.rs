@@ -0,0 +1,172 @@
+use aoc_math::{linalg, number::extended_gcd, rational::Rational};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a: Point,
+    button_b: Point,
+    prize: Point,
+}
+
+#[derive(Debug, Clone)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+pub fn parse(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|machine| {
+            let mut lines = machine.lines();
+            let mut parse_coords = |prefix: &str, expected: &str| {
+                let line = aoc_parse::next(input, machine, &mut lines, expected)?;
+                let coords = aoc_parse::strip_prefix(input, line, prefix)?;
+                let (x, y) = aoc_parse::split_once(input, coords, ", ")?;
+                let (_, x) =
+                    aoc_parse::split_once(input, x, if x.contains('+') { "+" } else { "=" })?;
+                let (_, y) =
+                    aoc_parse::split_once(input, y, if y.contains('+') { "+" } else { "=" })?;
+
+                Ok(Point {
+                    x: aoc_parse::number(input, x)?,
+                    y: aoc_parse::number(input, y)?,
+                })
+            };
+
+            Ok(ClawMachine {
+                button_a: parse_coords("Button A: ", "a Button A line")?,
+                button_b: parse_coords("Button B: ", "a Button B line")?,
+                prize: parse_coords("Prize: ", "a Prize line")?,
+            })
+        })
+        .collect()
+}
+
+/// The cheapest presses of A and B that land exactly on the prize, if whole
+/// numbers of each can.
+fn is_solvable_large(machine: &ClawMachine) -> Option<(i64, i64)> {
+    let (a, b, prize) = (&machine.button_a, &machine.button_b, &machine.prize);
+    let rows = [[a.x, b.x], [a.y, b.y]].map(|row| row.map(Rational::from).to_vec());
+    let target = [prize.x, prize.y].map(Rational::from);
+
+    let presses = linalg::solve(&rows, &target)?;
+    if a.x * b.y != a.y * b.x {
+        let a_presses = presses[0].to_i64().filter(|&n| n >= 0)?;
+        let b_presses = presses[1].to_i64().filter(|&n| n >= 0)?;
+        return Some((a_presses, b_presses));
+    }
+
+    // The buttons move along the same line as each other and the prize, so
+    // either axis alone says which presses reach it.
+    if (a.x, b.x) != (0, 0) {
+        cheapest_on_line(a.x, b.x, prize.x)
+    } else if (a.y, b.y) != (0, 0) {
+        cheapest_on_line(a.y, b.y, prize.y)
+    } else {
+        Some((0, 0))
+    }
+}
+
+/// The presses `(a, b)` with `a * a_step + b * b_step == target` that cost
+/// the fewest tokens, or `None` if no whole, non-negative ones do.
+/// `a_step` and `b_step` mustn't both be zero.
+fn cheapest_on_line(a_step: i64, b_step: i64, target: i64) -> Option<(i64, i64)> {
+    let (g, x, y) = extended_gcd(a_step, b_step);
+    if target % g != 0 {
+        return None;
+    }
+
+    // Every solution is (a0 + k * da, b0 + k * db) for some whole k.
+    let (a0, b0) = (x * (target / g), y * (target / g));
+    let (da, db) = (b_step / g, -a_step / g);
+    let (mut low, mut high) = (i64::MIN, i64::MAX);
+    for (start, step) in [(a0, da), (b0, db)] {
+        match step.signum() {
+            1 => low = low.max(-start.div_euclid(step)),
+            -1 => high = high.min(start.div_euclid(-step)),
+            _ if start < 0 => return None,
+            _ => {}
+        }
+    }
+    if low > high {
+        return None;
+    }
+
+    // The cost is linear in k, so the cheapest is at whichever end it falls
+    // towards, and that end is bounded since neither count can go negative.
+    let k = if calculate_tokens(da, db) >= 0 {
+        low
+    } else {
+        high
+    };
+    Some((a0 + k * da, b0 + k * db))
+}
+
+fn calculate_tokens(a_presses: i64, b_presses: i64) -> i64 {
+    a_presses * 3 + b_presses
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<ClawMachine>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(machines: &Self::Input<'_>) -> i64 {
+        let mut total_tokens = 0;
+
+        for machine in machines {
+            if let Some((a_presses, b_presses)) = is_solvable_large(machine) {
+                total_tokens += calculate_tokens(a_presses, b_presses);
+            }
+        }
+
+        total_tokens
+    }
+
+    fn part2(machines: &Self::Input<'_>) -> i64 {
+        let mut total_tokens = 0;
+        let offset = 10000000000000i64;
+
+        for machine in machines {
+            let mut machine = machine.clone();
+            machine.prize.x += offset;
+            machine.prize.y += offset;
+
+            if let Some((a_presses, b_presses)) = is_solvable_large(&machine) {
+                total_tokens += calculate_tokens(a_presses, b_presses);
+            }
+        }
+
+        total_tokens
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day13::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day13::part2(&parse(input).expect("Failed to parse input"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collinear_buttons_take_the_cheapest_presses() {
+        let input = "Button A: X+1, Y+1\nButton B: X+3, Y+3\nPrize: X=6, Y=6\n\n\
+                     Button A: X+2, Y+4\nButton B: X+4, Y+8\nPrize: X=7, Y=14";
+        let machines = parse(input).unwrap();
+        assert_eq!(is_solvable_large(&machines[0]), Some((0, 2)));
+        assert_eq!(is_solvable_large(&machines[1]), None);
+    }
+}
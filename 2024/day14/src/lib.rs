@@ -0,0 +1,212 @@
+use aoc_grid::{Grid, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use aoc_visualize::Simulation;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Point,
+    vel: Point,
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Robot>, Point), ParseError> {
+    let mut lines = input.lines();
+    // Parse grid dimensions from first line
+    let dims = aoc_parse::next(input, input, &mut lines, "the grid dimensions")?;
+    let bounds = parse_point(input, dims)?;
+    if bounds.x <= 0 || bounds.y <= 0 {
+        return Err(ParseError::new(input, dims, "positive grid dimensions"));
+    }
+
+    // Parse robots
+    let robots = lines
+        .map(|line| {
+            let (pos, vel) = aoc_parse::split_once(input, line, " ")?;
+            Ok(Robot {
+                pos: parse_point(input, aoc_parse::strip_prefix(input, pos, "p=")?)?,
+                vel: parse_point(input, aoc_parse::strip_prefix(input, vel, "v=")?)?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((robots, bounds))
+}
+
+fn parse_point(input: &str, s: &str) -> Result<Point, ParseError> {
+    let (x, y) = aoc_parse::split_once(input, s, ",")?;
+    Ok(Point {
+        x: aoc_parse::number(input, x)?,
+        y: aoc_parse::number(input, y)?,
+    })
+}
+
+fn simulate_step(robot: &mut Robot, bounds: Point) {
+    robot.pos.x = (robot.pos.x + robot.vel.x).rem_euclid(bounds.x);
+    robot.pos.y = (robot.pos.y + robot.vel.y).rem_euclid(bounds.y);
+}
+
+fn count_robots_in_quadrants(robots: &[Robot], bounds: Point) -> Vec<i32> {
+    let mut quadrants = vec![0; 4];
+    let mid_x = bounds.x / 2;
+    let mid_y = bounds.y / 2;
+
+    for robot in robots {
+        if robot.pos.x == mid_x || robot.pos.y == mid_y {
+            continue; // Skip robots on the middle lines
+        }
+
+        let quadrant = match (robot.pos.x < mid_x, robot.pos.y < mid_y) {
+            (true, true) => 0,   // Top-left
+            (false, true) => 1,  // Top-right
+            (true, false) => 2,  // Bottom-left
+            (false, false) => 3, // Bottom-right
+        };
+        quadrants[quadrant] += 1;
+    }
+    quadrants
+}
+
+/// Detects if the robots have formed a tree-like pattern by checking for rows with 21 or more consecutive robots.
+/// This pattern emerges when robots align in a way that resembles a Christmas tree shape.
+///
+/// # Arguments
+/// * `robots` - A slice containing all robot positions
+/// * `bounds` - The boundaries of the grid (width and height)
+///
+/// # Returns
+/// * `true` if a tree pattern is detected (21+ consecutive robots in any row)
+/// * `false` otherwise
+fn detect_tree_pattern(robots: &[Robot], bounds: Point) -> bool {
+    // Create a grid to mark robot positions
+    let mut grid = vec![vec![false; bounds.x as usize]; bounds.y as usize];
+
+    // Mark robot positions in grid
+    for robot in robots {
+        grid[robot.pos.y as usize][robot.pos.x as usize] = true;
+    }
+
+    // Check each row for consecutive robots
+    for row in grid {
+        let mut current_consecutive = 0;
+        for has_robot in row {
+            if has_robot {
+                current_consecutive += 1;
+                if current_consecutive >= 21 {
+                    return true;
+                }
+            } else {
+                current_consecutive = 0;
+            }
+        }
+    }
+
+    false
+}
+
+/// The robots patrolling the lobby, a second a frame.
+pub struct Patrol {
+    robots: Vec<Robot>,
+    bounds: Point,
+    seconds: i32,
+    part: u8,
+}
+
+impl Simulation for Patrol {
+    fn frame(&self) -> Grid<char> {
+        let mut frame = Grid::new(self.bounds.x as usize, self.bounds.y as usize, '.');
+        for robot in &self.robots {
+            // Robots only start off the grid if the input put them there.
+            if let Some(cell) = frame.get_mut(Pos::new(robot.pos.x as usize, robot.pos.y as usize))
+            {
+                *cell = '#';
+            }
+        }
+        frame
+    }
+
+    fn step(&mut self) -> bool {
+        let done = match self.part {
+            1 => self.seconds == 100,
+            _ => self.seconds == 10000 || detect_tree_pattern(&self.robots, self.bounds),
+        };
+        if done {
+            return false;
+        }
+        for robot in self.robots.iter_mut() {
+            simulate_step(robot, self.bounds);
+        }
+        self.seconds += 1;
+        true
+    }
+}
+
+/// The robots for the 100 seconds part 1 counts them after, or until they
+/// line up into the Christmas tree for part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let (robots, bounds) = parse(input)?;
+    Ok(Box::new(Patrol {
+        robots,
+        bounds,
+        seconds: 0,
+        part,
+    }))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = (Vec<Robot>, Point);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(&(ref robots, bounds): &Self::Input<'_>) -> i32 {
+        let mut robots = robots.clone();
+
+        // Simulate 100 seconds
+        for _ in 0..100 {
+            for robot in robots.iter_mut() {
+                simulate_step(robot, bounds);
+            }
+        }
+
+        // Calculate safety factor
+        let quadrants = count_robots_in_quadrants(&robots, bounds);
+        quadrants.iter().product()
+    }
+
+    fn part2(&(ref robots, bounds): &Self::Input<'_>) -> i32 {
+        let mut robots = robots.clone();
+
+        // Simulate until we find the tree pattern
+        for i in 1..10000 {
+            for robot in robots.iter_mut() {
+                simulate_step(robot, bounds);
+            }
+
+            let like_tree = detect_tree_pattern(&robots, bounds);
+            if like_tree {
+                return i;
+            }
+        }
+
+        -1 // Pattern not found within limit
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day14::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day14::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,361 @@
+use aoc_grid::Grid;
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+use aoc_visualize::{palette, Simulation};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+impl Cell {
+    fn symbol(self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+            Cell::Box => 'O',
+            Cell::BoxLeft => '[',
+            Cell::BoxRight => ']',
+            Cell::Robot => '@',
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Warehouse {
+    grid: Vec<Vec<Cell>>,
+    robot_pos: Position,
+    //wide_boxes: bool,
+}
+
+#[derive(Clone)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Warehouse {
+    fn new(input: &str, wide_boxes: bool) -> Self {
+        let mut grid = Vec::new();
+        let mut robot_pos = Position { x: 0, y: 0 };
+
+        for (row, line) in input.lines().enumerate() {
+            let mut grid_row = Vec::new();
+            let chars: Vec<char> = line.chars().collect();
+            let mut col = 0;
+            for c in chars {
+                if wide_boxes {
+                    match c {
+                        '#' => {
+                            grid_row.push(Cell::Wall);
+                            grid_row.push(Cell::Wall);
+                            col += 1;
+                        }
+                        'O' => {
+                            grid_row.push(Cell::BoxLeft);
+                            grid_row.push(Cell::BoxRight);
+                            col += 1;
+                        }
+                        '@' => {
+                            robot_pos = Position { x: col, y: row };
+                            grid_row.push(Cell::Robot);
+                            grid_row.push(Cell::Empty);
+                            col += 1;
+                        }
+                        '.' => {
+                            grid_row.push(Cell::Empty);
+                            grid_row.push(Cell::Empty);
+                            col += 1;
+                        }
+                        _ => col += 1,
+                    }
+                } else {
+                    let cell = match c {
+                        '#' => Cell::Wall,
+                        'O' => Cell::Box,
+                        '@' => {
+                            robot_pos = Position { x: col, y: row };
+                            Cell::Robot
+                        }
+                        _ => Cell::Empty,
+                    };
+                    grid_row.push(cell);
+                }
+                col += 1;
+            }
+            if !grid_row.is_empty() {
+                grid.push(grid_row);
+            }
+        }
+
+        Warehouse {
+            grid,
+            robot_pos,
+            //wide_boxes,
+        }
+    }
+
+    fn try_move(&mut self, direction: char) {
+        let (dx, dy) = match direction {
+            '^' => (0, -1),
+            'v' => (0, 1),
+            '<' => (-1, 0),
+            '>' => (1, 0),
+            _ => return,
+        };
+
+        let new_col = (self.robot_pos.x as i32 + dx) as usize;
+        let new_row = (self.robot_pos.y as i32 + dy) as usize;
+
+        // Check if the new position is within bounds
+        if new_row >= self.grid.len() || new_col >= self.grid[0].len() {
+            return;
+        }
+
+        match self.grid[new_row][new_col] {
+            Cell::Empty => {
+                // Move robot
+                self.grid[self.robot_pos.y][self.robot_pos.x] = Cell::Empty;
+                self.grid[new_row][new_col] = Cell::Robot;
+                self.robot_pos = Position {
+                    x: new_col,
+                    y: new_row,
+                };
+            }
+            Cell::Box => {
+                match self.can_push_box(new_row, new_col, dx, dy) {
+                    Some(pos) => {
+                        self.grid[pos.y][pos.x] = Cell::Box;
+                    }
+                    None => return,
+                }
+
+                // Move robot
+                self.grid[self.robot_pos.y][self.robot_pos.x] = Cell::Empty;
+                self.grid[new_row][new_col] = Cell::Robot;
+                self.robot_pos = Position {
+                    x: new_col,
+                    y: new_row,
+                };
+            }
+            Cell::BoxLeft | Cell::BoxRight => {
+                if !self.can_push_wide_box(new_row, new_col, dx, dy) {
+                    return;
+                }
+
+                self.push_wide_box(new_row, new_col, dx, dy);
+
+                self.grid[self.robot_pos.y][self.robot_pos.x] = Cell::Empty;
+                self.grid[new_row][new_col] = Cell::Robot;
+                self.robot_pos = Position {
+                    x: new_col,
+                    y: new_row,
+                };
+            }
+            _ => {} // Wall or other - do nothing
+        }
+    }
+
+    fn can_push_box(&self, row: usize, col: usize, dx: i32, dy: i32) -> Option<Position> {
+        let mut curr_row = row;
+        let mut curr_col = col;
+
+        while curr_row < self.grid.len() && curr_col < self.grid[0].len() {
+            match self.grid[curr_row][curr_col] {
+                Cell::Wall => return None,
+                Cell::Empty => {
+                    return Some(Position {
+                        x: curr_col,
+                        y: curr_row,
+                    })
+                }
+                _ => {}
+            }
+            curr_col = (curr_col as i32 + dx) as usize;
+            curr_row = (curr_row as i32 + dy) as usize;
+        }
+        None
+    }
+
+    fn can_push_wide_box(&self, row: usize, col: usize, dx: i32, dy: i32) -> bool {
+        let curr_cell = self.grid[row][col];
+        if curr_cell == Cell::Empty {
+            return true;
+        }
+        if curr_cell == Cell::Wall {
+            return false;
+        }
+
+        // Check if we're pushing horizontally
+        if dx != 0 {
+            let next_col = (col as i32 + (dx * 2)) as usize;
+            return self.can_push_wide_box(row, next_col, dx, dy);
+        } else {
+            // For vertical movement, both parts must be able to move
+            let partner_col = if curr_cell == Cell::BoxLeft {
+                col + 1
+            } else {
+                col - 1
+            };
+
+            let next_row = (row as i32 + dy) as usize;
+            return self.can_push_wide_box(next_row, partner_col, dx, dy)
+                && self.can_push_wide_box(next_row, col, dx, dy);
+        }
+    }
+
+    fn push_wide_box(&mut self, row: usize, col: usize, dx: i32, dy: i32) {
+        let curr_cell = self.grid[row][col];
+
+        if curr_cell == Cell::Empty {
+            return;
+        }
+
+        // Check if we're pushing horizontally
+        if dx != 0 {
+            let next_col = (col as i32 + dx) as usize;
+            self.push_wide_box(row, next_col, dx, dy);
+            self.grid[row][next_col] = curr_cell;
+        } else {
+            // For vertical movement, both parts must move
+            let partner_col = if curr_cell == Cell::BoxLeft {
+                col + 1
+            } else {
+                col - 1
+            };
+
+            let next_row = (row as i32 + dy) as usize;
+
+            self.push_wide_box(next_row, col, dx, dy);
+            self.push_wide_box(next_row, partner_col, dx, dy);
+
+            self.grid[next_row][col] = curr_cell;
+            self.grid[next_row][partner_col] = self.grid[row][partner_col];
+
+            self.grid[row][col] = Cell::Empty;
+            self.grid[row][partner_col] = Cell::Empty;
+        }
+    }
+
+    fn calculate_gps_sum(&self) -> i32 {
+        let mut sum = 0;
+        for (row, grid_row) in self.grid.iter().enumerate() {
+            for (col, cell) in grid_row.iter().enumerate() {
+                match cell {
+                    Cell::Box => {
+                        sum += (100 * row + col) as i32;
+                    }
+                    Cell::BoxLeft => {
+                        sum += (100 * row + col) as i32;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        sum
+    }
+}
+
+/// The robot working through its moves, one a frame.
+pub struct Moving {
+    warehouse: Warehouse,
+    moves: Vec<char>,
+    next: usize,
+}
+
+impl Simulation for Moving {
+    fn frame(&self) -> Grid<char> {
+        let width = self.warehouse.grid.first().map_or(0, Vec::len);
+        let cells = self.warehouse.grid.iter().flatten();
+        Grid::from_vec(width, cells.map(|cell| cell.symbol()).collect())
+    }
+
+    fn step(&mut self) -> bool {
+        let Some(&direction) = self.moves.get(self.next) else {
+            return false;
+        };
+        self.warehouse.try_move(direction);
+        self.next += 1;
+        true
+    }
+
+    fn color(&self, cell: char) -> [u8; 3] {
+        match cell {
+            'O' | '[' | ']' => [255, 170, 0],
+            _ => palette(cell),
+        }
+    }
+}
+
+/// The robot moving boxes about the warehouse, with the boxes twice as wide
+/// for part 2.
+pub fn simulate(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError> {
+    let (warehouse, moves) = parse(input, part == 2)?;
+    Ok(Box::new(Moving {
+        warehouse,
+        moves: moves.chars().collect(),
+        next: 0,
+    }))
+}
+
+pub fn parse(input: &str, wide_boxes: bool) -> Result<(Warehouse, String), ParseError> {
+    let (map, moves) = aoc_parse::split_once(input, input, "\n\n")?;
+    let tiles =
+        aoc_grid::Grid::parse_with(map, |c| "#O@.".contains(c).then_some(c)).within(input, map)?;
+    if tiles.find(&'@').is_none() {
+        return Err(ParseError::new(input, &map[map.len()..], "a robot '@'"));
+    }
+
+    let warehouse = Warehouse::new(map, wide_boxes);
+    let moves = moves.chars().filter(|c| "^v<>".contains(*c)).collect();
+
+    Ok((warehouse, moves))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    /// The warehouse as drawn, the warehouse with every tile doubled in width, and the moves.
+    type Input<'a> = (Warehouse, Warehouse, String);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (warehouse, moves) = parse(input, false)?;
+        let (wide_warehouse, _) = parse(input, true)?;
+        Ok((warehouse, wide_warehouse, moves))
+    }
+
+    fn part1((warehouse, _, moves): &Self::Input<'_>) -> i32 {
+        let mut warehouse = warehouse.clone();
+
+        for movement in moves.chars() {
+            warehouse.try_move(movement);
+        }
+
+        warehouse.calculate_gps_sum()
+    }
+
+    fn part2((_, warehouse, moves): &Self::Input<'_>) -> i32 {
+        let mut warehouse = warehouse.clone();
+
+        for movement in moves.chars() {
+            warehouse.try_move(movement);
+        }
+
+        warehouse.calculate_gps_sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day15::part1(&Day15::parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day15::part2(&Day15::parse(input).expect("Failed to parse input"))
+}
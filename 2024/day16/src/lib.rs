@@ -0,0 +1,97 @@
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_solution::{ParseError, Solution};
+use std::collections::HashSet;
+
+type StateKey = (Pos, Direction);
+type ParseResult = (Grid<char>, Pos, Pos);
+
+pub fn parse(input: &str) -> Result<ParseResult, ParseError> {
+    let grid: Grid<char> = input.parse()?;
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| ParseError::end(input, "a start tile 'S'"))?;
+    let end = grid
+        .find(&'E')
+        .ok_or_else(|| ParseError::end(input, "an end tile 'E'"))?;
+
+    Ok((grid, start, end))
+}
+
+/// The open tile one step from `pos` in `direction`, if there is one.
+fn step(grid: &Grid<char>, pos: Pos, direction: Direction) -> Option<Pos> {
+    grid.neighbor(pos, direction)
+        .filter(|&next| grid[next] != '#')
+}
+
+/// Moving forward costs 1 and turning on the spot costs 1000.
+fn moves(grid: &Grid<char>, (position, direction): StateKey) -> Vec<(StateKey, i32)> {
+    let mut moves = vec![
+        ((position, direction.turn_left()), 1000),
+        ((position, direction.turn_right()), 1000),
+    ];
+    if let Some(next_pos) = step(grid, position, direction) {
+        moves.push(((next_pos, direction), 1));
+    }
+    moves
+}
+
+fn find_shortest_path(grid: &Grid<char>, start: Pos, end: Pos) -> i32 {
+    // Start facing East as specified
+    aoc_search::dijkstra(
+        [(start, Direction::East)],
+        |&state| moves(grid, state),
+        |&(position, _)| position == end,
+    )
+    .expect("No path found")
+    .cost
+}
+
+#[allow(dead_code)]
+fn print_grid_with_path(grid: &Grid<char>, optimal_tiles: &HashSet<Pos>) {
+    let mut path = grid.clone();
+    for &tile in optimal_tiles {
+        path[tile] = 'O';
+    }
+    println!("{}", path);
+}
+
+fn find_optimal_path_tiles(grid: &Grid<char>, start: Pos, end: Pos) -> HashSet<Pos> {
+    let paths = aoc_search::dijkstra_all(
+        [(start, Direction::East)],
+        |&state| moves(grid, state),
+        |&(position, _)| position == end,
+    )
+    .expect("No path found");
+
+    paths.nodes().map(|&(position, _)| position).collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = ParseResult;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((grid, start, end): &Self::Input<'_>) -> i32 {
+        find_shortest_path(grid, *start, *end)
+    }
+
+    fn part2((grid, start, end): &Self::Input<'_>) -> i32 {
+        let optimal_tiles = find_optimal_path_tiles(grid, *start, *end);
+        //print_grid_with_path(&grid, &optimal_tiles);
+        optimal_tiles.len() as i32
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day16::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day16::part2(&parse(input).expect("Failed to parse input"))
+}
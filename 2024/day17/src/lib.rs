@@ -0,0 +1,198 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Computer, ParseError> {
+    let mut lines = input.lines();
+
+    let mut parse_register = |expected: &str| -> Result<u64, ParseError> {
+        let line = aoc_parse::next(input, input, &mut lines, expected)?;
+        let mut words = line.split_whitespace().skip(2);
+        aoc_parse::number(input, aoc_parse::next(input, line, &mut words, expected)?)
+    };
+
+    let register_a = parse_register("register A")?;
+    let register_b = parse_register("register B")?;
+    let register_c = parse_register("register C")?;
+
+    // Skip the empty line
+    lines.next();
+
+    let line = aoc_parse::next(input, input, &mut lines, "a program line")?;
+    let nums = aoc_parse::next(
+        input,
+        line,
+        &mut line.split_whitespace().skip(1),
+        "a program",
+    )?;
+    let program = nums
+        .split(',')
+        .map(|n| aoc_parse::number(input, n))
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    Ok(Computer {
+        register_a,
+        register_b,
+        register_c,
+        program,
+    })
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    register_a: u64,
+    register_b: u64,
+    register_c: u64,
+    program: Vec<u64>,
+}
+
+impl Computer {
+    fn run_program(&mut self) -> Vec<u64> {
+        let mut output = Vec::new();
+        let mut instruction_pointer = 0;
+
+        while instruction_pointer < self.program.len() as u64 {
+            let opcode = self.program[instruction_pointer as usize];
+            let literal_operand = self.program[instruction_pointer as usize + 1];
+            let combo_operand = match literal_operand {
+                0..=3 => literal_operand,
+                4 => self.register_a,
+                5 => self.register_b,
+                6 => self.register_c,
+                _ => panic!("Invalid opcode: {}", opcode),
+            };
+
+            match opcode {
+                0 => {
+                    // adv
+                    let denominator = 2_u64.pow(combo_operand as u32);
+                    self.register_a /= denominator;
+                    instruction_pointer += 2;
+                }
+                1 => {
+                    // bxl
+                    self.register_b ^= literal_operand;
+                    instruction_pointer += 2;
+                }
+                2 => {
+                    // bst
+                    self.register_b = combo_operand % 8;
+                    instruction_pointer += 2;
+                }
+                3 => {
+                    // jnz
+                    if self.register_a != 0 {
+                        instruction_pointer = literal_operand;
+                    } else {
+                        instruction_pointer += 2;
+                    }
+                }
+                4 => {
+                    // bxc
+                    self.register_b ^= self.register_c;
+                    instruction_pointer += 2;
+                }
+                5 => {
+                    // out
+                    output.push(combo_operand % 8);
+                    instruction_pointer += 2;
+                }
+                6 => {
+                    // bdv
+                    let denominator = 2_u64.pow(combo_operand as u32);
+                    self.register_b = self.register_a / denominator;
+                    instruction_pointer += 2;
+                }
+                7 => {
+                    // cdv
+                    let denominator = 2_u64.pow(combo_operand as u32);
+                    self.register_c = self.register_a / denominator;
+                    instruction_pointer += 2;
+                }
+                _ => panic!("Invalid opcode: {}", opcode),
+            }
+        }
+
+        output
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Computer;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(computer: &Self::Input<'_>) -> String {
+        let mut computer = computer.clone();
+
+        computer
+            .run_program()
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    fn part2(computer: &Self::Input<'_>) -> u64 {
+        let mut x: u64;
+
+        let mut new_a = vec![0; 1];
+        while new_a.len() <= computer.program.len() {
+            x = 0;
+            for j in &new_a {
+                x <<= 3;
+                x += j;
+            }
+
+            let mut altered_computer = computer.clone();
+            altered_computer.register_a = x;
+
+            let out = altered_computer.run_program();
+            if out.len() == new_a.len()
+                && out[0] == computer.program[computer.program.len() - out.len()]
+            {
+                new_a.push(0);
+            } else {
+                let mut last_value = new_a.last_mut().unwrap();
+                *last_value += 1;
+                while *last_value == 8 {
+                    new_a.pop();
+                    if let Some(new_last) = new_a.last_mut() {
+                        *new_last += 1;
+                        last_value = new_last;
+                    } else {
+                        break;
+                    }
+                }
+            }
+
+            let mut altered_computer = computer.clone();
+            altered_computer.register_a = x;
+
+            let out = altered_computer.run_program();
+            if computer.program.len() == out.len()
+                && out
+                    .iter()
+                    .enumerate()
+                    .all(|(i, x)| computer.program[i] == *x)
+            {
+                return x;
+            }
+        }
+
+        0
+    }
+}
+
+pub fn part1(input: &str) -> String {
+    Day17::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> u64 {
+    Day17::part2(&parse(input).expect("Failed to parse input"))
+}
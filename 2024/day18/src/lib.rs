@@ -0,0 +1,155 @@
+use aoc_grid::{Grid, Pos};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+/// The width of the real memory space and how many bytes have fallen into
+/// it for part 1.
+const SPACE: (usize, usize) = (71, 1024);
+/// The same for the puzzle's example, which is the only input whose bytes
+/// all fit in its smaller space.
+const EXAMPLE_SPACE: (usize, usize) = (7, 12);
+
+/// The falling bytes and the memory space they fall into.
+#[derive(Debug)]
+pub struct Memory {
+    bytes: Vec<(usize, usize)>,
+    size: usize,
+    fallen: usize,
+}
+
+pub fn parse(input: &str) -> Result<Memory, ParseError> {
+    let bytes: Vec<(usize, usize)> = input
+        .lines()
+        .map(|line| {
+            let (x, y) = aoc_parse::split_once(input, line, ",")?;
+            let byte = (
+                aoc_parse::number(input, x.trim())?,
+                aoc_parse::number(input, y.trim())?,
+            );
+            if byte.0 >= SPACE.0 || byte.1 >= SPACE.0 {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!("a byte inside the {0}x{0} memory space", SPACE.0),
+                ));
+            }
+            Ok(byte)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let is_example = bytes
+        .iter()
+        .all(|&(x, y)| x < EXAMPLE_SPACE.0 && y < EXAMPLE_SPACE.0);
+    let (size, fallen) = if is_example { EXAMPLE_SPACE } else { SPACE };
+    Ok(Memory {
+        bytes,
+        size,
+        fallen,
+    })
+}
+
+/// The memory space with the first `take` bytes fallen. Bytes that land
+/// outside it are lost.
+fn corrupted(byte_positions: &[(usize, usize)], size: usize, take: usize) -> Grid<char> {
+    let mut grid = Grid::new(size, size, '.');
+
+    // Mark corrupted positions
+    for &(x, y) in byte_positions.iter().take(take) {
+        if let Some(cell) = grid.get_mut(Pos::new(x, y)) {
+            *cell = '#';
+        }
+    }
+    grid
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Memory;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(memory: &Self::Input<'_>) -> i32 {
+        shortest_path_after(&memory.bytes, memory.size, memory.fallen)
+    }
+
+    fn part2(memory: &Self::Input<'_>) -> String {
+        first_blocking_byte(&memory.bytes, memory.size)
+    }
+}
+
+pub fn part1(input: &str, size: usize, take: usize) -> i32 {
+    let memory = parse(input).expect("Failed to parse input");
+    shortest_path_after(&memory.bytes, size, take)
+}
+
+pub fn part2(input: &str, size: usize) -> String {
+    let memory = parse(input).expect("Failed to parse input");
+    first_blocking_byte(&memory.bytes, size)
+}
+
+/// Length of the shortest path across a `size` square grid once the first `take` bytes have fallen.
+fn shortest_path_after(byte_positions: &[(usize, usize)], size: usize, take: usize) -> i32 {
+    let grid = corrupted(byte_positions, size, take);
+
+    find_shortest_path(&grid, Pos::new(0, 0), Pos::new(size - 1, size - 1))
+}
+
+/// Binary searches for the first byte that cuts the exit off from the start.
+fn first_blocking_byte(byte_positions: &[(usize, usize)], size: usize) -> String {
+    let mut step_size = byte_positions.len() / 2;
+    let mut take = step_size;
+    while step_size > 0 {
+        let grid = corrupted(byte_positions, size, take);
+
+        let result = find_shortest_path(&grid, Pos::new(0, 0), Pos::new(size - 1, size - 1));
+
+        step_size /= 2;
+
+        if result == -1 {
+            take -= step_size;
+        } else {
+            take += step_size;
+        }
+    }
+
+    return format!("{},{}", byte_positions[take].0, byte_positions[take].1);
+}
+
+fn find_shortest_path(grid: &Grid<char>, start: Pos, goal: Pos) -> i32 {
+    let path = aoc_search::bfs(
+        [start],
+        |&position| {
+            grid.neighbors4(position)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        },
+        |&position| position == goal,
+    );
+
+    path.map_or(-1, |path| path.cost as i32) // Return -1 if no path is found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test1_part1() {
+        let test_input =
+            fs::read_to_string("input/test1.txt").expect("Should have been able to read the file");
+        assert_eq!(part1(&test_input, 7, 12), 22);
+    }
+
+    #[test]
+    fn test1_part2() {
+        let test_input =
+            fs::read_to_string("input/test1.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&test_input, 7), "6,1");
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct Onsen {
+    pub patterns: Vec<String>, // Available towel patterns
+    pub designs: Vec<String>,  // Desired designs
+}
+
+pub fn parse(input: &str) -> Result<Onsen, ParseError> {
+    let mut lines = input.lines();
+    let patterns = aoc_parse::next(input, input, &mut lines, "the towel patterns")?
+        .split(", ")
+        .map(String::from)
+        .collect();
+    let designs = lines.skip(1).map(String::from).collect();
+    Ok(Onsen { patterns, designs })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Onsen;
+    type Answer1 = i32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(onsen: &Self::Input<'_>) -> i32 {
+        onsen
+            .designs
+            .iter()
+            .filter(|design| {
+                let mut memo = HashMap::new();
+                can_form_design(design, &onsen.patterns, &mut memo)
+            })
+            .count() as i32
+    }
+
+    fn part2(onsen: &Self::Input<'_>) -> u64 {
+        onsen
+            .designs
+            .iter()
+            .map(|design| {
+                let mut memo = HashMap::new();
+                count_ways(design, &onsen.patterns, &mut memo)
+            })
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day19::part1(&parse(input).expect("Failed to parse input"))
+}
+
+fn can_form_design(design: &str, patterns: &[String], memo: &mut HashMap<String, bool>) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    if let Some(&result) = memo.get(design) {
+        return result;
+    }
+
+    for pattern in patterns {
+        if let Some(remaining_design) = design.strip_prefix(pattern) {
+            if can_form_design(remaining_design, patterns, memo) {
+                memo.insert(design.to_string(), true);
+                return true;
+            }
+        }
+    }
+
+    memo.insert(design.to_string(), false);
+    false
+}
+
+pub fn part2(input: &str) -> u64 {
+    Day19::part2(&parse(input).expect("Failed to parse input"))
+}
+
+fn count_ways(design: &str, patterns: &[String], memo: &mut HashMap<String, u64>) -> u64 {
+    if design.is_empty() {
+        return 1; // An empty design can be formed in one way (by using no patterns)
+    }
+
+    if let Some(&result) = memo.get(design) {
+        return result;
+    }
+
+    let total_ways = patterns
+        .iter()
+        .filter_map(|pattern| {
+            design
+                .strip_prefix(pattern)
+                .map(|remaining_design| count_ways(remaining_design, patterns, memo))
+        })
+        .sum();
+
+    memo.insert(design.to_string(), total_ways);
+    total_ways
+}
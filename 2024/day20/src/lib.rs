@@ -0,0 +1,225 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_grid::Grid;
+use aoc_solution::{ParseError, Solution};
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+fn generate_cost_map(grid: &[Vec<char>], start: Position) -> Vec<Vec<i32>> {
+    let directions = vec![(1, 0), (0, 1), (-1, 0), (0, -1)]; // Right, Down, Left, Up
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    visited.insert(start);
+
+    queue.push_back((start, 0));
+    // generate a floodfill cost map
+    let mut cost_map = vec![vec![i32::MAX; grid[0].len()]; grid.len()];
+    cost_map[start.y][start.x] = 0;
+
+    while let Some((pos, time)) = queue.pop_front() {
+        for (dx, dy) in &directions {
+            let new_x = pos.x as isize + dx;
+            let new_y = pos.y as isize + dy;
+
+            if new_x >= 0
+                && new_x < grid[0].len() as isize
+                && new_y >= 0
+                && new_y < grid.len() as isize
+                && grid[new_y as usize][new_x as usize] != '#'
+            {
+                let new_position = Position {
+                    x: new_x as usize,
+                    y: new_y as usize,
+                };
+
+                if visited.insert(new_position) {
+                    cost_map[new_position.y][new_position.x] = time + 1;
+                    queue.push_back((new_position, time + 1));
+                }
+            }
+        }
+    }
+
+    cost_map
+}
+
+fn get_shortest_path(grid: &[Vec<char>], start: Position, end: Position) -> Vec<Position> {
+    let directions = vec![(1, 0), (0, 1), (-1, 0), (0, -1)]; // Right, Down, Left, Up
+    let mut queue = VecDeque::new();
+    let mut visited = HashMap::new();
+
+    queue.push_back(start);
+
+    while let Some(pos) = queue.pop_front() {
+        if pos == end {
+            let mut path = vec![pos];
+            let mut prev = visited[&pos];
+            while prev != start {
+                path.push(prev);
+                prev = visited[&prev];
+            }
+            path.push(start);
+            return path;
+        }
+        for (dx, dy) in &directions {
+            let new_x = pos.x as isize + dx;
+            let new_y = pos.y as isize + dy;
+
+            if new_x >= 0
+                && new_x < grid[0].len() as isize
+                && new_y >= 0
+                && new_y < grid.len() as isize
+                && grid[new_y as usize][new_x as usize] != '#'
+            {
+                let new_position = Position {
+                    x: new_x as usize,
+                    y: new_y as usize,
+                };
+
+                if visited.contains_key(&new_position) {
+                    continue;
+                }
+                visited.insert(new_position, pos);
+                queue.push_back(new_position);
+            }
+        }
+    }
+
+    vec![]
+}
+
+#[derive(Debug)]
+pub struct Racetrack {
+    grid: Vec<Vec<char>>,
+    start: Position,
+    end: Position,
+}
+
+/// The track map, with exactly one start `S` and end `E`.
+pub fn parse(input: &str) -> Result<Racetrack, ParseError> {
+    let cells = Grid::parse_with(input, |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))?;
+    let find = |marker: char| {
+        let mut found = cells.iter().filter(|&(_, &cell)| cell == marker);
+        match (found.next(), found.next()) {
+            (Some((pos, _)), None) => Ok(Position { x: pos.x, y: pos.y }),
+            (None, _) => Err(ParseError::end(
+                input,
+                format!("{:?} somewhere on the map", marker),
+            )),
+            (Some(_), Some((pos, _))) => {
+                // Every cell is ASCII, so the column is the byte offset too
+                let line = input.lines().nth(pos.y).unwrap();
+                let error = ParseError::at(line, pos.x, 1, format!("only one {:?}", marker));
+                Err(error.within(input, line))
+            }
+        }
+    };
+    let start = find('S')?;
+    let end = find('E')?;
+    let grid = cells.rows().map(<[char]>::to_vec).collect();
+
+    Ok(Racetrack { grid, start, end })
+}
+
+// Part 1 is part 2 with cheats limited to two picoseconds.
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Racetrack;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(racetrack: &Self::Input<'_>) -> i32 {
+        count_cheats(racetrack, 100, 2)
+    }
+
+    fn part2(racetrack: &Self::Input<'_>) -> i32 {
+        count_cheats(racetrack, 100, 20)
+    }
+}
+
+pub fn part1(input: &str, time_limit: i32) -> i32 {
+    part2(input, time_limit, 2)
+}
+
+pub fn part2(input: &str, time_limit: i32, distance: i32) -> i32 {
+    count_cheats(
+        &parse(input).expect("Failed to parse input"),
+        time_limit,
+        distance,
+    )
+}
+
+/// Counts the cheats of at most `distance` picoseconds that save at least `time_limit` picoseconds.
+fn count_cheats(racetrack: &Racetrack, time_limit: i32, distance: i32) -> i32 {
+    let Racetrack { grid, start, end } = racetrack;
+    let (start, end) = (*start, *end);
+
+    // generate a floodfill cost map
+    let end_cost_map = generate_cost_map(grid, end);
+    let start_cost_map = generate_cost_map(grid, start);
+
+    let start_cost = end_cost_map[start.y][start.x];
+    let shortest_path = get_shortest_path(grid, start, end);
+    let mut total_cheats = 0; // Counter for cheats saving at least 100 picoseconds
+
+    // Check for possible cheats
+    let mut distinct_cheats = HashSet::new();
+    for step in shortest_path.iter().rev() {
+        for cheat_x in -distance..=distance {
+            for cheat_y in (-distance + cheat_x.abs())..=(distance - cheat_x.abs()) {
+                let new_x = step.x as i32 + cheat_x;
+                let new_y = step.y as i32 + cheat_y;
+                if new_x < 0
+                    || new_y < 0
+                    || new_x >= grid[0].len() as i32
+                    || new_y >= grid.len() as i32
+                    || grid[new_y as usize][new_x as usize] == '#'
+                {
+                    continue;
+                }
+                // Loop through all possible cheat times
+                let destination = Position {
+                    x: new_x as usize,
+                    y: new_y as usize,
+                };
+                let shortcut_cost =
+                    start_cost_map[step.y][step.x] + end_cost_map[destination.y][destination.x];
+                let saved_time = start_cost - shortcut_cost - cheat_x.abs() - cheat_y.abs();
+                if saved_time >= time_limit && distinct_cheats.insert((step, destination)) {
+                    total_cheats += 1;
+                }
+            }
+        }
+    }
+
+    total_cheats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test1_part1() {
+        let test_input =
+            fs::read_to_string("input/test1.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&test_input, 20, 2), 5);
+    }
+
+    #[test]
+    fn test1_part2() {
+        let test_input =
+            fs::read_to_string("input/test1.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&test_input, 74, 6), 1);
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .map(|line| aoc_parse::number(input, line))
+        .collect()
+}
+
+fn mix(secret: i64, value: i64) -> i64 {
+    secret ^ value
+}
+
+fn prune(secret: i64) -> i64 {
+    secret % 16777216
+}
+
+fn generate_next_secret(secret: i64) -> i64 {
+    let mut result = secret;
+
+    // Step 1: multiply by 64
+    result = mix(result, result * 64);
+    result = prune(result);
+
+    // Step 2: divide by 32
+    result = mix(result, result / 32);
+    result = prune(result);
+
+    // Step 3: multiply by 2048
+    result = mix(result, result * 2048);
+    result = prune(result);
+
+    result
+}
+
+fn generate_nth_secret(initial: i64, n: usize) -> i64 {
+    let mut secret = initial;
+    for _ in 0..n {
+        secret = generate_next_secret(secret);
+    }
+    secret
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> i64 {
+        numbers
+            .iter()
+            .map(|&secret| generate_nth_secret(secret, 2000))
+            .sum()
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> i32 {
+        let mut sequence_price_sums = vec![0; 130321]; // Stores accumulated prices for each sequence pattern
+        let mut seen = vec![u16::MAX; 130321];
+
+        // Process each number in the input
+        for (id, &number) in numbers.iter().enumerate() {
+            let id = id as u16;
+            let number = number as usize;
+
+            let zeroth = number;
+            let first = hash(zeroth);
+            let second = hash(first);
+            let third = hash(second);
+
+            let mut a;
+            let mut b = to_index(zeroth, first);
+            let mut c = to_index(first, second);
+            let mut d = to_index(second, third);
+
+            let mut number = third;
+            let mut previous = third % 10;
+
+            // Process the sequence
+            for _ in 3..2000 {
+                number = hash(number);
+                let price = number % 10;
+
+                (a, b, c, d) = (b, c, d, 9 + price - previous);
+                let index = 6859 * a + 361 * b + 19 * c + d;
+
+                if seen[index] != id {
+                    sequence_price_sums[index] += price as u16;
+                    seen[index] = id;
+                }
+
+                previous = price;
+            }
+        }
+
+        // Find the maximum sum
+        *sequence_price_sums.iter().max().unwrap() as i32
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day22::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    Day22::part2(&parse(input).expect("Failed to parse input"))
+}
+
+fn hash(mut n: usize) -> usize {
+    n = (n ^ (n << 6)) & 0xffffff;
+    n = (n ^ (n >> 5)) & 0xffffff;
+    (n ^ (n << 11)) & 0xffffff
+}
+
+/// Convert -9..9 to 0..18.
+fn to_index(previous: usize, current: usize) -> usize {
+    9 + current % 10 - previous % 10
+}
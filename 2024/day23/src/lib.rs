@@ -0,0 +1,77 @@
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::new();
+
+    for line in input.lines() {
+        let (a, b) = aoc_parse::split_once(input, line, "-")?;
+        let a = graph.node(a);
+        let b = graph.node(b);
+        graph.add_undirected_edge(a, b, ());
+    }
+
+    Ok(graph)
+}
+
+fn find_triangles(graph: &Graph) -> Vec<[usize; 3]> {
+    let matrix = graph.adjacency_matrix();
+    let mut triangles = Vec::new();
+
+    for a in 0..graph.len() {
+        for b in graph.neighbors(a).filter(|&b| b > a) {
+            for c in graph.neighbors(b).filter(|&c| c > b) {
+                if matrix.contains(a, c) {
+                    triangles.push([a, b, c]);
+                }
+            }
+        }
+    }
+
+    triangles
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Graph;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> i32 {
+        let triangles = find_triangles(graph);
+
+        triangles
+            .iter()
+            .filter(|triangle| {
+                triangle
+                    .iter()
+                    .any(|&node| graph.key(node).starts_with('t'))
+            })
+            .count() as i32
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> String {
+        let mut clique: Vec<&str> = graph
+            .adjacency_matrix()
+            .max_clique()
+            .into_iter()
+            .map(|node| graph.key(node).as_str())
+            .collect();
+        clique.sort();
+        clique.join(",")
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day23::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> String {
+    Day23::part2(&parse(input).expect("Failed to parse input"))
+}
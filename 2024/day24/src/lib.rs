@@ -0,0 +1,208 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Gate {
+    And(String, String, String), // (input1, input2, output)
+    Or(String, String, String),  // (input1, input2, output)
+    Xor(String, String, String), // (input1, input2, output)
+}
+
+impl Gate {
+    fn inputs(&self) -> (&str, &str) {
+        match self {
+            Gate::And(in1, in2, _) | Gate::Or(in1, in2, _) | Gate::Xor(in1, in2, _) => (in1, in2),
+        }
+    }
+
+    fn output(&self) -> &str {
+        match self {
+            Gate::And(_, _, out) | Gate::Or(_, _, out) | Gate::Xor(_, _, out) => out,
+        }
+    }
+}
+
+type Circuit = HashMap<String, bool>;
+
+pub fn parse(input: &str) -> Result<(Circuit, Vec<Gate>), ParseError> {
+    let mut initial_values = HashMap::new();
+    let mut gates = Vec::new();
+    let mut parsing_gates = false;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            parsing_gates = true;
+            continue;
+        }
+
+        if !parsing_gates {
+            let (wire, value) = aoc_parse::split_once(input, line, ": ")?;
+            initial_values.insert(
+                wire.to_string(),
+                aoc_parse::number::<u8>(input, value)? == 1,
+            );
+        } else {
+            let (gate_def, output) = aoc_parse::split_once(input, line, " -> ")?;
+            let mut parts = gate_def.split_whitespace();
+            let in1 = aoc_parse::next(input, gate_def, &mut parts, "an input wire")?.to_string();
+            let op = aoc_parse::next(input, gate_def, &mut parts, "a gate operator")?;
+            let in2 = aoc_parse::next(input, gate_def, &mut parts, "an input wire")?.to_string();
+            let output = output.trim().to_string();
+
+            let gate = match op {
+                "AND" => Gate::And(in1, in2, output),
+                "OR" => Gate::Or(in1, in2, output),
+                "XOR" => Gate::Xor(in1, in2, output),
+                _ => return Err(ParseError::new(input, op, "AND, OR or XOR")),
+            };
+            gates.push(gate);
+        }
+    }
+
+    Ok((initial_values, gates))
+}
+
+fn simulate_circuit(initial_values: &Circuit, gates: &[Gate]) -> Circuit {
+    let mut values = initial_values.clone();
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+        for gate in gates {
+            let (in1, in2) = gate.inputs();
+            if let (Some(&v1), Some(&v2)) = (values.get(in1), values.get(in2)) {
+                let result = match gate {
+                    Gate::And(_, _, _) => v1 && v2,
+                    Gate::Or(_, _, _) => v1 || v2,
+                    Gate::Xor(_, _, _) => v1 ^ v2,
+                };
+
+                let out = gate.output();
+                if values.get(out).copied() != Some(result) {
+                    values.insert(out.to_string(), result);
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    values
+}
+
+fn calculate_result(values: &Circuit) -> i64 {
+    let mut result = 0;
+    let mut bit = 0;
+
+    while let Some(&value) = values.get(&format!("z{:02}", bit)) {
+        if value {
+            result |= 1 << bit;
+        }
+        bit += 1;
+    }
+
+    result
+}
+
+fn find_highest_z_wire(gates: &[Gate]) -> String {
+    gates
+        .iter()
+        .filter_map(|gate| {
+            let out = gate.output();
+            if out.starts_with('z') {
+                // Parse the number after 'z'
+                out.strip_prefix('z')
+                    .and_then(|num_str| num_str.parse::<u32>().ok())
+                    .map(|num| (num, out.to_string()))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(num, _)| *num)
+        .map(|(_, wire)| wire)
+        .unwrap_or_else(|| "z00".to_string())
+}
+
+fn find_faulty_gates(gates: &[Gate]) -> Vec<String> {
+    let mut faulty_gates = Vec::new();
+    let last_z_wire = find_highest_z_wire(gates);
+
+    for gate in gates {
+        let (in1, in2) = gate.inputs();
+        let out = gate.output();
+
+        // Case 1: Z-wire outputs should use XOR (except final bit)
+        if out.starts_with('z') && out != last_z_wire && !matches!(gate, Gate::Xor(_, _, _)) {
+            faulty_gates.push(out.to_string());
+        }
+
+        // Case 2: Non-z outputs with non-x/y inputs should use AND or OR
+        let has_xy_input = in1.starts_with('x')
+            || in1.starts_with('y')
+            || in2.starts_with('x')
+            || in2.starts_with('y');
+        if !out.starts_with('z') && !has_xy_input && matches!(gate, Gate::Xor(_, _, _)) {
+            faulty_gates.push(out.to_string());
+        }
+
+        // Case 3: XOR gates with x/y inputs (except bit 0) should feed into another XOR
+        if matches!(gate, Gate::Xor(_, _, _))
+            && has_xy_input
+            && !(in1.ends_with("00") && in2.ends_with("00"))
+        {
+            let feeds_into_xor = gates.iter().any(|other| {
+                matches!(other, Gate::Xor(other_in1, other_in2, _) if other_in1 == out || other_in2 == out)
+            });
+            if !feeds_into_xor {
+                faulty_gates.push(out.to_string());
+            }
+        }
+
+        // Case 4: AND gates with x/y inputs (except bit 0) should feed into OR
+        if matches!(gate, Gate::And(_, _, _))
+            && has_xy_input
+            && !(in1.ends_with("00") && in2.ends_with("00"))
+        {
+            let feeds_into_or = gates.iter().any(|other| {
+                matches!(other, Gate::Or(other_in1, other_in2, _) if other_in1 == out || other_in2 == out)
+            });
+            if !feeds_into_or {
+                faulty_gates.push(out.to_string());
+            }
+        }
+    }
+
+    faulty_gates.sort();
+    faulty_gates.dedup();
+    faulty_gates
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = (Circuit, Vec<Gate>);
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1((initial_values, gates): &Self::Input<'_>) -> i64 {
+        let final_values = simulate_circuit(initial_values, gates);
+        calculate_result(&final_values)
+    }
+
+    fn part2((_, gates): &Self::Input<'_>) -> String {
+        let faulty_gates = find_faulty_gates(gates);
+        faulty_gates.join(",")
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day24::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> String {
+    Day24::part2(&parse(input).expect("Failed to parse input"))
+}
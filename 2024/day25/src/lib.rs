@@ -0,0 +1,119 @@
+use aoc_parse::{ParseError, Within};
+use aoc_solution::{NoPuzzle, Solution};
+
+#[derive(Debug)]
+pub struct Pattern {
+    heights: Vec<i32>,
+    is_lock: bool,
+}
+
+fn get_heights(pattern: &[String], is_lock: bool) -> Vec<i32> {
+    let mut heights = vec![];
+    let height = pattern.len() as i32 - 1;
+
+    for col in 0..pattern[0].len() {
+        if is_lock {
+            // For locks, count from top down
+            let mut pin_height = 0;
+            for (row, line) in pattern.iter().enumerate() {
+                if line.chars().nth(col).unwrap() == '#' {
+                    pin_height = row as i32;
+                }
+            }
+            heights.push(pin_height);
+        } else {
+            // For keys, count from bottom up
+            let mut key_height = 0;
+            for (row, line) in pattern.iter().enumerate().rev() {
+                if line.chars().nth(col).unwrap() == '#' {
+                    key_height = height - row as i32;
+                }
+            }
+            heights.push(key_height);
+        }
+    }
+    heights
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = vec![];
+
+    for block in aoc_parse::blocks(input) {
+        let schematic = aoc_grid::Grid::parse_with(block, |c| matches!(c, '#' | '.').then_some(c))
+            .within(input, block)?;
+        let current_pattern: Vec<String> =
+            schematic.rows().map(|row| row.iter().collect()).collect();
+        let is_lock = current_pattern[0].chars().all(|c| c == '#');
+        let heights = get_heights(&current_pattern[..], is_lock);
+        patterns.push(Pattern { heights, is_lock });
+    }
+
+    Ok(patterns)
+}
+
+fn can_fit(lock: &[i32], key: &[i32]) -> bool {
+    lock.iter().zip(key.iter()).all(|(l, k)| l + k < 6)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<Pattern>;
+    type Answer1 = i32;
+    type Answer2 = NoPuzzle;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(patterns: &Self::Input<'_>) -> i32 {
+        let locks: Vec<_> = patterns.iter().filter(|p| p.is_lock).collect();
+        let keys: Vec<_> = patterns.iter().filter(|p| !p.is_lock).collect();
+
+        let mut valid_pairs = 0;
+        for lock in locks {
+            for key in &keys {
+                if can_fit(&lock.heights, &key.heights) {
+                    valid_pairs += 1;
+                }
+            }
+        }
+
+        valid_pairs
+    }
+
+    fn part2(_: &Self::Input<'_>) -> NoPuzzle {
+        NoPuzzle
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day25::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i32 {
+    let _data = parse(input);
+    0 // TODO: Implement part 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test1_part2() {
+        let test_input =
+            fs::read_to_string("input/test1.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&test_input), 0);
+    }
+
+    #[test]
+    fn test2_part2() {
+        let test_input =
+            fs::read_to_string("input/test2.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&test_input), 0);
+    }
+}
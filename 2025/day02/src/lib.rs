@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+#[derive(Debug)]
+pub struct Range {
+    start: i64,
+    end: i64,
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start_str, end_str) = aoc_parse::split_once(s, s.trim(), "-")?;
+
+        let start = aoc_parse::number(s, start_str.trim())?;
+        let end = aoc_parse::number(s, end_str.trim())?;
+
+        Ok(Range { start, end })
+    }
+}
+
+struct PatternGenerator {
+    sub_len: u32,
+    multiplier: i64,
+}
+
+impl PatternGenerator {
+    fn new(len: u32, sub_len: u32) -> Self {
+        let reps = len / sub_len;
+        let shift = 10_i64.pow(sub_len);
+        let mut multiplier = 0_i64;
+        for _ in 0..reps {
+            multiplier = multiplier.saturating_mul(shift).saturating_add(1);
+        }
+        Self {
+            sub_len,
+            multiplier,
+        }
+    }
+
+    fn generate_in_range(&self, range: &Range, found: &mut HashSet<i64>) {
+        // The pattern base P must be of length sub_len
+        // P range: [10^(sub_len-1), 10^sub_len - 1]
+        let p_min = 10_i64.pow(self.sub_len - 1);
+        let p_max = 10_i64.pow(self.sub_len) - 1;
+
+        // P must also satisfy: range.start <= P * multiplier <= range.end
+        // Therefore:
+        // P >= ceil(range.start / multiplier)
+        // P <= floor(range.end / multiplier)
+        let start_p = (range.start + self.multiplier - 1) / self.multiplier;
+        let end_p = range.end / self.multiplier;
+
+        let effective_min = p_min.max(start_p);
+        let effective_max = p_max.min(end_p);
+
+        if effective_min <= effective_max {
+            for p in effective_min..=effective_max {
+                found.insert(p * self.multiplier);
+            }
+        }
+    }
+}
+
+trait LengthStrategy {
+    fn sub_lengths(&self, len: u32) -> Vec<u32>;
+}
+
+struct HalfLengthStrategy;
+
+impl LengthStrategy for HalfLengthStrategy {
+    fn sub_lengths(&self, len: u32) -> Vec<u32> {
+        if len.is_multiple_of(2) {
+            vec![len / 2]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct AnyDivisorStrategy;
+
+impl LengthStrategy for AnyDivisorStrategy {
+    fn sub_lengths(&self, len: u32) -> Vec<u32> {
+        (1..=len / 2).filter(|&sl| len.is_multiple_of(sl)).collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Range>, ParseError> {
+    input
+        .split(',')
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| s.parse().within(input, s))
+        .collect()
+}
+
+fn solve(ranges: &[Range], strategy: impl LengthStrategy) -> i64 {
+    let mut total_sum = 0;
+
+    for range in ranges {
+        let mut found = HashSet::new();
+        let min_len = range.start.checked_ilog10().unwrap_or(0) + 1;
+        let max_len = range.end.checked_ilog10().unwrap_or(0) + 1;
+
+        for len in min_len..=max_len {
+            for sub_len in strategy.sub_lengths(len) {
+                let generator = PatternGenerator::new(len, sub_len);
+                generator.generate_in_range(range, &mut found);
+            }
+        }
+        total_sum += found.iter().sum::<i64>();
+    }
+
+    total_sum
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Range>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Self::Input<'_>) -> i64 {
+        solve(ranges, HalfLengthStrategy)
+    }
+
+    fn part2(ranges: &Self::Input<'_>) -> i64 {
+        solve(ranges, AnyDivisorStrategy)
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day02::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day02::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,96 @@
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+const PART1_SUBSEQUENCE_LEN: usize = 2;
+const PART2_SUBSEQUENCE_LEN: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitSequence {
+    digits: Vec<u8>,
+}
+
+impl DigitSequence {
+    /// A bank of batteries, one joltage digit each.
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        if let Some((i, c)) = s.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::at(s, i, c.len_utf8(), "a digit"));
+        }
+        Ok(Self {
+            digits: s.as_bytes().to_vec(),
+        })
+    }
+
+    pub fn find_largest_subsequence(&self, k: usize) -> Option<u64> {
+        let n = self.digits.len();
+        if n < k {
+            return None;
+        }
+
+        // We want to keep `k` elements, so we can remove `n - k` elements.
+        let mut to_remove = n - k;
+        let mut stack = Vec::with_capacity(k);
+
+        for &digit in &self.digits {
+            // Maintain decreasing monotonic stack property where possible
+            while to_remove > 0 {
+                if let Some(&top) = stack.last() {
+                    if digit > top {
+                        stack.pop();
+                        to_remove -= 1;
+                        continue;
+                    }
+                }
+                break;
+            }
+            stack.push(digit);
+        }
+
+        stack.truncate(k);
+
+        let result = stack
+            .iter()
+            .fold(0u64, |acc, &d| acc * 10 + ((d - b'0') as u64));
+        Some(result)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<DigitSequence>, ParseError> {
+    input
+        .lines()
+        .map(|line| DigitSequence::new(line).within(input, line))
+        .collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<DigitSequence>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(sequences: &Self::Input<'_>) -> u64 {
+        sequences
+            .iter()
+            .filter_map(|seq| seq.find_largest_subsequence(PART1_SUBSEQUENCE_LEN))
+            .sum()
+    }
+
+    fn part2(sequences: &Self::Input<'_>) -> u64 {
+        sequences
+            .iter()
+            .filter_map(|seq| seq.find_largest_subsequence(PART2_SUBSEQUENCE_LEN))
+            .sum()
+    }
+}
+
+pub fn part1(input: &str) -> u64 {
+    Day03::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> u64 {
+    Day03::part2(&parse(input).expect("Failed to parse input"))
+}
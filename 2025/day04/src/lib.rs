@@ -0,0 +1,145 @@
+use aoc_grid::{Grid, Pos};
+use aoc_solution::{ParseError, Solution};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cell {
+    PaperRoll,
+    #[default]
+    Empty,
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        match c {
+            '@' => Cell::PaperRoll,
+            _ => Cell::Empty,
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::PaperRoll => write!(f, "@"),
+            Cell::Empty => write!(f, "."),
+        }
+    }
+}
+
+impl Cell {
+    const fn is_paper_roll(self) -> bool {
+        matches!(self, Cell::PaperRoll)
+    }
+}
+
+trait StabilityRule {
+    fn is_stable(&self, grid: &Grid<Cell>, pos: Pos) -> bool;
+}
+
+fn count_paper_roll_neighbors(grid: &Grid<Cell>, pos: Pos) -> usize {
+    grid.neighbors8(pos)
+        .filter(|&n| grid[n].is_paper_roll())
+        .count()
+}
+
+#[derive(Debug)]
+struct NeighborCountRule {
+    threshold: usize,
+}
+
+impl Default for NeighborCountRule {
+    fn default() -> Self {
+        Self { threshold: 4 }
+    }
+}
+
+impl StabilityRule for NeighborCountRule {
+    fn is_stable(&self, grid: &Grid<Cell>, pos: Pos) -> bool {
+        match grid.get(pos) {
+            Some(cell) if cell.is_paper_roll() => {
+                count_paper_roll_neighbors(grid, pos) >= self.threshold
+            }
+            _ => true,
+        }
+    }
+}
+
+struct Simulator<R: StabilityRule> {
+    grid: Grid<Cell>,
+    stability_rule: R,
+}
+
+impl<R: StabilityRule> Simulator<R> {
+    fn new(grid: Grid<Cell>, stability_rule: R) -> Self {
+        Self {
+            grid,
+            stability_rule,
+        }
+    }
+
+    fn find_unstable(&self) -> Vec<Pos> {
+        self.grid
+            .iter()
+            .filter(|(pos, cell)| {
+                cell.is_paper_roll() && !self.stability_rule.is_stable(&self.grid, *pos)
+            })
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    fn count_unstable(&self) -> usize {
+        self.find_unstable().len()
+    }
+
+    fn remove_unstable_cells(&mut self) -> usize {
+        let unstable = self.find_unstable();
+        let count = unstable.len();
+        for pos in unstable {
+            self.grid[pos] = Cell::Empty;
+        }
+        count
+    }
+
+    fn count_removable_cells(&mut self) -> usize {
+        std::iter::from_fn(|| {
+            let removed = self.remove_unstable_cells();
+            (removed > 0).then_some(removed)
+        })
+        .sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<Cell>, ParseError> {
+    input.parse()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<Cell>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> usize {
+        let simulator = Simulator::new(grid.clone(), NeighborCountRule::default());
+        simulator.count_unstable()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> usize {
+        let mut simulator = Simulator::new(grid.clone(), NeighborCountRule::default());
+        simulator.count_removable_cells()
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    Day04::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> usize {
+    Day04::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,66 @@
+use aoc_intervals::{Interval, IntervalSet};
+use aoc_solution::{ParseError, Solution};
+
+#[derive(Debug)]
+pub struct ParsedInput {
+    ranges: Vec<Interval<i64>>,
+    ids: Vec<i64>,
+}
+
+pub fn parse(input: &str) -> Option<ParsedInput> {
+    let mut lines = input.lines();
+
+    let ranges: Vec<Interval<i64>> = lines
+        .by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let (start, end) = line.split_once('-')?;
+            let (start, end) = (start.trim().parse().ok()?, end.trim().parse().ok()?);
+            (start <= end).then(|| Interval::inclusive(start, end))
+        })
+        .collect();
+
+    if ranges.is_empty() {
+        return None;
+    }
+
+    let ids: Vec<i64> = lines.filter_map(|line| line.trim().parse().ok()).collect();
+
+    Some(ParsedInput { ranges, ids })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input).ok_or_else(|| ParseError::end(input, "at least one fresh ingredient ID range"))
+    }
+
+    fn part1(parsed: &Self::Input<'_>) -> i32 {
+        let merged_ranges: IntervalSet<i64> = parsed.ranges.iter().copied().collect();
+
+        parsed
+            .ids
+            .iter()
+            .filter(|&&id| merged_ranges.contains(id))
+            .count() as i32
+    }
+
+    fn part2(parsed: &Self::Input<'_>) -> i64 {
+        let merged_ranges: IntervalSet<i64> = parsed.ranges.iter().copied().collect();
+
+        merged_ranges.len()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day05::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day05::part2(&parse(input).expect("Failed to parse input"))
+}
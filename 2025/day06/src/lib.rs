@@ -0,0 +1,187 @@
+use aoc_solution::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn apply(&self, numbers: &[i64]) -> i64 {
+        match self {
+            Operation::Add => numbers.iter().sum(),
+            Operation::Multiply => numbers.iter().product(),
+        }
+    }
+}
+
+trait NumberParser {
+    fn extract_numbers(&self, grid: &[Vec<char>]) -> Vec<i64>;
+}
+
+struct RowParser;
+impl NumberParser for RowParser {
+    fn extract_numbers(&self, grid: &[Vec<char>]) -> Vec<i64> {
+        grid.iter()
+            .filter_map(|row| {
+                let s: String = row.iter().collect();
+                s.trim().parse().ok()
+            })
+            .collect()
+    }
+}
+
+struct ColumnParser;
+impl NumberParser for ColumnParser {
+    fn extract_numbers(&self, grid: &[Vec<char>]) -> Vec<i64> {
+        if grid.is_empty() {
+            return Vec::new();
+        }
+
+        let width = grid[0].len();
+        (0..width)
+            .rev()
+            .filter_map(|x| {
+                let s: String = grid
+                    .iter()
+                    .filter_map(|row| row.get(x).filter(|c| c.is_ascii_digit()))
+                    .collect();
+                if s.is_empty() {
+                    None
+                } else {
+                    s.parse().ok()
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct Problem {
+    grid: Vec<Vec<char>>,
+    op: Option<Operation>,
+}
+
+impl Problem {
+    fn solve<P: NumberParser>(&self, parser: P) -> i64 {
+        if let Some(op) = self.op {
+            let numbers = parser.extract_numbers(&self.grid);
+            op.apply(&numbers)
+        } else {
+            0
+        }
+    }
+}
+
+/// Rows of digits and spaces, then a row of operators whose first column
+/// starts the first problem.
+fn check_worksheet(input: &str) -> Result<(), ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((operators, numbers)) = lines.split_last() else {
+        return Err(ParseError::end(input, "a worksheet"));
+    };
+    let bad_char = |line: &str, allowed: fn(char) -> bool, expected: &str| {
+        line.char_indices()
+            .find(|&(_, c)| !allowed(c))
+            .map(|(i, c)| ParseError::at(line, i, c.len_utf8(), expected).within(input, line))
+    };
+
+    for line in numbers {
+        if let Some(error) = bad_char(
+            line,
+            |c| c.is_ascii_digit() || c == ' ',
+            "a digit or a space",
+        ) {
+            return Err(error);
+        }
+    }
+    if let Some(error) = bad_char(
+        operators,
+        |c| matches!(c, '+' | '*' | ' '),
+        "'+', '*' or a space",
+    ) {
+        return Err(error);
+    }
+    if !operators.starts_with(['+', '*']) {
+        return Err(ParseError::at(operators, 0, 1, "an operator").within(input, operators));
+    }
+    Ok(())
+}
+
+pub fn parse(input: &str) -> Result<Vec<Problem>, ParseError> {
+    check_worksheet(input)?;
+    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let max_len = grid.iter().map(|r| r.len()).max().unwrap_or(0);
+
+    // Use the last line to identify problem boundaries based on operators
+    let last_row = grid.last().unwrap();
+    let split_indices: Vec<usize> = last_row
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &c)| if c == '+' || c == '*' { Some(i) } else { None })
+        .collect();
+
+    let mut problems = Vec::new();
+
+    for i in 0..split_indices.len() {
+        let start = split_indices[i];
+        let end = if i + 1 < split_indices.len() {
+            split_indices[i + 1]
+        } else {
+            max_len
+        };
+
+        if start >= max_len {
+            continue;
+        }
+
+        let mut prob_grid = Vec::with_capacity(grid.len());
+        for row in &grid {
+            let mut sub_row = Vec::with_capacity(end - start);
+            for x in start..end {
+                sub_row.push(*row.get(x).unwrap_or(&' '));
+            }
+            prob_grid.push(sub_row);
+        }
+
+        let op = match grid.last().and_then(|row| row.get(start)) {
+            Some('+') => Some(Operation::Add),
+            Some('*') => Some(Operation::Multiply),
+            _ => None,
+        };
+
+        problems.push(Problem {
+            grid: prob_grid,
+            op,
+        });
+    }
+
+    Ok(problems)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<Problem>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(problems: &Self::Input<'_>) -> i64 {
+        problems.iter().map(|p| p.solve(RowParser)).sum()
+    }
+
+    fn part2(problems: &Self::Input<'_>) -> i64 {
+        problems.iter().map(|p| p.solve(ColumnParser)).sum()
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    Day06::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day06::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,159 @@
+use aoc_grid::Grid;
+use aoc_solution::{ParseError, Solution};
+
+/// The tachyon manifold: empty space and splitters, with the beam entering
+/// at the one `S`, which has to be in the top row.
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let cells = Grid::parse_with(input, |c| matches!(c, '.' | '^' | 'S').then_some(c))?;
+    let mut starts = cells.iter().filter(|&(_, &cell)| cell == 'S');
+    match (starts.next(), starts.next()) {
+        (None, _) => return Err(ParseError::end(input, "'S' in the top row")),
+        // Every cell is ASCII, so the column is the byte offset too
+        (Some((pos, _)), None) if pos.y > 0 => {
+            let line = input.lines().nth(pos.y).unwrap();
+            return Err(
+                ParseError::at(line, pos.x, 1, "'S' only in the top row").within(input, line)
+            );
+        }
+        (Some(_), Some((pos, _))) => {
+            let line = input.lines().nth(pos.y).unwrap();
+            return Err(ParseError::at(line, pos.x, 1, "only one 'S'").within(input, line));
+        }
+        (Some(_), None) => {}
+    }
+    Ok(cells.rows().map(<[char]>::to_vec).collect())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Vec<char>>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> i32 {
+        if grid.is_empty() {
+            return 0;
+        }
+
+        // Find starting position (S)
+        let mut start_col = 0;
+        for line in grid.iter() {
+            for (col, &ch) in line.iter().enumerate() {
+                if ch == 'S' {
+                    start_col = col;
+                    break;
+                }
+            }
+        }
+
+        // Track active beam positions (column indices)
+        let mut active_beams = vec![start_col];
+        let mut split_count = 0;
+
+        // Process each row starting from row 1 (below S)
+        for row_data in grid.iter().skip(1) {
+            let mut new_beams = Vec::new();
+
+            for &col in &active_beams {
+                if col < row_data.len() {
+                    match row_data[col] {
+                        '.' => {
+                            // Beam continues straight down
+                            new_beams.push(col);
+                        }
+                        '^' => {
+                            // Beam splits: create two new beams at left and right
+                            split_count += 1;
+                            if col > 0 {
+                                new_beams.push(col - 1);
+                            }
+                            if col < row_data.len() - 1 {
+                                new_beams.push(col + 1);
+                            }
+                        }
+                        _ => {
+                            // Unknown character, beam stops
+                        }
+                    }
+                }
+            }
+
+            // Remove duplicates (beams that merge at the same position)
+            new_beams.sort();
+            new_beams.dedup();
+            active_beams = new_beams;
+        }
+
+        split_count
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> i64 {
+        if grid.is_empty() {
+            return 0;
+        }
+
+        // Find starting position (S)
+        let mut start_col = 0;
+        for line in grid.iter() {
+            for (col, &ch) in line.iter().enumerate() {
+                if ch == 'S' {
+                    start_col = col;
+                    break;
+                }
+            }
+        }
+
+        // Track the number of timelines at each position (column)
+        // Key: column index, Value: number of timelines at that position
+        let mut timeline_counts: std::collections::HashMap<usize, i64> =
+            std::collections::HashMap::new();
+        timeline_counts.insert(start_col, 1);
+
+        // Process each row starting from row 1 (below S)
+        for row_data in grid.iter().skip(1) {
+            let mut new_counts: std::collections::HashMap<usize, i64> =
+                std::collections::HashMap::new();
+
+            for (&col, &count) in &timeline_counts {
+                if col < row_data.len() {
+                    match row_data[col] {
+                        '.' => {
+                            // All timelines continue straight down
+                            *new_counts.entry(col).or_insert(0) += count;
+                        }
+                        '^' => {
+                            // Each timeline splits into two: left and right
+                            if col > 0 {
+                                *new_counts.entry(col - 1).or_insert(0) += count;
+                            }
+                            if col < row_data.len() - 1 {
+                                *new_counts.entry(col + 1).or_insert(0) += count;
+                            }
+                        }
+                        _ => {
+                            // Unknown character, timelines end
+                        }
+                    }
+                }
+            }
+
+            timeline_counts = new_counts;
+        }
+
+        // Sum all timelines across all final positions
+        timeline_counts.values().sum::<i64>()
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day07::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> i64 {
+    Day07::part2(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,186 @@
+use aoc_graph::UnionFind;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+type Pair = (usize, usize);
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Point {
+    fn from_str(input: &str, s: &str) -> Result<Self, ParseError> {
+        let mut parts = s.split(',');
+        let mut coordinate =
+            |expected| aoc_parse::number(input, aoc_parse::next(input, s, &mut parts, expected)?);
+        Ok(Point {
+            x: coordinate("an x coordinate")?,
+            y: coordinate("a y coordinate")?,
+            z: coordinate("a z coordinate")?,
+        })
+    }
+
+    fn distance_squared(&self, other: &Point) -> i64 {
+        let dx = (self.x - other.x) as i64;
+        let dy = (self.y - other.y) as i64;
+        let dz = (self.z - other.z) as i64;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+#[derive(Clone)]
+pub struct Boxes {
+    points: Vec<Point>,
+}
+
+impl Boxes {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let points: Vec<Point> = input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| Point::from_str(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Boxes { points })
+    }
+
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    fn get_point(&self, index: usize) -> Point {
+        self.points[index]
+    }
+
+    fn sorted_pairs_by_distance(&self) -> Vec<Pair> {
+        let n = self.points.len();
+        let pair_count = n.saturating_sub(1) * n / 2;
+        let mut pairs_with_distance: Vec<(Pair, i64)> = Vec::with_capacity(pair_count);
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let dist_sq = self.points[i].distance_squared(&self.points[j]);
+                pairs_with_distance.push(((i, j), dist_sq));
+            }
+        }
+
+        pairs_with_distance.sort_by_key(|&(_, dist)| dist);
+        pairs_with_distance
+            .into_iter()
+            .map(|(pair, _)| pair)
+            .collect()
+    }
+}
+
+struct WiringPlan {
+    boxes: Boxes,
+    ordered_pairs: Vec<Pair>,
+    circuits: UnionFind,
+}
+
+impl WiringPlan {
+    fn new(boxes: Boxes) -> Self {
+        let ordered_pairs = boxes.sorted_pairs_by_distance();
+        let circuits = UnionFind::new(boxes.len());
+
+        WiringPlan {
+            boxes,
+            ordered_pairs,
+            circuits,
+        }
+    }
+
+    fn connect_first_n(&mut self, count: usize) {
+        for &(x, y) in self.ordered_pairs.iter().take(count) {
+            self.circuits.union(x, y);
+        }
+    }
+
+    fn connect_until_single_circuit(&mut self) -> Vec<Pair> {
+        let mut connected_pairs = Vec::new();
+        for &(i, j) in &self.ordered_pairs {
+            if self.circuits.union(i, j) {
+                connected_pairs.push((i, j));
+                if self.circuits.components() == 1 {
+                    break;
+                }
+            }
+        }
+        connected_pairs
+    }
+
+    fn top_n_circuits(&self, n: usize) -> Option<Vec<usize>> {
+        let sizes = self.circuits.largest_sizes(n);
+        (sizes.len() == n).then_some(sizes)
+    }
+
+    fn point(&self, index: usize) -> Point {
+        self.boxes.get_point(index)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Boxes, ParseError> {
+    Boxes::from_str(input)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Boxes;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(boxes: &Self::Input<'_>) -> i32 {
+        largest_circuits_product(boxes, 1000).expect("fewer than three circuits were formed")
+    }
+
+    fn part2(boxes: &Self::Input<'_>) -> i32 {
+        last_connection_product(boxes).expect("no connections were made")
+    }
+}
+
+pub fn part1(input: &str, target_connections: usize) -> Option<i32> {
+    largest_circuits_product(
+        &parse(input).expect("Failed to parse input"),
+        target_connections,
+    )
+}
+
+pub fn part2(input: &str) -> Option<i32> {
+    last_connection_product(&parse(input).expect("Failed to parse input"))
+}
+
+fn largest_circuits_product(boxes: &Boxes, target_connections: usize) -> Option<i32> {
+    let mut plan = WiringPlan::new(boxes.clone());
+    plan.connect_first_n(target_connections);
+    plan.top_n_circuits(3)
+        .map(|sizes| sizes.iter().product::<usize>() as i32)
+}
+
+fn last_connection_product(boxes: &Boxes) -> Option<i32> {
+    let mut plan = WiringPlan::new(boxes.clone());
+    let connected_pairs = plan.connect_until_single_circuit();
+
+    connected_pairs
+        .last()
+        .map(|&(i, j)| plan.point(i).x * plan.point(j).x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT_1: &str = include_str!("../input/test1.txt");
+
+    #[test]
+    fn test1_part1() {
+        assert_eq!(part1(TEST_INPUT_1, 10), Some(40));
+    }
+}
@@ -0,0 +1,422 @@
+use std::str::FromStr;
+
+use aoc_math::linalg::{BitVec, Gf2System};
+use aoc_parse::{ParseError, Within};
+use aoc_solution::Solution;
+
+const IMPOSSIBLE_COST: usize = 1_000_000;
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    target: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JoltageMachine {
+    buttons: Vec<Vec<usize>>,
+    target: Vec<usize>,
+}
+
+impl Machine {
+    /// Splits a line into its bracketed light diagram, the button wirings
+    /// and the braced joltage requirements.
+    fn split_line(line: &str) -> Result<(&str, Vec<&str>, &str), ParseError> {
+        let mut parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() < 2 {
+            return Err(ParseError::new(
+                line,
+                &line[line.len()..],
+                "a light diagram, buttons and joltages",
+            ));
+        }
+        let joltages = parts.pop().unwrap_or_default();
+        let diagram = parts.remove(0);
+
+        let lights = delimited(line, diagram, '[', ']')?;
+        let joltages = delimited(line, joltages, '{', '}')?;
+        Ok((lights, parts, joltages))
+    }
+
+    /// Reads a line once for both its lights and its joltage requirements.
+    fn parse_both(line: &str) -> Result<(Machine, JoltageMachine), ParseError> {
+        let (lights, buttons, joltages) = Self::split_line(line)?;
+        let buttons = Self::parse_buttons(line, &buttons)?;
+        let machine = Machine {
+            target: Self::parse_lights(line, lights)?,
+            buttons: buttons.clone(),
+        };
+        let joltage_machine = JoltageMachine {
+            buttons,
+            target: Self::parse_joltages(line, joltages)?,
+        };
+        Ok((machine, joltage_machine))
+    }
+
+    fn parse_lights(line: &str, lights: &str) -> Result<Vec<bool>, ParseError> {
+        lights
+            .char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::new(
+                    line,
+                    &lights[i..i + c.len_utf8()],
+                    "# or .",
+                )),
+            })
+            .collect()
+    }
+
+    fn parse_joltages(line: &str, joltages: &str) -> Result<Vec<usize>, ParseError> {
+        joltages
+            .split(',')
+            .map(|s| aoc_parse::number(line, s))
+            .collect()
+    }
+
+    fn parse_buttons(line: &str, tokens: &[&str]) -> Result<Vec<Vec<usize>>, ParseError> {
+        tokens
+            .iter()
+            .map(|s| {
+                delimited(line, s, '(', ')')?
+                    .split(',')
+                    .map(|n| aoc_parse::number(line, n))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// `s` without its `open` and `close` delimiters, which it must have.
+fn delimited<'a>(line: &str, s: &'a str, open: char, close: char) -> Result<&'a str, ParseError> {
+    s.strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or_else(|| ParseError::new(line, s, format!("something in {}{}", open, close)))
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (lights, buttons, _) = Self::split_line(line)?;
+        let target = Self::parse_lights(line, lights)?;
+        let buttons = Self::parse_buttons(line, &buttons)?;
+
+        Ok(Machine { target, buttons })
+    }
+}
+
+impl FromStr for JoltageMachine {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (_, buttons, joltages) = Machine::split_line(line)?;
+        let buttons = Machine::parse_buttons(line, &buttons)?;
+        let target = Machine::parse_joltages(line, joltages)?;
+
+        Ok(JoltageMachine { buttons, target })
+    }
+}
+
+trait MachineSolver {
+    fn solve(&self, machine: &Machine) -> usize;
+}
+
+trait JoltageSolver {
+    fn solve(&self, machine: &JoltageMachine) -> usize;
+}
+
+/// Tries every subset of the buttons, so it only suits machines with a few.
+/// Only the differential tests use it, to cross-check `EliminationSolver`.
+#[cfg(test)]
+struct BruteForceSolver;
+
+#[cfg(test)]
+impl MachineSolver for BruteForceSolver {
+    fn solve(&self, machine: &Machine) -> usize {
+        let num_buttons = machine.buttons.len();
+        let num_lights = machine.target.len();
+        let mut min_presses = usize::MAX;
+
+        for mask in 0u32..(1 << num_buttons) {
+            let mut lights = vec![false; num_lights];
+
+            for (i, button) in machine.buttons.iter().enumerate() {
+                if is_bit_set(mask, i) {
+                    toggle_lights(&mut lights, button);
+                }
+            }
+
+            if lights == machine.target {
+                min_presses = min_presses.min(mask.count_ones() as usize);
+            }
+        }
+
+        min_presses
+    }
+}
+
+/// Finds the fewest presses by solving the light equations over GF(2) and
+/// trying every solution in the null space instead of every button subset,
+/// so any number of buttons will do.
+struct EliminationSolver;
+
+impl MachineSolver for EliminationSolver {
+    fn solve(&self, machine: &Machine) -> usize {
+        let target: BitVec = machine.target.iter().copied().collect();
+        light_equations(&machine.buttons, machine.target.len())
+            .solutions(&target)
+            .map_or(usize::MAX, |solutions| {
+                solutions.map(|x| x.count_ones()).min().unwrap()
+            })
+    }
+}
+
+#[cfg(test)]
+fn is_bit_set<T: Into<u64>>(mask: T, index: usize) -> bool {
+    (mask.into() >> index) & 1 == 1
+}
+
+#[cfg(test)]
+fn toggle_lights(lights: &mut [bool], button: &[usize]) {
+    for &light_idx in button {
+        if light_idx < lights.len() {
+            lights[light_idx] = !lights[light_idx];
+        }
+    }
+}
+
+/// The equations saying which buttons, pressed once each or not at all,
+/// flip each light (or make each counter odd): one row per light, with a
+/// bit set for every button wired to it.
+fn light_equations(buttons: &[Vec<usize>], num_rows: usize) -> Gf2System {
+    let rows: Vec<BitVec> = (0..num_rows)
+        .map(|row| {
+            let wired = (0..buttons.len()).filter(|&j| buttons[j].contains(&row));
+            BitVec::from_ones(buttons.len(), wired)
+        })
+        .collect();
+    Gf2System::new(&rows, buttons.len())
+}
+
+struct LinearSolver {
+    memo: std::collections::HashMap<Vec<usize>, usize>,
+    row_map: Vec<Vec<usize>>,
+    gf2_solver: Option<std::rc::Rc<Gf2System>>,
+}
+
+impl LinearSolver {
+    fn new(buttons: Vec<Vec<usize>>, num_joltages: usize) -> Self {
+        let gf2_solver = if num_joltages > 0 {
+            Some(std::rc::Rc::new(light_equations(&buttons, num_joltages)))
+        } else {
+            None
+        };
+
+        let mut row_map = vec![vec![]; num_joltages];
+        for (btn_idx, indices) in buttons.iter().enumerate() {
+            for &row_idx in indices {
+                if row_idx < num_joltages {
+                    row_map[row_idx].push(btn_idx);
+                }
+            }
+        }
+
+        Self {
+            memo: std::collections::HashMap::new(),
+            row_map,
+            gf2_solver,
+        }
+    }
+
+    fn solve_recursive(&mut self, joltages: &[usize]) -> usize {
+        if self.is_solved(joltages) {
+            return 0;
+        }
+
+        if self.is_impossible(joltages) {
+            return IMPOSSIBLE_COST;
+        }
+
+        if let Some(&result) = self.memo.get(joltages) {
+            return result;
+        }
+
+        let min_presses = self.compute_min_presses(joltages);
+        self.memo.insert(joltages.to_vec(), min_presses);
+        min_presses
+    }
+
+    fn is_solved(&self, joltages: &[usize]) -> bool {
+        joltages.iter().all(|&x| x == 0)
+    }
+
+    fn is_impossible(&self, joltages: &[usize]) -> bool {
+        joltages.iter().any(|&x| x > IMPOSSIBLE_COST)
+    }
+
+    fn compute_min_presses(&mut self, joltages: &[usize]) -> usize {
+        let target_parity = self.joltages_to_parity(joltages);
+        let mut min_total = IMPOSSIBLE_COST;
+
+        let solver = self
+            .gf2_solver
+            .as_ref()
+            .expect("Solver should be initialized")
+            .clone();
+
+        let Some(solutions) = solver.solutions(&target_parity) else {
+            return IMPOSSIBLE_COST;
+        };
+        for mask in solutions {
+            if let Some(cost) = self.evaluate_solution(&mask, joltages) {
+                if cost < min_total {
+                    min_total = cost;
+                }
+            }
+        }
+
+        min_total
+    }
+
+    fn joltages_to_parity(&self, joltages: &[usize]) -> BitVec {
+        joltages.iter().map(|&val| val % 2 == 1).collect()
+    }
+
+    fn evaluate_solution(&mut self, mask: &BitVec, joltages: &[usize]) -> Option<usize> {
+        let next_joltages = self.compute_next_state(mask, joltages)?;
+        let recursive_cost = self.solve_recursive(&next_joltages);
+
+        if recursive_cost < IMPOSSIBLE_COST {
+            let button_presses = mask.count_ones();
+            Some(button_presses + 2 * recursive_cost)
+        } else {
+            None
+        }
+    }
+
+    fn compute_next_state(&self, mask: &BitVec, joltages: &[usize]) -> Option<Vec<usize>> {
+        let mut next_joltages = Vec::with_capacity(joltages.len());
+        for (i, &joltage) in joltages.iter().enumerate() {
+            let mut press_count = 0;
+            for &btn_idx in &self.row_map[i] {
+                if mask.get(btn_idx) {
+                    press_count += 1;
+                }
+            }
+
+            if press_count > joltage {
+                return None;
+            }
+            let rem = joltage - press_count;
+            if rem % 2 != 0 {
+                return None;
+            }
+            next_joltages.push(rem / 2);
+        }
+        Some(next_joltages)
+    }
+}
+
+impl JoltageSolver for LinearSolver {
+    fn solve(&self, machine: &JoltageMachine) -> usize {
+        let mut solver = LinearSolver::new(machine.buttons.clone(), machine.target.len());
+        solver.solve_recursive(&machine.target)
+    }
+}
+
+pub fn parse<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .map(|line| line.parse::<T>().within(input, line))
+        .collect()
+}
+
+fn solve_puzzle<T, S>(machines: &[T], solver: S) -> i32
+where
+    S: for<'a> Fn(&'a T) -> usize,
+{
+    machines.iter().map(solver).sum::<usize>() as i32
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    /// Each machine seen once for its indicator lights and once for its joltage requirements.
+    type Input<'a> = (Vec<Machine>, Vec<JoltageMachine>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let machines: Vec<_> = input
+            .lines()
+            .map(|line| Machine::parse_both(line).within(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(machines.into_iter().unzip())
+    }
+
+    fn part1((machines, _): &Self::Input<'_>) -> i32 {
+        solve_puzzle(machines, |machine| EliminationSolver.solve(machine))
+    }
+
+    fn part2((_, machines): &Self::Input<'_>) -> i32 {
+        solve_puzzle(machines, |machine| {
+            LinearSolver::new(vec![], 0).solve(machine)
+        })
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    let machines = parse(input).expect("Failed to parse input");
+    Day10::part1(&(machines, Vec::new()))
+}
+
+pub fn part2(input: &str) -> i32 {
+    let machines = parse(input).expect("Failed to parse input");
+    Day10::part2(&(Vec::new(), machines))
+}
+
+/// Checks the brute force light solver against the elimination one on random
+/// machines.
+#[cfg(test)]
+mod differential {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn machine() -> impl Strategy<Value = String> {
+        (1..8usize)
+            .prop_flat_map(|lights| {
+                let target = proptest::collection::vec(any::<bool>(), lights);
+                let button = proptest::collection::btree_set(0..lights, 1..=lights);
+                let buttons = proptest::collection::vec(button, 1..8);
+                (target, buttons)
+            })
+            .prop_map(|(target, buttons)| {
+                let diagram: String = target
+                    .iter()
+                    .map(|&on| if on { '#' } else { '.' })
+                    .collect();
+                let buttons: Vec<String> = buttons
+                    .iter()
+                    .map(|button| {
+                        let lights: Vec<String> = button.iter().map(|i| i.to_string()).collect();
+                        format!("({})", lights.join(","))
+                    })
+                    .collect();
+                let joltages = vec!["0"; target.len()].join(",");
+                format!("[{}] {} {{{}}}", diagram, buttons.join(" "), joltages)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn brute_force_and_elimination_agree(line in machine()) {
+            let machine: Machine = line.parse().unwrap();
+            prop_assert_eq!(BruteForceSolver.solve(&machine), EliminationSolver.solve(&machine));
+        }
+    }
+}
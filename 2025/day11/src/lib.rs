@@ -0,0 +1,78 @@
+use aoc_graph::Graph;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashSet;
+
+/// One line per device, `name: outputs...`, with each device listed once.
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::new();
+    let mut listed = HashSet::new();
+    for line in input.lines() {
+        let (name, outputs) = aoc_parse::split_once(input, line, ":")?;
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(ParseError::new(input, name, "a device name"));
+        }
+        if !listed.insert(name) {
+            return Err(ParseError::new(input, name, "each device listed once"));
+        }
+        let device = graph.node(name);
+        for output in outputs.split_whitespace() {
+            let output = graph.node(output);
+            graph.add_edge(device, output, ());
+        }
+    }
+    Ok(graph)
+}
+
+/// The number of paths between two devices, none if either is missing, or
+/// which devices are wired in a loop on the way.
+fn count_paths(graph: &Graph, from: &str, to: &str) -> Result<u64, String> {
+    let (Some(from), Some(to)) = (graph.id(from), graph.id(to)) else {
+        return Ok(0);
+    };
+    graph.count_paths(from, to).map_err(|cycle| {
+        let names: Vec<&str> = cycle
+            .nodes
+            .iter()
+            .map(|&id| graph.key(id).as_str())
+            .collect();
+        format!("devices {} are wired in a loop", names.join(", "))
+    })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Graph;
+    type Answer1 = Result<i32, String>;
+    type Answer2 = Result<i64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> Result<i32, String> {
+        Ok(count_paths(graph, "you", "out")? as i32)
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> Result<i64, String> {
+        // Paths: (svr->dac->fft->out) + (svr->fft->dac->out)
+        let svr_dac = count_paths(graph, "svr", "dac")?;
+        let dac_fft = count_paths(graph, "dac", "fft")?;
+        let fft_out = count_paths(graph, "fft", "out")?;
+        let svr_fft = count_paths(graph, "svr", "fft")?;
+        let fft_dac = count_paths(graph, "fft", "dac")?;
+        let dac_out = count_paths(graph, "dac", "out")?;
+
+        let result = (svr_dac * dac_fft * fft_out) + (svr_fft * fft_dac * dac_out);
+        Ok(result as i64)
+    }
+}
+
+pub fn part1(input: &str) -> Result<i32, String> {
+    Day11::part1(&parse(input).expect("Failed to parse input"))
+}
+
+pub fn part2(input: &str) -> Result<i64, String> {
+    Day11::part2(&parse(input).expect("Failed to parse input"))
+}
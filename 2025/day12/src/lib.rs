@@ -0,0 +1,225 @@
+use aoc_parse::ParseError;
+use aoc_solution::{NoPuzzle, Solution};
+
+const SHAPE_WIDTH: usize = 3;
+const SHAPE_HEIGHT: usize = 3;
+const MAX_PRESENTS_TOLERANCE: usize = 2;
+const REGION_DELIMITER: char = 'x';
+const SHAPE_HEADER_SUFFIX: char = ':';
+const TILE_CHAR: char = '#';
+
+struct Shape {
+    tile_count: usize,
+}
+
+struct Region {
+    width: usize,
+    height: usize,
+    shape_quantities: Vec<usize>,
+}
+
+pub struct PuzzleInput {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>,
+}
+
+impl PuzzleInput {
+    fn count_fitting_regions(&self) -> usize {
+        self.regions
+            .iter()
+            .filter(|region| RegionValidator::can_fit(region, &self.shapes))
+            .count()
+    }
+}
+
+impl Shape {
+    /// A block with the header `index:` and then the shape's rows, `#` for
+    /// its tiles and `.` for the gaps.
+    fn parse(input: &str, block: &str, index: usize) -> Result<Self, ParseError> {
+        let mut lines = block.lines();
+        let header = aoc_parse::next(input, block, &mut lines, "a shape header")?;
+        let number = header
+            .strip_suffix(SHAPE_HEADER_SUFFIX)
+            .ok_or_else(|| ParseError::new(input, header, "a shape header like \"0:\""))?;
+        if aoc_parse::number::<usize>(input, number)? != index {
+            return Err(ParseError::new(input, number, format!("shape {}", index)));
+        }
+
+        let rows: Vec<&str> = lines.collect();
+        if rows.len() != SHAPE_HEIGHT {
+            return Err(ParseError::new(
+                input,
+                &block[block.len()..],
+                format!("{} rows of tiles", SHAPE_HEIGHT),
+            ));
+        }
+        for row in &rows {
+            if let Some((i, c)) = row
+                .char_indices()
+                .find(|&(_, c)| c != TILE_CHAR && c != '.')
+            {
+                return Err(ParseError::new(
+                    input,
+                    &row[i..i + c.len_utf8()],
+                    "'#' or '.'",
+                ));
+            }
+            if row.len() != SHAPE_WIDTH {
+                return Err(ParseError::new(
+                    input,
+                    row,
+                    format!("a row of {} tiles", SHAPE_WIDTH),
+                ));
+            }
+        }
+
+        let tile_count = rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .filter(|&ch| ch == TILE_CHAR)
+            .count();
+
+        Ok(Shape { tile_count })
+    }
+}
+
+impl Region {
+    /// `12x5: 1 0 1 0 2 2`, the size and then how many of each shape to fit.
+    /// The numbers are read as `u32` so the areas and tile counts built from
+    /// them can't overflow.
+    fn parse(input: &str, line: &str, shapes: usize) -> Result<Self, ParseError> {
+        let (size, quantities) = aoc_parse::split_once(input, line, ": ")?;
+        let (width, height) = aoc_parse::split_once(input, size, &REGION_DELIMITER.to_string())?;
+        let shape_quantities: Vec<u32> = aoc_parse::numbers(input, quantities)?;
+        if shape_quantities.len() != shapes {
+            return Err(ParseError::new(
+                input,
+                quantities,
+                format!("a quantity for each of the {} shapes", shapes),
+            ));
+        }
+
+        Ok(Region {
+            width: aoc_parse::number::<u32>(input, width)? as usize,
+            height: aoc_parse::number::<u32>(input, height)? as usize,
+            shape_quantities: shape_quantities.into_iter().map(|q| q as usize).collect(),
+        })
+    }
+
+    fn total_presents(&self) -> usize {
+        self.shape_quantities.iter().sum()
+    }
+
+    fn area(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn max_presents_lower_bound(&self) -> usize {
+        (self.width / SHAPE_WIDTH) * (self.height / SHAPE_HEIGHT)
+    }
+
+    fn total_tile_count(&self, shapes: &[Shape]) -> usize {
+        shapes
+            .iter()
+            .zip(self.shape_quantities.iter())
+            .map(|(shape, &quantity)| shape.tile_count * quantity)
+            .sum()
+    }
+}
+
+struct PuzzleParser;
+
+impl PuzzleParser {
+    /// The shapes, a block each, then a block with a line per region.
+    fn parse(input: &str) -> Result<PuzzleInput, ParseError> {
+        let mut blocks = aoc_parse::blocks(input).peekable();
+        let mut shapes = Vec::new();
+        while let Some(block) = blocks.next_if(|block| !is_region_block(block)) {
+            shapes.push(Shape::parse(input, block, shapes.len())?);
+        }
+
+        let block = blocks
+            .next()
+            .ok_or_else(|| ParseError::end(input, "the regions"))?;
+        let regions = block
+            .lines()
+            .map(|line| Region::parse(input, line, shapes.len()))
+            .collect::<Result<_, _>>()?;
+        if let Some(extra) = blocks.next() {
+            return Err(ParseError::new(input, extra, "the end of the input"));
+        }
+
+        Ok(PuzzleInput { shapes, regions })
+    }
+}
+
+/// Region lines start with a size like `4x4`, where shape headers are just
+/// a number.
+fn is_region_block(block: &str) -> bool {
+    block
+        .lines()
+        .next()
+        .is_some_and(|line| line.contains(REGION_DELIMITER))
+}
+
+struct RegionValidator;
+
+impl RegionValidator {
+    fn can_fit(region: &Region, shapes: &[Shape]) -> bool {
+        Self::check_all_conditions(region, shapes)
+    }
+
+    fn check_all_conditions(region: &Region, shapes: &[Shape]) -> bool {
+        if Self::fits_by_presents_bound(region) {
+            return true;
+        }
+
+        if !Self::fits_by_tile_count(region, shapes) {
+            return false;
+        }
+
+        Self::fits_by_presents_tolerance(region)
+    }
+
+    fn fits_by_presents_bound(region: &Region) -> bool {
+        region.total_presents() <= region.max_presents_lower_bound()
+    }
+
+    fn fits_by_tile_count(region: &Region, shapes: &[Shape]) -> bool {
+        region.total_tile_count(shapes) <= region.area()
+    }
+
+    fn fits_by_presents_tolerance(region: &Region) -> bool {
+        region.total_presents() <= region.max_presents_lower_bound() + MAX_PRESENTS_TOLERANCE
+    }
+}
+
+pub fn parse(input: &str) -> Result<PuzzleInput, ParseError> {
+    PuzzleParser::parse(input)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = PuzzleInput;
+    type Answer1 = i32;
+    type Answer2 = NoPuzzle;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(puzzle: &Self::Input<'_>) -> i32 {
+        puzzle.count_fitting_regions() as i32
+    }
+
+    fn part2(_: &Self::Input<'_>) -> NoPuzzle {
+        NoPuzzle
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    Day12::part1(&parse(input).expect("Failed to parse input"))
+}
@@ -0,0 +1,155 @@
+//! Checks every day against the expected answers kept in `answers.json`.
+//!
+//! The file is keyed by year, day, input file and part:
+//!
+//! ```json
+//! { "2022": { "day01": { "test1.txt": { "part1": 24000, "part2": 45000 } } } }
+//! ```
+//!
+//! Answers that aren't numbers are written as strings. Each input file is
+//! parsed once and every part listed for it is solved from that.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::days;
+
+type Parts = BTreeMap<String, Value>;
+type Inputs = BTreeMap<String, Parts>;
+type Days = BTreeMap<String, Inputs>;
+
+/// Every expected answer, by year, day, input file and part.
+pub type Answers = BTreeMap<String, Days>;
+
+pub fn load(path: &Path) -> Result<Answers, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// One expected answer and how the day did against it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub year: String,
+    pub day: String,
+    pub input: String,
+    pub part: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The answer couldn't be checked at all, say because the input is
+    /// missing or doesn't parse.
+    Error(String),
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}: ",
+            self.year, self.day, self.input, self.part
+        )?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Fail { expected, actual } if expected.contains('\n') => {
+                write!(f, "FAILED\nexpected:\n{}\nactual:\n{}", expected, actual)
+            }
+            Outcome::Fail { expected, actual } => {
+                write!(f, "FAILED, expected {} but got {}", expected, actual)
+            }
+            Outcome::Error(e) => write!(f, "ERROR, {}", e),
+        }
+    }
+}
+
+/// Solves every part listed in `answers` from the inputs under `root`, the
+/// workspace root, keeping only the years and days that `keep` accepts.
+pub fn check(root: &Path, answers: &Answers, keep: impl Fn(&str, &str) -> bool) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for (year, days) in answers {
+        for (day, inputs) in days {
+            if !keep(year, day) {
+                continue;
+            }
+            for (input, parts) in inputs {
+                let mut check = |part: &str, outcome| {
+                    checks.push(Check {
+                        year: year.clone(),
+                        day: day.clone(),
+                        input: input.clone(),
+                        part: part.to_string(),
+                        outcome,
+                    })
+                };
+                let path = root.join(year).join(day).join("input").join(input);
+                match solve(year, day, &path, parts) {
+                    Ok(outcomes) => outcomes
+                        .into_iter()
+                        .for_each(|(part, outcome)| check(part, outcome)),
+                    Err(e) => parts
+                        .keys()
+                        .for_each(|part| check(part, Outcome::Error(e.clone()))),
+                }
+            }
+        }
+    }
+    checks
+}
+
+fn solve<'p>(
+    year: &str,
+    day: &str,
+    path: &Path,
+    parts: &'p Parts,
+) -> Result<Vec<(&'p str, Outcome)>, String> {
+    let solution = year
+        .parse()
+        .ok()
+        .zip(day.strip_prefix("day").and_then(|d| d.parse().ok()))
+        .and_then(|(year, day)| days::find(year, day))
+        .ok_or_else(|| "no solution for this day".to_string())?;
+    let input = fs::read_to_string(path)
+        .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+
+    let mut outcomes = Vec::new();
+    solution
+        .puzzle
+        .with_input(&input, &mut |parsed| {
+            for (part, expected) in parts {
+                let outcome = match part.strip_prefix("part").and_then(|p| p.parse().ok()) {
+                    Some(n) if solution.has_part(n) => {
+                        let expected = match expected {
+                            Value::String(s) => s.clone(),
+                            other => other.to_string(),
+                        };
+                        match parsed.solve(n) {
+                            Ok(actual) if actual == expected => Outcome::Pass,
+                            Ok(actual) => Outcome::Fail { expected, actual },
+                            Err(e) => Outcome::Error(format!("no answer: {}", e)),
+                        }
+                    }
+                    _ => Outcome::Error("the day has no such part".to_string()),
+                };
+                outcomes.push((part.as_str(), outcome));
+            }
+        })
+        .map_err(|e| e.to_string())?;
+    Ok(outcomes)
+}
@@ -0,0 +1,327 @@
+//! Times every day's parse and parts and compares the results with a stored
+//! baseline.
+//!
+//! The input is parsed once per day and both parts are timed on the parsed
+//! result, so part timings leave parsing out.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use aoc::days::Day;
+use serde::{Deserialize, Serialize};
+
+/// Stop taking samples of a stage once this much time has gone into it.
+const SAMPLE_BUDGET: Duration = Duration::from_secs(2);
+
+/// Slowdowns smaller than this are treated as noise however large the ratio.
+const NOISE_FLOOR_NS: u64 = 100_000;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut ns: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        ns.sort_unstable();
+
+        let n = ns.len();
+        let median = if n % 2 == 1 {
+            ns[n / 2]
+        } else {
+            (ns[n / 2 - 1] + ns[n / 2]) / 2
+        };
+        let mean = ns.iter().sum::<u64>() as f64 / n as f64;
+        let variance = ns.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            samples: n,
+            min_ns: ns[0],
+            median_ns: median,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+/// Timings of one day, keyed like `2022/day16` in the baseline file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DayTimings {
+    /// Input file the timings were taken on; timings are only compared
+    /// when it matches.
+    pub input: String,
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayTimings {
+    fn stages(&self) -> [(&'static str, Option<Stats>); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+}
+
+pub type Baseline = BTreeMap<String, DayTimings>;
+
+pub fn key(year: u16, day: u8) -> String {
+    format!("{}/day{:02}", year, day)
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    if !path.exists() {
+        return Ok(Baseline::new());
+    }
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("unable to create {}: {}", dir.display(), e))?;
+    }
+    let text = serde_json::to_string_pretty(baseline).map_err(|e| e.to_string())?;
+    fs::write(path, text + "\n").map_err(|e| format!("unable to write {}: {}", path.display(), e))
+}
+
+fn sample(settings: Settings, mut run: impl FnMut()) -> Stats {
+    for _ in 0..settings.warmup {
+        run();
+    }
+
+    let mut samples = Vec::with_capacity(settings.samples);
+    let started = Instant::now();
+    while samples.len() < settings.samples.max(1) {
+        let start = Instant::now();
+        run();
+        samples.push(start.elapsed());
+
+        if started.elapsed() > SAMPLE_BUDGET {
+            break;
+        }
+    }
+    Stats::from_samples(&samples)
+}
+
+/// Picks the real input when it has been fetched, otherwise the largest test
+/// input the day ships with.
+pub fn input_path(day_dir: &Path) -> Option<PathBuf> {
+    ["input.txt", "test2.txt", "test1.txt"]
+        .into_iter()
+        .map(|file| day_dir.join("input").join(file))
+        .find(|path| path.exists())
+}
+
+pub fn bench_day(
+    day: &Day,
+    input_name: &str,
+    input: &str,
+    settings: Settings,
+) -> Result<DayTimings, String> {
+    let mut timings = DayTimings {
+        input: input_name.to_string(),
+        ..DayTimings::default()
+    };
+
+    // Time the parts first: a malformed input fails here, before parsing is
+    // sampled with its result thrown away.
+    day.puzzle
+        .with_input(input, &mut |parsed| {
+            for part in day.parts() {
+                let stats = sample(settings, || {
+                    let _ = black_box(parsed.solve(black_box(part)));
+                });
+                match part {
+                    1 => timings.part1 = Some(stats),
+                    _ => timings.part2 = Some(stats),
+                }
+            }
+        })
+        .map_err(|e| format!("{:#}", e))?;
+
+    timings.parse = Some(sample(settings, || {
+        let _ = day.puzzle.with_input(black_box(input), &mut |parsed| {
+            black_box(parsed);
+        });
+    }));
+
+    Ok(timings)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub stage: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    pub fn ratio(&self) -> f64 {
+        self.current_ns as f64 / self.baseline_ns.max(1) as f64
+    }
+}
+
+/// Stages whose median got at least `threshold` times slower than the
+/// baseline.
+pub fn regressions(current: &DayTimings, baseline: &DayTimings, threshold: f64) -> Vec<Regression> {
+    if current.input != baseline.input {
+        return Vec::new();
+    }
+    current
+        .stages()
+        .into_iter()
+        .zip(baseline.stages())
+        .filter_map(|((stage, current), (_, baseline))| {
+            let (current, baseline) = (current?, baseline?);
+            let regression = Regression {
+                stage,
+                baseline_ns: baseline.median_ns,
+                current_ns: current.median_ns,
+            };
+            let slower_by = current.median_ns.saturating_sub(baseline.median_ns);
+            (regression.ratio() >= threshold && slower_by >= NOISE_FLOOR_NS).then_some(regression)
+        })
+        .collect()
+}
+
+pub struct Nanos(pub u64);
+
+impl fmt::Display for Nanos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.2?}", Duration::from_nanos(self.0))
+    }
+}
+
+pub fn print_day(key: &str, current: &DayTimings, baseline: Option<&DayTimings>) {
+    for (stage, stats) in current.stages() {
+        let Some(stats) = stats else {
+            continue;
+        };
+
+        let change = baseline
+            .filter(|b| b.input == current.input)
+            .and_then(|b| b.stages().into_iter().find(|(s, _)| *s == stage)?.1)
+            .map(|b| {
+                format!(
+                    "  {:.2}x baseline",
+                    stats.median_ns as f64 / b.median_ns.max(1) as f64
+                )
+            })
+            .unwrap_or_default();
+
+        println!(
+            "{} {:<6}  median {:>10}  mean {:>10} ± {:<10} min {:>10}  ({} samples){}",
+            key,
+            stage,
+            Nanos(stats.median_ns).to_string(),
+            Nanos(stats.mean_ns).to_string(),
+            Nanos(stats.stddev_ns).to_string(),
+            Nanos(stats.min_ns).to_string(),
+            stats.samples,
+            change
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            samples: 5,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    fn timings(parse: u64, part1: u64, part2: Option<u64>) -> DayTimings {
+        DayTimings {
+            input: "test1.txt".to_string(),
+            parse: Some(stats(parse)),
+            part1: Some(stats(part1)),
+            part2: part2.map(stats),
+        }
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_nanos).to_vec();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 2);
+        assert_eq!(stats.mean_ns, 3);
+        assert_eq!(stats.stddev_ns, 1);
+
+        let stats = Stats::from_samples(&[Duration::from_nanos(7)]);
+        assert_eq!((stats.median_ns, stats.stddev_ns), (7, 0));
+    }
+
+    #[test]
+    fn flags_stages_past_the_threshold() {
+        let baseline = timings(1_000_000, 1_000_000, Some(1_000_000));
+        let current = timings(1_100_000, 3_500_000, Some(1_900_000));
+
+        let found = regressions(&current, &baseline, 2.0);
+
+        assert_eq!(
+            found,
+            vec![Regression {
+                stage: "part 1",
+                baseline_ns: 1_000_000,
+                current_ns: 3_500_000
+            }]
+        );
+        assert_eq!(found[0].ratio(), 3.5);
+    }
+
+    #[test]
+    fn ignores_slowdowns_below_the_noise_floor() {
+        let baseline = timings(1_000, 1_000, None);
+        let current = timings(50_000, 1_000, Some(1_000_000));
+
+        assert!(regressions(&current, &baseline, 2.0).is_empty());
+    }
+
+    #[test]
+    fn only_compares_timings_taken_on_the_same_input() {
+        let baseline = timings(1_000_000, 1_000_000, None);
+        let mut current = timings(1_000_000, 9_000_000, None);
+        current.input = "input.txt".to_string();
+
+        assert!(regressions(&current, &baseline, 2.0).is_empty());
+    }
+
+    #[test]
+    fn baseline_round_trips_through_json() {
+        let mut baseline = Baseline::new();
+        baseline.insert(key(2022, 16), timings(10, 20, Some(30)));
+        baseline.insert(key(2025, 12), timings(1, 2, None));
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+
+use aoc_solution::{Answer, ParseError, Solution};
+use aoc_visualize::Simulate;
+
+/// A day's [`Solution`] with its types erased, so every day fits in one table.
+pub trait Puzzle: Sync {
+    /// How many parts the puzzle has; the last day of a year only has one.
+    fn parts(&self) -> u8;
+
+    /// Parses `input` once and hands the result to `then`, which can solve
+    /// as many parts from it as it likes.
+    fn with_input(&self, input: &str, then: &mut dyn FnMut(&dyn Parsed)) -> Result<(), ParseError>;
+}
+
+/// A parsed input, ready for either part to be solved from it.
+pub trait Parsed {
+    /// Solves `part` and renders its answer, or says why the input has none.
+    fn solve(&self, part: u8) -> Result<String, String>;
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Puzzle for Erased<S> {
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn with_input(&self, input: &str, then: &mut dyn FnMut(&dyn Parsed)) -> Result<(), ParseError> {
+        let parsed = S::parse(input)?;
+        then(&Solved::<S>(&parsed));
+        Ok(())
+    }
+}
+
+struct Solved<'p, 'a, S: Solution>(&'p S::Input<'a>);
+
+impl<S: Solution> Parsed for Solved<'_, '_, S> {
+    fn solve(&self, part: u8) -> Result<String, String> {
+        match part {
+            1 => S::part1(self.0).render(),
+            2 => S::part2(self.0).render(),
+            _ => panic!("there is no part {}", part),
+        }
+    }
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub puzzle: &'static dyn Puzzle,
+    /// Sets up the day's simulation for `aoc run --visualize`, if it has one.
+    pub simulate: Option<Simulate>,
+}
+
+impl Day {
+    pub fn has_part(&self, part: u8) -> bool {
+        (1..=self.puzzle.parts()).contains(&part)
+    }
+
+    /// The parts the puzzle has, in order.
+    pub fn parts(&self) -> impl Iterator<Item = u8> {
+        1..=self.puzzle.parts()
+    }
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:path) => {
+        Day {
+            year: $year,
+            day: $day,
+            puzzle: &Erased::<$solution>(PhantomData),
+            simulate: None,
+        }
+    };
+    ($year:literal, $day:literal, $solution:path, simulate = $simulate:path) => {
+        Day {
+            year: $year,
+            day: $day,
+            puzzle: &Erased::<$solution>(PhantomData),
+            simulate: Some($simulate),
+        }
+    };
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2022, 1, aoc_2022_day01::Day01),
+    day!(2022, 2, aoc_2022_day02::Day02),
+    day!(2022, 3, aoc_2022_day03::Day03),
+    day!(2022, 4, aoc_2022_day04::Day04),
+    day!(2022, 5, aoc_2022_day05::Day05),
+    day!(2022, 6, aoc_2022_day06::Day06),
+    day!(2022, 7, aoc_2022_day07::Day07),
+    day!(2022, 8, aoc_2022_day08::Day08),
+    day!(2022, 9, aoc_2022_day09::Day09),
+    day!(2022, 10, aoc_2022_day10::Day10),
+    day!(2022, 11, aoc_2022_day11::Day11),
+    day!(2022, 12, aoc_2022_day12::Day12),
+    day!(2022, 13, aoc_2022_day13::Day13),
+    day!(
+        2022,
+        14,
+        aoc_2022_day14::Day14,
+        simulate = aoc_2022_day14::simulate
+    ),
+    day!(2022, 15, aoc_2022_day15::Day15),
+    day!(2022, 16, aoc_2022_day16::Day16),
+    day!(
+        2022,
+        17,
+        aoc_2022_day17::Day17,
+        simulate = aoc_2022_day17::simulate
+    ),
+    day!(2022, 18, aoc_2022_day18::Day18),
+    day!(2022, 19, aoc_2022_day19::Day19),
+    day!(2022, 20, aoc_2022_day20::Day20),
+    day!(2022, 21, aoc_2022_day21::Day21),
+    day!(2022, 22, aoc_2022_day22::Day22),
+    day!(
+        2022,
+        23,
+        aoc_2022_day23::Day23,
+        simulate = aoc_2022_day23::simulate
+    ),
+    day!(2022, 24, aoc_2022_day24::Day24),
+    day!(2022, 25, aoc_2022_day25::Day25),
+    day!(2023, 1, aoc_2023_day01::Day01),
+    day!(2023, 2, aoc_2023_day02::Day02),
+    day!(2023, 3, aoc_2023_day03::Day03),
+    day!(2023, 4, aoc_2023_day04::Day04),
+    day!(2023, 5, aoc_2023_day05::Day05),
+    day!(2023, 6, aoc_2023_day06::Day06),
+    day!(2023, 7, aoc_2023_day07::Day07),
+    day!(2023, 8, aoc_2023_day08::Day08),
+    day!(2023, 9, aoc_2023_day09::Day09),
+    day!(2023, 10, aoc_2023_day10::Day10),
+    day!(2023, 11, aoc_2023_day11::Day11),
+    day!(2023, 12, aoc_2023_day12::Day12),
+    day!(2023, 13, aoc_2023_day13::Day13),
+    day!(2023, 14, aoc_2023_day14::Day14),
+    day!(2023, 15, aoc_2023_day15::Day15),
+    day!(2023, 16, aoc_2023_day16::Day16),
+    day!(2023, 17, aoc_2023_day17::Day17),
+    day!(2023, 18, aoc_2023_day18::Day18),
+    day!(2023, 19, aoc_2023_day19::Day19),
+    day!(2023, 20, aoc_2023_day20::Day20),
+    day!(2023, 21, aoc_2023_day21::Day21),
+    day!(2023, 22, aoc_2023_day22::Day22),
+    day!(2023, 23, aoc_2023_day23::Day23),
+    day!(2023, 24, aoc_2023_day24::Day24),
+    day!(2023, 25, aoc_2023_day25::Day25),
+    day!(2024, 1, aoc_2024_day01::Day01),
+    day!(2024, 2, aoc_2024_day02::Day02),
+    day!(2024, 3, aoc_2024_day03::Day03),
+    day!(2024, 4, aoc_2024_day04::Day04),
+    day!(2024, 5, aoc_2024_day05::Day05),
+    day!(2024, 6, aoc_2024_day06::Day06),
+    day!(2024, 7, aoc_2024_day07::Day07),
+    day!(2024, 8, aoc_2024_day08::Day08),
+    day!(2024, 9, aoc_2024_day09::Day09),
+    day!(2024, 10, aoc_2024_day10::Day10),
+    day!(2024, 11, aoc_2024_day11::Day11),
+    day!(2024, 12, aoc_2024_day12::Day12),
+    day!(2024, 13, aoc_2024_day13::Day13),
+    day!(
+        2024,
+        14,
+        aoc_2024_day14::Day14,
+        simulate = aoc_2024_day14::simulate
+    ),
+    day!(
+        2024,
+        15,
+        aoc_2024_day15::Day15,
+        simulate = aoc_2024_day15::simulate
+    ),
+    day!(2024, 16, aoc_2024_day16::Day16),
+    day!(2024, 17, aoc_2024_day17::Day17),
+    day!(2024, 18, aoc_2024_day18::Day18),
+    day!(2024, 19, aoc_2024_day19::Day19),
+    day!(2024, 20, aoc_2024_day20::Day20),
+    day!(2024, 21, aoc_2024_day21::Day21),
+    day!(2024, 22, aoc_2024_day22::Day22),
+    day!(2024, 23, aoc_2024_day23::Day23),
+    day!(2024, 24, aoc_2024_day24::Day24),
+    day!(2024, 25, aoc_2024_day25::Day25),
+    day!(2025, 1, aoc_2025_day01::Day01),
+    day!(2025, 2, aoc_2025_day02::Day02),
+    day!(2025, 3, aoc_2025_day03::Day03),
+    day!(2025, 4, aoc_2025_day04::Day04),
+    day!(2025, 5, aoc_2025_day05::Day05),
+    day!(2025, 6, aoc_2025_day06::Day06),
+    day!(2025, 7, aoc_2025_day07::Day07),
+    day!(2025, 8, aoc_2025_day08::Day08),
+    day!(2025, 9, aoc_2025_day09::Day09),
+    day!(2025, 10, aoc_2025_day10::Day10),
+    day!(2025, 11, aoc_2025_day11::Day11),
+    day!(2025, 12, aoc_2025_day12::Day12),
+];
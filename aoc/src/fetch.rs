@@ -0,0 +1,242 @@
+//! Downloads puzzle inputs from adventofcode.com and caches them next to each day.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request so the site operators know who is calling.
+pub const USER_AGENT: &str = concat!(
+    "github.com/brettseton/advent_of_code aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Environment variable holding the `session` cookie of a logged in user.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Shortest gap allowed between two requests to the server.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Name of the cached input file inside a day's `input/` directory.
+pub const INPUT_FILE: &str = "input.txt";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session token in the environment or the config file.
+    MissingSession,
+    /// The server rejected the session token.
+    Unauthorized,
+    /// The puzzle does not exist or has not been unlocked yet.
+    NotFound,
+    /// The server failed to answer, so the request is worth retrying later.
+    Server(u16),
+    /// The server answered with an unexpected status code.
+    Status(u16),
+    /// The request never got an answer.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session token, set {} or write it to {}",
+                SESSION_ENV,
+                config_path().map_or("the config file".into(), |p| p.display().to_string())
+            ),
+            FetchError::Unauthorized => write!(f, "the session token was rejected"),
+            FetchError::NotFound => write!(f, "the puzzle is not available yet"),
+            FetchError::Server(code) => {
+                write!(f, "the server failed with status {}, try again later", code)
+            }
+            FetchError::Status(code) => write!(f, "unexpected response status {}", code),
+            FetchError::Transport(e) => write!(f, "request failed: {}", e),
+            FetchError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+/// Where the session token is read from when the environment does not set it.
+pub fn config_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_home.join("aoc").join("session"))
+}
+
+/// Reads the session token from `AOC_SESSION`, falling back to the config file.
+pub fn session_token() -> Result<String, FetchError> {
+    let from_env = env::var(SESSION_ENV).ok();
+    load_session(from_env, config_path().as_deref())
+}
+
+pub fn load_session(from_env: Option<String>, config: Option<&Path>) -> Result<String, FetchError> {
+    let token = match from_env {
+        Some(token) => token,
+        None => match config {
+            Some(path) if path.exists() => fs::read_to_string(path)?,
+            _ => return Err(FetchError::MissingSession),
+        },
+    };
+
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(FetchError::MissingSession);
+    }
+    Ok(token.to_string())
+}
+
+/// The cache location of a day's real input.
+pub fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string())
+        .join(format!("day{:02}", day))
+        .join("input")
+        .join(INPUT_FILE)
+}
+
+pub(crate) struct Throttle {
+    pub(crate) min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Throttle {
+    pub(crate) fn new(min_interval: Duration) -> Throttle {
+        Throttle {
+            min_interval,
+            last_request: None,
+        }
+    }
+
+    pub(crate) fn wait(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+pub(crate) fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+pub(crate) fn session_cookie(session: &str) -> String {
+    format!("session={}", session)
+}
+
+/// Maps a failed request onto the errors callers care about.
+pub(crate) fn request_error(e: ureq::Error) -> FetchError {
+    match e {
+        // The site answers a bad or expired cookie with 400 and a "Please
+        // log in" page rather than 401.
+        ureq::Error::Status(400 | 401 | 403, _) => FetchError::Unauthorized,
+        ureq::Error::Status(404, _) => FetchError::NotFound,
+        ureq::Error::Status(code @ 500..=599, _) => FetchError::Server(code),
+        ureq::Error::Status(code, _) => FetchError::Status(code),
+        ureq::Error::Transport(e) => FetchError::Transport(e.to_string()),
+    }
+}
+
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Fetcher {
+    pub fn new(session: &str) -> Fetcher {
+        Fetcher {
+            agent: agent(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.to_string(),
+            throttle: Throttle::new(DEFAULT_MIN_INTERVAL),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Fetcher {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Fetcher {
+        self.throttle.min_interval = min_interval;
+        self
+    }
+
+    /// Downloads the input for a day, always going to the server.
+    pub fn fetch(&mut self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.throttle.wait();
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &session_cookie(&self.session))
+            .call()
+            .map_err(request_error)?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Returns the cached input under `root` if there is one, otherwise
+    /// downloads it and stores it in the day's `input/` directory.
+    pub fn fetch_cached(&mut self, root: &Path, year: u16, day: u8) -> Result<Fetched, FetchError> {
+        let path = input_path(root, year, day);
+        if path.exists() {
+            let input = fs::read_to_string(&path)?;
+            return Ok(Fetched {
+                path,
+                input,
+                from_cache: true,
+            });
+        }
+
+        self.refetch(root, year, day)
+    }
+
+    /// Downloads the input for a day and stores it under `root`, replacing
+    /// any cached copy only once the download has succeeded.
+    pub fn refetch(&mut self, root: &Path, year: u16, day: u8) -> Result<Fetched, FetchError> {
+        let path = input_path(root, year, day);
+        let input = self.fetch(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &input)?;
+
+        Ok(Fetched {
+            path,
+            input,
+            from_cache: false,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub input: String,
+    pub from_cache: bool,
+}
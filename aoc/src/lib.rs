@@ -0,0 +1,6 @@
+pub mod answers;
+pub mod days;
+pub mod fetch;
+pub mod run_all;
+pub mod scaffold;
+pub mod submit;
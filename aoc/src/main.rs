@@ -0,0 +1,705 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::days::{self, Day};
+use aoc::fetch::{self, Fetcher};
+use aoc::run_all::{self, Job};
+use aoc::scaffold::{self, NewDay};
+use aoc::submit::{self, Ledger, Submitter};
+use aoc_visualize::{Output, Player};
+
+mod bench;
+
+const USAGE: &str = "\
+Usage:
+    aoc run <year> <day> [--part <1|2>] [--input <path>]
+            [--visualize [<path>]] [--fps <n>] [--frames <n>]
+    aoc run-all [<year> [<day>]] [--jobs <n>] [--timeout <seconds>]
+    aoc fetch <year> [<day>] [--force]
+    aoc submit <year> <day> <1|2> [<answer>]
+    aoc bench [<year> [<day>]] [--samples <n>] [--warmup <n>] [--threshold <ratio>]
+              [--baseline <path>] [--save]
+    aoc new <year> <day> [--title <title>]
+
+Options:
+    --part <1|2>      Only run the given part (default: both)
+    --input <path>    Puzzle input to solve (default: <year>/dayNN/input/test1.txt)
+    --visualize       Watch the part's simulation on the terminal instead of
+                      solving it, or write it as PNG frames into <path>, or as
+                      a GIF when <path> ends in .gif
+    --fps <n>         Frames a second to visualize at (default: 30)
+    --frames <n>      Stop visualizing after n frames
+    --jobs <n>        Days to run at once (default: one per CPU)
+    --timeout <s>     Seconds a day may take before it is abandoned (default: 60)
+    --force           Download the input again even if it is cached
+    --samples <n>     Timed runs per stage (default: 10, fewer if a stage is slow)
+    --warmup <n>      Untimed runs before sampling (default: 1)
+    --threshold <r>   Flag stages whose median is r times the baseline (default: 2)
+    --baseline <path> Baseline to compare with (default: bench/baseline.json)
+    --save            Write the new timings into the baseline
+    --title <title>   Puzzle title for the day's row in Readme.md
+
+Fetching reads the session cookie from $AOC_SESSION or ~/.config/aoc/session
+and caches each input as <year>/dayNN/input/input.txt.
+
+Only some days can be visualized: those whose solution runs a simulation worth
+watching. Part 1 is shown unless --part says otherwise.
+
+Running all days solves every day, or those of one year, on a pool of workers.
+Each uses its fetched input, or its test input when none has been fetched. A
+day that panics or times out only fails its own parts, and a table of every
+part's answer, status and time is printed at the end.
+
+Submitting without an answer solves the part on the fetched input first. Every
+verdict is kept in <year>/dayNN/submissions.tsv, and answers it already rules
+out are not sent again.
+
+Benchmarks time parse, part 1 and part 2 of every selected day on its fetched
+input, or its test input when none has been fetched, and exit with an error
+when a stage regressed against the baseline. Build with --release.
+
+New days are created as <year>/dayNN from a skeleton that builds and passes its
+tests, and are added to the workspace, the runner and the year's Readme table.";
+
+struct RunArgs {
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    visualize: Option<Player>,
+}
+
+struct RunAllArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    settings: run_all::Settings,
+}
+
+struct FetchArgs {
+    year: u16,
+    day: Option<u8>,
+    force: bool,
+}
+
+struct SubmitArgs {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+}
+
+struct BenchArgs {
+    year: Option<u16>,
+    day: Option<u8>,
+    settings: bench::Settings,
+    threshold: f64,
+    baseline: Option<PathBuf>,
+    save: bool,
+}
+
+struct NewArgs {
+    new_day: NewDay,
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_root()
+        .join(year.to_string())
+        .join(format!("day{:02}", day))
+}
+
+fn parse_run_args(args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut args = args.peekable();
+    let year = args
+        .next()
+        .ok_or("missing <year>")?
+        .parse()
+        .map_err(|_| "<year> must be a number")?;
+    let day = args
+        .next()
+        .ok_or("missing <day>")?
+        .parse()
+        .map_err(|_| "<day> must be a number")?;
+
+    let mut run = RunArgs {
+        year,
+        day,
+        part: None,
+        input: None,
+        visualize: None,
+    };
+    let mut fps = None;
+    let mut frames = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                run.part = match part.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("--part must be 1 or 2, got '{}'", part)),
+                };
+            }
+            "--input" => {
+                run.input = Some(args.next().ok_or("--input needs a value")?.into());
+            }
+            "--visualize" => {
+                let path = args
+                    .next_if(|arg| !arg.starts_with("--"))
+                    .map(PathBuf::from);
+                run.visualize = Some(Player::new(Output::for_path(path)));
+            }
+            "--fps" => {
+                fps = Some(
+                    args.next()
+                        .ok_or("--fps needs a value")?
+                        .parse()
+                        .map_err(|_| "--fps must be a number")?,
+                );
+            }
+            "--frames" => {
+                frames = Some(
+                    args.next()
+                        .ok_or("--frames needs a value")?
+                        .parse()
+                        .map_err(|_| "--frames must be a number")?,
+                );
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    match &mut run.visualize {
+        Some(player) => {
+            player.fps = fps.unwrap_or(player.fps);
+            player.max_frames = frames;
+        }
+        None if fps.is_some() || frames.is_some() => {
+            return Err("--fps and --frames only apply with --visualize".to_string())
+        }
+        None => {}
+    }
+
+    Ok(run)
+}
+
+fn parse_run_all_args(mut args: impl Iterator<Item = String>) -> Result<RunAllArgs, String> {
+    let mut run_all = RunAllArgs {
+        year: None,
+        day: None,
+        settings: run_all::Settings {
+            workers: thread::available_parallelism().map_or(1, |n| n.get()),
+            timeout: Duration::from_secs(60),
+        },
+    };
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jobs" => {
+                run_all.settings.workers = match args.next().ok_or("--jobs needs a value")?.parse()
+                {
+                    Ok(jobs) if jobs > 0 => jobs,
+                    _ => return Err("--jobs must be a positive number".to_string()),
+                };
+            }
+            "--timeout" => {
+                let seconds: f64 = args
+                    .next()
+                    .ok_or("--timeout needs a value")?
+                    .parse()
+                    .map_err(|_| "--timeout must be a number")?;
+                run_all.settings.timeout = Duration::try_from_secs_f64(seconds)
+                    .map_err(|_| "--timeout must be a positive number of seconds")?;
+            }
+            _ if arg.starts_with("--") => return Err(format!("unexpected argument '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    run_all.year = positional
+        .next()
+        .map(|year| year.parse().map_err(|_| "<year> must be a number"))
+        .transpose()?;
+    run_all.day = positional
+        .next()
+        .map(|day| day.parse().map_err(|_| "<day> must be a number"))
+        .transpose()?;
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument '{}'", arg));
+    }
+
+    Ok(run_all)
+}
+
+fn parse_fetch_args(args: impl Iterator<Item = String>) -> Result<FetchArgs, String> {
+    let mut fetch = FetchArgs {
+        year: 0,
+        day: None,
+        force: false,
+    };
+    let mut positional = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "--force" => fetch.force = true,
+            _ if arg.starts_with("--") => return Err(format!("unexpected argument '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    fetch.year = positional
+        .next()
+        .ok_or("missing <year>")?
+        .parse()
+        .map_err(|_| "<year> must be a number")?;
+    fetch.day = positional
+        .next()
+        .map(|day| day.parse().map_err(|_| "<day> must be a number"))
+        .transpose()?;
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument '{}'", arg));
+    }
+
+    Ok(fetch)
+}
+
+fn parse_submit_args(mut args: impl Iterator<Item = String>) -> Result<SubmitArgs, String> {
+    let year = args
+        .next()
+        .ok_or("missing <year>")?
+        .parse()
+        .map_err(|_| "<year> must be a number")?;
+    let day = args
+        .next()
+        .ok_or("missing <day>")?
+        .parse()
+        .map_err(|_| "<day> must be a number")?;
+    let part = match args.next().ok_or("missing <part>")?.as_str() {
+        "1" => 1,
+        "2" => 2,
+        part => return Err(format!("<part> must be 1 or 2, got '{}'", part)),
+    };
+    let answer = args.next();
+    if let Some(arg) = args.next() {
+        return Err(format!("unexpected argument '{}'", arg));
+    }
+
+    Ok(SubmitArgs {
+        year,
+        day,
+        part,
+        answer,
+    })
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut bench = BenchArgs {
+        year: None,
+        day: None,
+        settings: bench::Settings {
+            warmup: 1,
+            samples: 10,
+        },
+        threshold: 2.0,
+        baseline: None,
+        save: false,
+    };
+    let mut positional = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--samples" => {
+                bench.settings.samples = args
+                    .next()
+                    .ok_or("--samples needs a value")?
+                    .parse()
+                    .map_err(|_| "--samples must be a number")?;
+            }
+            "--warmup" => {
+                bench.settings.warmup = args
+                    .next()
+                    .ok_or("--warmup needs a value")?
+                    .parse()
+                    .map_err(|_| "--warmup must be a number")?;
+            }
+            "--threshold" => {
+                bench.threshold = args
+                    .next()
+                    .ok_or("--threshold needs a value")?
+                    .parse()
+                    .map_err(|_| "--threshold must be a number")?;
+            }
+            "--baseline" => {
+                bench.baseline = Some(args.next().ok_or("--baseline needs a value")?.into());
+            }
+            "--save" => bench.save = true,
+            _ if arg.starts_with("--") => return Err(format!("unexpected argument '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    bench.year = positional
+        .next()
+        .map(|year| year.parse().map_err(|_| "<year> must be a number"))
+        .transpose()?;
+    bench.day = positional
+        .next()
+        .map(|day| day.parse().map_err(|_| "<day> must be a number"))
+        .transpose()?;
+    if let Some(arg) = positional.next() {
+        return Err(format!("unexpected argument '{}'", arg));
+    }
+
+    Ok(bench)
+}
+
+fn parse_new_args(mut args: impl Iterator<Item = String>) -> Result<NewArgs, String> {
+    let year = args
+        .next()
+        .ok_or("missing <year>")?
+        .parse()
+        .map_err(|_| "<year> must be a number")?;
+    let day = match args.next().ok_or("missing <day>")?.parse() {
+        Ok(day @ 1..=25) => day,
+        _ => return Err("<day> must be a number from 1 to 25".to_string()),
+    };
+
+    let mut new_day = NewDay::new(year, day);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => {
+                new_day = new_day.with_title(&args.next().ok_or("--title needs a value")?);
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(NewArgs { new_day })
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let day = days::find(args.year, args.day)
+        .ok_or_else(|| format!("no solution for {} day {}", args.year, args.day))?;
+    let parts: Vec<u8> = match args.part {
+        Some(part) if !day.has_part(part) => {
+            return Err(format!("{} day {} has no part {}", day.year, day.day, part))
+        }
+        Some(part) => vec![part],
+        None => day.parts().collect(),
+    };
+
+    let path = args
+        .input
+        .unwrap_or_else(|| day_dir(day.year, day.day).join("input/test1.txt"));
+    let input = fs::read_to_string(&path)
+        .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+
+    if let Some(player) = args.visualize {
+        let simulate = day
+            .simulate
+            .ok_or_else(|| format!("{} day {} has no visualization", day.year, day.day))?;
+        let mut simulation =
+            simulate(&input, parts[0]).map_err(|e| format!("{}: {:#}", path.display(), e))?;
+        let shown = player
+            .play(simulation.as_mut())
+            .map_err(|e| format!("unable to visualize: {}", e))?;
+        match &player.output {
+            Output::Terminal => {}
+            Output::Png(dir) => println!("wrote {} frames into {}", shown, dir.display()),
+            Output::Gif(gif) => println!("wrote {} frames to {}", shown, gif.display()),
+        }
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let mut unanswered = Vec::new();
+    day.puzzle
+        .with_input(&input, &mut |parsed| {
+            println!(
+                "{} day {:02} parse: {:.2?}",
+                day.year,
+                day.day,
+                start.elapsed()
+            );
+            for &part in &parts {
+                let start = Instant::now();
+                let answer = parsed.solve(part);
+                let elapsed = start.elapsed();
+
+                match answer {
+                    Ok(answer) => println!(
+                        "{} day {:02} part {}: {} ({:.2?})",
+                        day.year, day.day, part, answer, elapsed
+                    ),
+                    Err(e) => unanswered.push(format!(
+                        "{}: no answer to part {}: {}",
+                        path.display(),
+                        part,
+                        e
+                    )),
+                }
+            }
+        })
+        .map_err(|e| format!("{}: {:#}", path.display(), e))?;
+    if unanswered.is_empty() {
+        Ok(())
+    } else {
+        Err(unanswered.join("\n"))
+    }
+}
+
+fn run_all_days(args: RunAllArgs) -> Result<(), String> {
+    let jobs = days::DAYS
+        .iter()
+        .filter(|d| args.year.is_none_or(|year| d.year == year))
+        .filter(|d| args.day.is_none_or(|day| d.day == day))
+        .map(|day| {
+            let key = bench::key(day.year, day.day);
+            let path = bench::input_path(&day_dir(day.year, day.day))
+                .ok_or_else(|| format!("{}: no input to run", key))?;
+            let input = fs::read_to_string(&path)
+                .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+            Ok(Job { day, input })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    if jobs.is_empty() {
+        return Err("no solutions match".to_string());
+    }
+
+    let rows = run_all::run_all(&jobs, args.settings);
+    print!("{}", run_all::table(&rows));
+
+    let failed = rows.iter().filter(|row| !row.passed()).count();
+    if failed > 0 {
+        return Err(format!("{} of {} parts failed", failed, rows.len()));
+    }
+    Ok(())
+}
+
+fn fetch_inputs(args: FetchArgs) -> Result<(), String> {
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => days::DAYS
+            .iter()
+            .filter(|d| d.year == args.year)
+            .map(|d| d.day)
+            .collect(),
+    };
+    if days.is_empty() {
+        return Err(format!("no solutions for {}", args.year));
+    }
+
+    let session = fetch::session_token().map_err(|e| e.to_string())?;
+    let mut fetcher = Fetcher::new(&session);
+
+    for day in days {
+        let fetched = if args.force {
+            fetcher.refetch(workspace_root(), args.year, day)
+        } else {
+            fetcher.fetch_cached(workspace_root(), args.year, day)
+        }
+        .map_err(|e| format!("{} day {:02}: {}", args.year, day, e))?;
+        let status = if fetched.from_cache {
+            "cached"
+        } else {
+            "fetched"
+        };
+        println!(
+            "{} day {:02}: {} {}",
+            args.year,
+            day,
+            status,
+            fetched.path.display()
+        );
+    }
+    Ok(())
+}
+
+fn submit_answer(args: SubmitArgs) -> Result<(), String> {
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let day = days::find(args.year, args.day)
+                .ok_or_else(|| format!("no solution for {} day {}", args.year, args.day))?;
+            if !day.has_part(args.part) {
+                return Err(format!(
+                    "{} day {} has no part {}",
+                    day.year, day.day, args.part
+                ));
+            }
+            let path = fetch::input_path(workspace_root(), args.year, args.day);
+            let input = fs::read_to_string(&path).map_err(|e| {
+                format!(
+                    "unable to read {}: {} (run `aoc fetch {} {}` first)",
+                    path.display(),
+                    e,
+                    args.year,
+                    args.day
+                )
+            })?;
+            let mut answer = Ok(String::new());
+            day.puzzle
+                .with_input(&input, &mut |parsed| answer = parsed.solve(args.part))
+                .map_err(|e| format!("{}: {:#}", path.display(), e))?;
+            let answer =
+                answer.map_err(|e| format!("{}: no answer to submit: {}", path.display(), e))?;
+            if submit::check_answer(&answer).is_err() {
+                return Err(format!(
+                    "the answer spans several lines, read the letters off it and pass them as <answer>:\n{}",
+                    answer
+                ));
+            }
+            answer
+        }
+    };
+
+    let ledger_path = Ledger::path(workspace_root(), args.year, args.day);
+    let mut ledger = Ledger::load(&ledger_path)
+        .map_err(|e| format!("unable to read {}: {}", ledger_path.display(), e))?;
+
+    let session = fetch::session_token().map_err(|e| e.to_string())?;
+    let outcome = Submitter::new(&session)
+        .submit_checked(&mut ledger, args.year, args.day, args.part, &answer)
+        .map_err(|e| e.to_string())?;
+
+    println!(
+        "{} day {:02} part {}: {} is {}{}",
+        args.year,
+        args.day,
+        args.part,
+        answer.trim(),
+        outcome.verdict,
+        if outcome.from_ledger {
+            " (known from the ledger, not submitted)"
+        } else {
+            ""
+        }
+    );
+    Ok(())
+}
+
+fn run_benchmarks(args: BenchArgs) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, use `cargo run --release`");
+    }
+
+    let days: Vec<&Day> = days::DAYS
+        .iter()
+        .filter(|d| args.year.is_none_or(|year| d.year == year))
+        .filter(|d| args.day.is_none_or(|day| d.day == day))
+        .collect();
+    if days.is_empty() {
+        return Err("no solutions match".to_string());
+    }
+
+    let baseline_path = args
+        .baseline
+        .unwrap_or_else(|| workspace_root().join("bench").join("baseline.json"));
+    let mut baseline = bench::load_baseline(&baseline_path)?;
+
+    let mut regressions = Vec::new();
+    for day in days {
+        let key = bench::key(day.year, day.day);
+        let dir = day_dir(day.year, day.day);
+        let path =
+            bench::input_path(&dir).ok_or_else(|| format!("{}: no input to benchmark", key))?;
+        let input = fs::read_to_string(&path)
+            .map_err(|e| format!("unable to read {}: {}", path.display(), e))?;
+        let input_name = path.file_name().unwrap().to_string_lossy();
+
+        let timings = bench::bench_day(day, &input_name, &input, args.settings)
+            .map_err(|e| format!("{}: {}", key, e))?;
+        let previous = baseline.get(&key);
+
+        bench::print_day(&key, &timings, previous);
+        if let Some(previous) = previous {
+            for regression in bench::regressions(&timings, previous, args.threshold) {
+                regressions.push(format!(
+                    "{} {}: {} -> {} ({:.2}x)",
+                    key,
+                    regression.stage,
+                    bench::Nanos(regression.baseline_ns),
+                    bench::Nanos(regression.current_ns),
+                    regression.ratio()
+                ));
+            }
+        }
+
+        if args.save {
+            baseline.insert(key, timings);
+        }
+    }
+
+    if args.save {
+        bench::save_baseline(&baseline_path, &baseline)?;
+        println!("saved {}", baseline_path.display());
+    }
+
+    if regressions.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "{} regression(s) past {}x the baseline:\n    {}",
+        regressions.len(),
+        args.threshold,
+        regressions.join("\n    ")
+    ))
+}
+
+fn new_day(args: NewArgs) -> Result<(), String> {
+    let root = workspace_root()
+        .canonicalize()
+        .map_err(|e| format!("unable to find the workspace: {}", e))?;
+    let written = scaffold::create(&root, &args.new_day).map_err(|e| e.to_string())?;
+
+    for path in written {
+        println!(
+            "wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    println!(
+        "run it with `cargo run -p aoc -- run {} {}`",
+        args.new_day.year, args.new_day.day
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => parse_run_args(args).and_then(run),
+        Some("run-all") => parse_run_all_args(args).and_then(run_all_days),
+        Some("fetch") => parse_fetch_args(args).and_then(fetch_inputs),
+        Some("submit") => parse_submit_args(args).and_then(submit_answer),
+        Some("bench") => parse_bench_args(args).and_then(run_benchmarks),
+        Some("new") => parse_new_args(args).and_then(new_day),
+        Some("-h" | "--help") => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Some(command) => Err(format!("unknown command '{}'\n\n{}", command, USAGE)),
+        None => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Runs many days at once on a pool of workers, each day on a thread of its
+//! own so that one that panics or never finishes only fails its own rows.
+//!
+//! A day that runs past the timeout is abandoned rather than stopped, since
+//! a thread can't be killed: it keeps its core busy until the process exits,
+//! but the rest of the days carry on without it.
+
+use std::any::Any;
+use std::fmt;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::days::Day;
+
+/// Days run on threads named with this prefix, so their panics can be kept
+/// off the terminal and reported in the table instead.
+const THREAD_PREFIX: &str = "aoc-run-all ";
+
+/// Recursive solutions get the same room as they have on the main thread.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// A day to run and the input to run it on.
+pub struct Job {
+    pub day: &'static Day,
+    pub input: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    /// How many days run at the same time.
+    pub workers: usize,
+    /// How long a day may take, parsing and every part together.
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The input didn't parse, or has no answer to the part.
+    Error(String),
+    Panicked(String),
+    TimedOut,
+    /// An earlier part of the day panicked or timed out, so this one never
+    /// ran.
+    Skipped,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Error(e) => write!(f, "error: {}", e),
+            Status::Panicked(message) => write!(f, "panicked: {}", message),
+            Status::TimedOut => write!(f, "timed out"),
+            Status::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+/// How one part of one day went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// How long the part took, or ran before it failed.
+    pub elapsed: Duration,
+}
+
+impl Row {
+    pub fn passed(&self) -> bool {
+        self.status == Status::Ok
+    }
+}
+
+/// What a day's thread reports back as it goes.
+enum Event {
+    Solved {
+        part: u8,
+        answer: Result<String, String>,
+        elapsed: Duration,
+    },
+    ParseFailed(String),
+}
+
+/// Runs every job, `settings.workers` at a time, and returns a row for each
+/// part of each day, ordered by year, day and part.
+pub fn run_all(jobs: &[Job], settings: Settings) -> Vec<Row> {
+    quiet_job_panics();
+
+    let next = AtomicUsize::new(0);
+    let rows = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..settings.workers.max(1) {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let day_rows = run_day(job, settings.timeout);
+                    rows.lock().unwrap().extend(day_rows);
+                }
+            });
+        }
+    });
+
+    let mut rows = rows.into_inner().unwrap();
+    rows.sort_by_key(|row| (row.year, row.day, row.part));
+    rows
+}
+
+fn run_day(job: &Job, timeout: Duration) -> Vec<Row> {
+    let day = job.day;
+    let input = job.input.clone();
+    let (events, received) = mpsc::channel();
+    let solver = thread::Builder::new()
+        .name(format!("{}{} day {:02}", THREAD_PREFIX, day.year, day.day))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let parsed = day.puzzle.with_input(&input, &mut |parsed| {
+                for part in day.parts() {
+                    let start = Instant::now();
+                    let answer = parsed.solve(part);
+                    let elapsed = start.elapsed();
+                    let _ = events.send(Event::Solved {
+                        part,
+                        answer,
+                        elapsed,
+                    });
+                }
+            });
+            if let Err(e) = parsed {
+                let _ = events.send(Event::ParseFailed(e.to_string()));
+            }
+        });
+
+    let row = |part, answer, status, elapsed| Row {
+        year: day.year,
+        day: day.day,
+        part,
+        answer,
+        status,
+        elapsed,
+    };
+    let solver = match solver {
+        Ok(solver) => solver,
+        Err(e) => {
+            let status = Status::Error(format!("unable to start a thread: {}", e));
+            return day
+                .parts()
+                .map(|part| row(part, None, status.clone(), Duration::ZERO))
+                .collect();
+        }
+    };
+
+    let deadline = Instant::now() + timeout;
+    let mut rows = Vec::new();
+    let mut started = Instant::now();
+    let mut parts = day.parts();
+    while let Some(part) = parts.next() {
+        let wait = deadline.saturating_duration_since(Instant::now());
+        let failed = match received.recv_timeout(wait) {
+            Ok(Event::Solved {
+                part,
+                answer,
+                elapsed,
+            }) => {
+                let (answer, status) = match answer {
+                    Ok(answer) => (Some(answer), Status::Ok),
+                    Err(e) => (None, Status::Error(format!("no answer: {}", e))),
+                };
+                rows.push(row(part, answer, status, elapsed));
+                started = Instant::now();
+                continue;
+            }
+            Ok(Event::ParseFailed(e)) => {
+                // Nothing was solved, so every part has the same error.
+                rows.extend(
+                    day.parts()
+                        .map(|part| row(part, None, Status::Error(e.clone()), started.elapsed())),
+                );
+                return rows;
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => Status::TimedOut,
+            // The thread is gone without a word, so it panicked.
+            Err(mpsc::RecvTimeoutError::Disconnected) => match solver.join() {
+                Err(payload) => Status::Panicked(panic_message(&*payload)),
+                Ok(()) => Status::Panicked("the day stopped early".to_string()),
+            },
+        };
+
+        rows.push(row(part, None, failed, started.elapsed()));
+        rows.extend(parts.map(|part| row(part, None, Status::Skipped, Duration::ZERO)));
+        break;
+    }
+    rows
+}
+
+/// Keeps the default panic message off the terminal for panics on a day's
+/// thread, which show up in the table instead. Panics anywhere else are
+/// reported as usual.
+fn quiet_job_panics() {
+    static QUIET: Once = Once::new();
+    QUIET.call_once(|| {
+        let report = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let on_job = thread::current()
+                .name()
+                .is_some_and(|name| name.starts_with(THREAD_PREFIX));
+            if !on_job {
+                report(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "a panic without a message".to_string()
+    }
+}
+
+/// Lays `rows` out as a table with a header and aligned columns. Answers
+/// that span several lines, such as letters drawn in ASCII art, are shown
+/// by their line count.
+pub fn table(rows: &[Row]) -> String {
+    let cells: Vec<[String; 6]> = rows
+        .iter()
+        .map(|row| {
+            let answer = match &row.answer {
+                Some(answer) if answer.contains('\n') => {
+                    format!("({} lines)", answer.lines().count())
+                }
+                Some(answer) => answer.clone(),
+                None => "-".to_string(),
+            };
+            [
+                row.year.to_string(),
+                format!("{:02}", row.day),
+                row.part.to_string(),
+                answer,
+                row.status.to_string(),
+                format!("{:.2?}", row.elapsed),
+            ]
+        })
+        .collect();
+
+    let header = ["year", "day", "part", "answer", "status", "time"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&cells) {
+        let line = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                // Times line up on the right, like the numbers they are.
+                5 => format!("{:>width$}", cell),
+                _ => format!("{:<width$}", cell),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
@@ -0,0 +1,495 @@
+//! Creates the crate for a new day and registers it everywhere a day is listed.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::answers::Answers;
+
+const REPO_URL: &str = "https://github.com/brettseton/advent_of_code/blob/master";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day's directory is already there; nothing was touched.
+    Exists(PathBuf),
+    /// A file that lists the days is missing the section a day goes in.
+    Malformed {
+        path: PathBuf,
+        expected: String,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Malformed { path, expected } => {
+                write!(f, "{}: expected {}", path.display(), expected)
+            }
+            ScaffoldError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+/// A day to create, with the puzzle title for the Readme when it is known.
+#[derive(Debug, Clone)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+    pub title: Option<String>,
+}
+
+impl NewDay {
+    pub fn new(year: u16, day: u8) -> NewDay {
+        NewDay {
+            year,
+            day,
+            title: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: &str) -> NewDay {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// The day's directory relative to the workspace root, like `2026/day01`.
+    pub fn dir(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    /// The package name, like `aoc-2026-day01`.
+    pub fn package(&self) -> String {
+        format!("aoc-{}-day{:02}", self.year, self.day)
+    }
+
+    /// The day's fuzz target, like `parse_2026_day01`.
+    pub fn fuzz_target(&self) -> String {
+        format!("parse_{}_day{:02}", self.year, self.day)
+    }
+
+    fn krate(&self) -> String {
+        self.package().replace('-', "_")
+    }
+
+    fn solution(&self) -> String {
+        format!("Day{:02}", self.day)
+    }
+}
+
+/// Creates the day under `root` and registers it with the workspace, the
+/// runner, the Readme, the expected answers and the fuzzing crate. Returns
+/// every file written or changed.
+pub fn create(root: &Path, new_day: &NewDay) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let dir = root.join(new_day.dir());
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    // Work out every edit before writing anything, so a file we can't make
+    // sense of leaves the tree as it was.
+    let edits = [
+        ("Cargo.toml", add_workspace_member as Edit),
+        ("aoc/Cargo.toml", add_day_dependency),
+        ("aoc/src/days.rs", add_runner_day),
+        ("Readme.md", add_readme_row),
+        ("answers.json", add_answers),
+        ("fuzz/Cargo.toml", add_fuzz_target),
+    ]
+    .into_iter()
+    .map(|(file, edit)| {
+        let path = root.join(file);
+        let text = fs::read_to_string(&path)?;
+        let edited = edit(&text, new_day).map_err(|expected| ScaffoldError::Malformed {
+            path: path.clone(),
+            expected,
+        })?;
+        Ok((path, text, edited))
+    })
+    .collect::<Result<Vec<_>, ScaffoldError>>()?;
+
+    let mut written = Vec::new();
+    for (file, contents) in [
+        ("Cargo.toml", cargo_toml(new_day)),
+        ("src/lib.rs", lib_rs(new_day)),
+        ("src/main.rs", main_rs(new_day)),
+        ("input/test1.txt", String::new()),
+    ] {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().expect("files live in a directory"))?;
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    let target = root
+        .join("fuzz/fuzz_targets")
+        .join(format!("{}.rs", new_day.fuzz_target()));
+    fs::create_dir_all(target.parent().expect("targets live in a directory"))?;
+    fs::write(&target, fuzz_target_rs(new_day))?;
+    written.push(target);
+
+    for (path, before, after) in edits {
+        if after != before {
+            fs::write(&path, after)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+/// Rewrites one file that lists the days, or says what it was missing.
+type Edit = fn(&str, &NewDay) -> Result<String, String>;
+
+pub fn cargo_toml(new_day: &NewDay) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[lints]
+workspace = true
+
+[dependencies]
+aoc-parse = {{ path = "../../common/parse" }}
+aoc-solution = {{ path = "../../common/solution" }}
+"#,
+        new_day.package()
+    )
+}
+
+pub fn lib_rs(new_day: &NewDay) -> String {
+    let name = new_day.solution();
+    format!(
+        r#"use aoc_parse::ParseError;
+use aoc_solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {{
+    Ok(input.lines().collect())
+}}
+
+pub struct {name};
+
+impl Solution for {name} {{
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {{
+        parse(input)
+    }}
+
+    fn part1(lines: &Self::Input<'_>) -> usize {{
+        lines.len()
+    }}
+
+    fn part2(lines: &Self::Input<'_>) -> usize {{
+        lines.len()
+    }}
+}}
+
+pub fn part1(input: &str) -> usize {{
+    {name}::part1(&parse(input).expect("Failed to parse input"))
+}}
+
+pub fn part2(input: &str) -> usize {{
+    {name}::part2(&parse(input).expect("Failed to parse input"))
+}}
+"#
+    )
+}
+
+pub fn main_rs(new_day: &NewDay) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use {}::{name};
+
+fn main() -> ExitCode {{
+    aoc_solution::cli::main::<{name}>(concat!(env!("CARGO_MANIFEST_DIR"), "/input/test1.txt"))
+}}
+"#,
+        new_day.krate(),
+        name = new_day.solution()
+    )
+}
+
+pub fn fuzz_target_rs(new_day: &NewDay) -> String {
+    format!(
+        r#"#![no_main]
+
+use aoc_solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {{
+    let _ = {}::{}::parse(input);
+}});
+"#,
+        new_day.krate(),
+        new_day.solution()
+    )
+}
+
+/// Adds `"<year>/*"` to the workspace members unless the year is already
+/// covered.
+pub fn add_workspace_member(manifest: &str, new_day: &NewDay) -> Result<String, String> {
+    let member = format!("\"{}/*\"", new_day.year);
+    let expected = || "a `members = [...]` line".to_string();
+
+    let start = manifest.find("members = [").ok_or_else(expected)?;
+    let end = start + manifest[start..].find(']').ok_or_else(expected)?;
+    if manifest[start..end].contains(&member) {
+        return Ok(manifest.to_string());
+    }
+    Ok(format!(
+        "{}, {}{}",
+        &manifest[..end],
+        member,
+        &manifest[end..]
+    ))
+}
+
+/// Adds the day to the dependencies of a crate that lists every day, like the
+/// runner, after the last day before it.
+pub fn add_day_dependency(manifest: &str, new_day: &NewDay) -> Result<String, String> {
+    let package = new_day.package();
+    let line = format!("{} = {{ path = \"../{}\" }}", package, new_day.dir());
+    insert_sorted(
+        manifest,
+        &line,
+        |existing| {
+            let name = existing.split(" = ").next()?;
+            is_day_package(name).then(|| name.to_string())
+        },
+        &package,
+    )
+    .ok_or_else(|| "a [dependencies] section listing the days".to_string())
+}
+
+/// Adds the day to the runner's `DAYS` table, after the last day before it.
+pub fn add_runner_day(days: &str, new_day: &NewDay) -> Result<String, String> {
+    let line = format!(
+        "    day!({}, {}, {}::{}),",
+        new_day.year,
+        new_day.day,
+        new_day.krate(),
+        new_day.solution()
+    );
+    insert_sorted(
+        days,
+        &line,
+        |existing| {
+            let args = existing.trim().strip_prefix("day!(")?;
+            let mut args = args.split(", ");
+            let year: u16 = args.next()?.parse().ok()?;
+            let day: u8 = args.next()?.parse().ok()?;
+            Some((year, day))
+        },
+        &(new_day.year, new_day.day),
+    )
+    .ok_or_else(|| "a `DAYS` table of `day!` entries".to_string())
+}
+
+/// Adds the day to the fuzzing crate's dependencies and gives it a target.
+pub fn add_fuzz_target(manifest: &str, new_day: &NewDay) -> Result<String, String> {
+    let mut manifest = add_day_dependency(manifest, new_day)?;
+    let name = new_day.fuzz_target();
+    if manifest.contains(&format!("name = \"{}\"", name)) {
+        return Ok(manifest);
+    }
+    manifest.truncate(manifest.trim_end().len());
+    manifest.push_str(&format!(
+        "\n\n[[bin]]\nname = \"{0}\"\npath = \"fuzz_targets/{0}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        name
+    ));
+    Ok(manifest)
+}
+
+/// Adds the day's row to the year's table in the Readme, creating the year's
+/// section and quick link first if this is its first day.
+pub fn add_readme_row(readme: &str, new_day: &NewDay) -> Result<String, String> {
+    let year = new_day.year;
+    let heading = format!("# [Advent of Code {0}](https://adventofcode.com/{0})", year);
+    let mut readme = readme.to_string();
+
+    if !readme.contains(&heading) {
+        readme =
+            add_quick_link(&readme, year).ok_or_else(|| "a `# Quick Links` list".to_string())?;
+        readme.truncate(readme.trim_end().len());
+        readme.push_str(&format!(
+            "\n\n<br><br>\n\n{}\n{}",
+            heading,
+            year_section(year)
+        ));
+    }
+
+    let section = readme.find(&heading).expect("the heading was just added");
+    let section_end = readme[section + heading.len()..]
+        .find("\n# ")
+        .map_or(readme.len(), |i| section + heading.len() + i + 1);
+
+    let label = match &new_day.title {
+        Some(title) => format!("Day {:02}: {}", new_day.day, title),
+        None => format!("Day {:02}", new_day.day),
+    };
+    let row = format!(
+        "| [{}](https://adventofcode.com/{}/day/{}) | [🦀]({}/{}/src/main.rs) |",
+        label,
+        year,
+        new_day.day,
+        REPO_URL,
+        new_day.dir()
+    );
+    let day_link = format!("https://adventofcode.com/{}/day/", year);
+    let table = insert_sorted(
+        &readme[section..section_end],
+        &row,
+        |existing| {
+            if existing.starts_with("|-") {
+                return Some(0);
+            }
+            let (_, after) = existing.split_once(&day_link)?;
+            after.split(')').next()?.parse::<u8>().ok()
+        },
+        &new_day.day,
+    )
+    .ok_or_else(|| format!("a table under the {} heading", year))?;
+
+    Ok(format!(
+        "{}{}{}",
+        &readme[..section],
+        table,
+        &readme[section_end..]
+    ))
+}
+
+/// Expects 0 for both parts of the empty test input, which is what the
+/// skeleton answers.
+pub fn add_answers(answers: &str, new_day: &NewDay) -> Result<String, String> {
+    let mut answers: Answers =
+        serde_json::from_str(answers).map_err(|e| format!("answers as JSON ({})", e))?;
+    let inputs = answers
+        .entry(new_day.year.to_string())
+        .or_default()
+        .entry(format!("day{:02}", new_day.day))
+        .or_default();
+    if inputs.is_empty() {
+        let zero = Value::from(0);
+        inputs.insert(
+            "test1.txt".to_string(),
+            BTreeMap::from([
+                ("part1".to_string(), zero.clone()),
+                ("part2".to_string(), zero),
+            ]),
+        );
+    }
+    let mut text = serde_json::to_string_pretty(&answers).expect("answers serialize");
+    text.push('\n');
+    Ok(text)
+}
+
+fn add_quick_link(readme: &str, year: u16) -> Option<String> {
+    let line = format!("- [{0}](#advent-of-code-{0})", year);
+    let start = readme.find("# Quick Links\n")?;
+    let end = readme[start..]
+        .find("\n\n")
+        .map_or(readme.len(), |i| start + i + 1);
+    let links = insert_sorted(
+        &readme[start..end],
+        &line,
+        |existing| existing.strip_prefix("- [")?.get(..4)?.parse::<u16>().ok(),
+        &year,
+    )?;
+    Some(format!("{}{}{}", &readme[..start], links, &readme[end..]))
+}
+
+/// The run and test instructions and the empty table that open every year.
+fn year_section(year: u16) -> String {
+    format!(
+        r#"
+### Run
+To run navigate to the folder and run:
+ ```
+ cargo run
+ ```
+
+eg
+```
+cd {0}/day01
+cargo run
+```
+
+### Test
+Every day's answers are checked against `answers.json` by running, from the repository root:
+```
+cargo test -p aoc --test answers -- {0}
+```
+
+### Contents
+| Puzzle|Code (Part 1 and 2)|
+|-------|----|
+"#,
+        year
+    )
+}
+
+fn is_day_package(name: &str) -> bool {
+    let mut parts = name.split('-');
+    parts.next() == Some("aoc")
+        && parts
+            .next()
+            .is_some_and(|y| y.len() == 4 && y.parse::<u16>().is_ok())
+        && parts.next().is_some_and(|d| d.starts_with("day"))
+        && parts.next().is_none()
+}
+
+/// Inserts `line` into `text` after the last line whose key sorts before
+/// `key`, where `key_of` picks out the lines that take part in the ordering.
+/// Nothing changes if a line with the same key is already there, and `None`
+/// means no line took part at all.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    line: &str,
+    key_of: impl Fn(&str) -> Option<K>,
+    key: &K,
+) -> Option<String> {
+    let mut after = None;
+    let mut offset = 0;
+    for existing in text.split_inclusive('\n') {
+        offset += existing.len();
+        match key_of(existing.trim_end()) {
+            Some(k) if &k == key => return Some(text.to_string()),
+            Some(k) if &k < key => after = Some(offset),
+            Some(_) => after = after.or(Some(offset - existing.len())),
+            None => {}
+        }
+    }
+
+    let at = after?;
+    let newline = if at > 0 && !text[..at].ends_with('\n') {
+        "\n"
+    } else {
+        ""
+    };
+    Some(format!(
+        "{}{}{}\n{}",
+        &text[..at],
+        newline,
+        line,
+        &text[at..]
+    ))
+}
@@ -0,0 +1,417 @@
+//! Posts answers to adventofcode.com and remembers what the site said about them.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::fetch::{self, FetchError, Throttle, DEFAULT_BASE_URL, DEFAULT_MIN_INTERVAL};
+
+/// Name of the ledger file inside a day's directory.
+pub const LEDGER_FILE: &str = "submissions.tsv";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Incorrect,
+    /// Submitted too soon after the previous answer.
+    RateLimited {
+        seconds: u64,
+    },
+    /// The part has already been solved, or part 1 is still open.
+    WrongLevel,
+    /// The response did not contain any message we recognise.
+    Unrecognised,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the HTML page returned for a submission.
+    pub fn from_response(body: &str) -> Verdict {
+        let text = body.to_lowercase();
+
+        if text.contains("that's the right answer") {
+            Verdict::Correct
+        } else if text.contains("you gave an answer too recently") {
+            Verdict::RateLimited {
+                seconds: wait_seconds(&text).unwrap_or(60),
+            }
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("that's not the right answer") {
+            Verdict::Incorrect
+        } else if text.contains("you don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unrecognised
+        }
+    }
+
+    /// Whether the verdict says something lasting about the answer and so
+    /// belongs in the ledger.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+
+    fn token(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited { .. } => "rate-limited",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unrecognised => "unrecognised",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited { seconds } => write!(f, "rate limited, wait {} seconds", seconds),
+            Verdict::WrongLevel => write!(f, "already solved or not unlocked yet"),
+            Verdict::Unrecognised => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            _ => Err(format!("unknown verdict '{}'", s)),
+        }
+    }
+}
+
+/// Pulls the remaining wait out of "You have 1m 23s left to wait."
+fn wait_seconds(text: &str) -> Option<u64> {
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("you have ")? + "you have ".len();
+
+    let mut seconds = 0;
+    for part in text[start..end].split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(seconds)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every final verdict received for one day, one tab separated line each.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    pub fn path(root: &Path, year: u16, day: u8) -> PathBuf {
+        root.join(year.to_string())
+            .join(format!("day{:02}", day))
+            .join(LEDGER_FILE)
+    }
+
+    /// Loads the ledger at `path`, which does not have to exist yet.
+    pub fn load(path: &Path) -> io::Result<Ledger> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let entries = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_entry(line).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}:{}: malformed ledger entry", path.display(), i + 1),
+                    )
+                })
+            })
+            .collect::<io::Result<Vec<Entry>>>()?;
+
+        Ok(Ledger {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// What is already known about an answer without asking the server: a
+    /// verdict given to the same answer before, a correct answer on record
+    /// for the part, or a too high/too low bound the answer falls outside of.
+    pub fn check(&self, part: u8, answer: &str) -> Option<Verdict> {
+        let answer = answer.trim();
+        let entries = self.entries.iter().filter(|e| e.part == part);
+
+        if let Some(entry) = entries.clone().find(|e| e.answer == answer) {
+            return Some(entry.verdict);
+        }
+        if entries.clone().any(|e| e.verdict == Verdict::Correct) {
+            return Some(Verdict::Incorrect);
+        }
+
+        let value: i128 = answer.parse().ok()?;
+        for entry in entries {
+            let Ok(bound) = entry.answer.parse::<i128>() else {
+                continue;
+            };
+            match entry.verdict {
+                Verdict::TooHigh if value >= bound => return Some(Verdict::TooHigh),
+                Verdict::TooLow if value <= bound => return Some(Verdict::TooLow),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Appends a verdict to the ledger file. Verdicts that say nothing about
+    /// the answer itself, like being rate limited, are not recorded.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) -> io::Result<()> {
+        if !verdict.is_final() {
+            return Ok(());
+        }
+        check_answer(answer)?;
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}\t{}\t{}", part, answer.trim(), verdict.token())?;
+
+        self.entries.push(Entry {
+            part,
+            answer: answer.trim().to_string(),
+            verdict,
+        });
+        Ok(())
+    }
+}
+
+/// Answers have to fit on one ledger line, so multi-line ones, like the
+/// letters some puzzles draw, are turned away before anything is sent.
+pub fn check_answer(answer: &str) -> io::Result<()> {
+    if answer.trim().contains(['\n', '\r', '\t']) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the answer spans several lines or contains a tab",
+        ));
+    }
+    Ok(())
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.split('\t');
+    let part = fields.next()?.parse().ok()?;
+    let answer = fields.next()?.to_string();
+    let verdict = fields.next()?.parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    Some(Entry {
+        part,
+        answer,
+        verdict,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    /// The verdict came from the ledger and nothing was sent.
+    pub from_ledger: bool,
+}
+
+pub struct Submitter {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Submitter {
+    pub fn new(session: &str) -> Submitter {
+        Submitter {
+            agent: fetch::agent(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.to_string(),
+            throttle: Throttle::new(DEFAULT_MIN_INTERVAL),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Submitter {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Submitter {
+        self.throttle.min_interval = min_interval;
+        self
+    }
+
+    /// Posts an answer, always going to the server.
+    pub fn submit(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, FetchError> {
+        self.throttle.wait();
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &fetch::session_cookie(&self.session))
+            .send_form(&[("level", &level), ("answer", answer.trim())])
+            .map_err(fetch::request_error)?;
+
+        Ok(Verdict::from_response(&response.into_string()?))
+    }
+
+    /// Posts an answer unless the ledger already rules on it, and records
+    /// the verdict the server gives.
+    pub fn submit_checked(
+        &mut self,
+        ledger: &mut Ledger,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, FetchError> {
+        check_answer(answer)?;
+        if let Some(verdict) = ledger.check(part, answer) {
+            return Ok(Outcome {
+                verdict,
+                from_ledger: true,
+            });
+        }
+
+        let verdict = self.submit(year, day, part, answer)?;
+        ledger.record(part, answer, verdict)?;
+        Ok(Outcome {
+            verdict,
+            from_ledger: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(entries: &[(u8, &str, Verdict)]) -> Ledger {
+        Ledger {
+            path: PathBuf::new(),
+            entries: entries
+                .iter()
+                .map(|&(part, answer, verdict)| Entry {
+                    part,
+                    answer: answer.to_string(),
+                    verdict,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reads_verdicts_from_responses() {
+        let cases = [
+            ("<p>That's the right answer!  You are one gold star closer.</p>", Verdict::Correct),
+            ("<p>That's not the right answer; your answer is too high.</p>", Verdict::TooHigh),
+            ("<p>That's not the right answer; your answer is too low.</p>", Verdict::TooLow),
+            ("<p>That's not the right answer.  If you're stuck...</p>", Verdict::Incorrect),
+            (
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+                Verdict::WrongLevel,
+            ),
+            ("<p>Something else entirely</p>", Verdict::Unrecognised),
+        ];
+
+        for (body, verdict) in cases {
+            assert_eq!(Verdict::from_response(body), verdict, "{}", body);
+        }
+    }
+
+    #[test]
+    fn reads_the_rate_limit_wait() {
+        let body = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p>";
+        assert_eq!(
+            Verdict::from_response(body),
+            Verdict::RateLimited { seconds: 83 }
+        );
+
+        let body = "<p>You gave an answer too recently.  You have 45s left to wait.</p>";
+        assert_eq!(
+            Verdict::from_response(body),
+            Verdict::RateLimited { seconds: 45 }
+        );
+    }
+
+    #[test]
+    fn repeats_known_verdicts() {
+        let ledger = ledger(&[(1, "abc", Verdict::Incorrect)]);
+        assert_eq!(ledger.check(1, "abc"), Some(Verdict::Incorrect));
+        assert_eq!(ledger.check(2, "abc"), None);
+    }
+
+    #[test]
+    fn rejects_answers_outside_the_bounds() {
+        let ledger = ledger(&[(1, "100", Verdict::TooHigh), (1, "40", Verdict::TooLow)]);
+        assert_eq!(ledger.check(1, "150"), Some(Verdict::TooHigh));
+        assert_eq!(ledger.check(1, "100"), Some(Verdict::TooHigh));
+        assert_eq!(ledger.check(1, "12"), Some(Verdict::TooLow));
+        assert_eq!(ledger.check(1, "41"), None);
+        assert_eq!(ledger.check(2, "150"), None);
+    }
+
+    #[test]
+    fn rejects_other_answers_once_solved() {
+        let ledger = ledger(&[(2, "42", Verdict::Correct)]);
+        assert_eq!(ledger.check(2, "42"), Some(Verdict::Correct));
+        assert_eq!(ledger.check(2, "43"), Some(Verdict::Incorrect));
+    }
+}
@@ -0,0 +1,47 @@
+//! Checks every day against `answers.json` at the workspace root and reports
+//! all of them at once.
+//!
+//! Pass years or days to check only those, e.g.
+//! `cargo test -p aoc --test answers -- 2023 2024/day05`.
+
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc::answers;
+
+fn main() -> ExitCode {
+    let root = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let filters: Vec<String> = env::args()
+        .skip(1)
+        .filter(|a| !a.starts_with('-'))
+        .collect();
+    let keep = |year: &str, day: &str| {
+        let key = format!("{}/{}", year, day);
+        filters.is_empty() || filters.iter().any(|f| f == year || *f == key)
+    };
+
+    let answers = match answers::load(&root.join("answers.json")) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let checks = answers::check(root, &answers, keep);
+    for check in &checks {
+        println!("{}", check);
+    }
+
+    let failed = checks.iter().filter(|c| !c.passed()).count();
+    println!(
+        "\nanswers: {} passed, {} failed",
+        checks.len() - failed,
+        failed
+    );
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
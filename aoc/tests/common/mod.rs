@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use tiny_http::{Header, Response, Server};
+
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+/// A local stand-in for adventofcode.com that answers every request with the
+/// given status and body and records what it was sent.
+pub struct MockServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl MockServer {
+    pub fn start(status: u16, body: &'static str) -> MockServer {
+        let server = Server::http("127.0.0.1:0").expect("mock server should start");
+        let port = server.server_addr().to_ip().expect("tcp listener").port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let header = |name: &str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case(name))
+                        .map(|h| h.value.to_string())
+                };
+                let mut received = Recorded {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie: header("Cookie"),
+                    user_agent: header("User-Agent"),
+                    body: String::new(),
+                };
+                let _ = request.as_reader().read_to_string(&mut received.body);
+                recorded.lock().unwrap().push(received);
+
+                let content_type = Header::from_bytes("Content-Type", "text/html").unwrap();
+                let response = Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(content_type);
+                let _ = request.respond(response);
+            }
+        });
+
+        MockServer {
+            base_url: format!("http://127.0.0.1:{}", port),
+            requests,
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// A fresh scratch directory for one test.
+pub fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+    root
+}
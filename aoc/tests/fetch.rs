@@ -0,0 +1,183 @@
+mod common;
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+use aoc::fetch::{self, FetchError, Fetcher};
+use common::{temp_root, MockServer};
+
+fn fetcher(server: &MockServer) -> Fetcher {
+    Fetcher::new("secret-token")
+        .with_base_url(&server.base_url)
+        .with_min_interval(Duration::ZERO)
+}
+
+#[test]
+fn fetch_requests_the_day_input() {
+    let server = MockServer::start(200, "1\n2\n3\n");
+
+    let input = fetcher(&server).fetch(2024, 7).unwrap();
+
+    assert_eq!(input, "1\n2\n3\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].url, "/2024/day/7/input");
+}
+
+#[test]
+fn fetch_sends_session_cookie_and_user_agent() {
+    let server = MockServer::start(200, "input");
+
+    fetcher(&server).fetch(2022, 1).unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.cookie.as_deref(), Some("session=secret-token"));
+    assert_eq!(request.user_agent.as_deref(), Some(fetch::USER_AGENT));
+}
+
+#[test]
+fn fetch_cached_writes_into_the_day_input_directory() {
+    let server = MockServer::start(200, "cached input\n");
+    let root = temp_root("fetch-write");
+
+    let fetched = fetcher(&server).fetch_cached(&root, 2023, 5).unwrap();
+
+    assert!(!fetched.from_cache);
+    assert_eq!(fetched.path, root.join("2023/day05/input/input.txt"));
+    assert_eq!(fs::read_to_string(&fetched.path).unwrap(), "cached input\n");
+}
+
+#[test]
+fn fetch_cached_does_not_hit_the_server_twice() {
+    let server = MockServer::start(200, "once\n");
+    let root = temp_root("fetch-reuse");
+    let mut fetcher = fetcher(&server);
+
+    let first = fetcher.fetch_cached(&root, 2025, 3).unwrap();
+    let second = fetcher.fetch_cached(&root, 2025, 3).unwrap();
+
+    assert!(!first.from_cache);
+    assert!(second.from_cache);
+    assert_eq!(second.input, "once\n");
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn refetch_replaces_the_cached_input() {
+    let server = MockServer::start(200, "fresh\n");
+    let root = temp_root("fetch-refetch");
+    let path = fetch::input_path(&root, 2022, 9);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "stale\n").unwrap();
+
+    let fetched = fetcher(&server).refetch(&root, 2022, 9).unwrap();
+
+    assert!(!fetched.from_cache);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "fresh\n");
+}
+
+#[test]
+fn refetch_keeps_the_cached_input_when_the_download_fails() {
+    let server = MockServer::start(503, "busy");
+    let root = temp_root("fetch-refetch-failed");
+    let path = fetch::input_path(&root, 2022, 9);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "keep me\n").unwrap();
+
+    let result = fetcher(&server).refetch(&root, 2022, 9);
+
+    assert!(matches!(result, Err(FetchError::Server(503))));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "keep me\n");
+}
+
+#[test]
+fn fetch_is_throttled() {
+    let server = MockServer::start(200, "input");
+    let mut fetcher = fetcher(&server).with_min_interval(Duration::from_millis(200));
+
+    let start = Instant::now();
+    fetcher.fetch(2024, 1).unwrap();
+    fetcher.fetch(2024, 2).unwrap();
+    fetcher.fetch(2024, 3).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(400));
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn fetch_reports_a_rejected_session() {
+    let server = MockServer::start(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    );
+    let root = temp_root("fetch-rejected");
+
+    let result = fetcher(&server).fetch_cached(&root, 2024, 1);
+
+    assert!(matches!(result, Err(FetchError::Unauthorized)));
+    assert!(!fetch::input_path(&root, 2024, 1).exists());
+}
+
+#[test]
+fn fetch_reports_a_server_failure_apart_from_a_rejected_session() {
+    let server = MockServer::start(500, "Internal Server Error");
+    let root = temp_root("fetch-server-error");
+
+    let result = fetcher(&server).fetch_cached(&root, 2024, 1);
+
+    assert!(matches!(result, Err(FetchError::Server(500))));
+    assert!(!fetch::input_path(&root, 2024, 1).exists());
+}
+
+#[test]
+fn fetch_reports_a_locked_puzzle() {
+    let server = MockServer::start(
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks!",
+    );
+
+    let result = fetcher(&server).fetch(2030, 1);
+
+    assert!(matches!(result, Err(FetchError::NotFound)));
+}
+
+#[test]
+fn fetch_reports_other_statuses() {
+    let server = MockServer::start(429, "slow down");
+
+    let result = fetcher(&server).fetch(2024, 1);
+
+    assert!(matches!(result, Err(FetchError::Status(429))));
+}
+
+#[test]
+fn session_prefers_the_environment() {
+    let root = temp_root("fetch-session-env");
+    let config = root.join("session");
+    fs::write(&config, "from-file\n").unwrap();
+
+    let token = fetch::load_session(Some("from-env".to_string()), Some(&config)).unwrap();
+
+    assert_eq!(token, "from-env");
+}
+
+#[test]
+fn session_falls_back_to_the_config_file() {
+    let root = temp_root("fetch-session-file");
+    let config = root.join("session");
+    fs::write(&config, "from-file\n").unwrap();
+
+    let token = fetch::load_session(None, Some(&config)).unwrap();
+
+    assert_eq!(token, "from-file");
+}
+
+#[test]
+fn session_is_required() {
+    let root = temp_root("fetch-session-missing");
+
+    let result = fetch::load_session(None, Some(&root.join("session")));
+
+    assert!(matches!(result, Err(FetchError::MissingSession)));
+}
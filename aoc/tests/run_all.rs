@@ -0,0 +1,160 @@
+use std::thread;
+use std::time::Duration;
+
+use aoc::days::{Day, Parsed, Puzzle};
+use aoc::run_all::{self, Job, Row, Settings, Status};
+use aoc_solution::ParseError;
+
+/// A two-part puzzle whose parts behave as `solve` says, and whose input
+/// only parses when it is "ok".
+struct Fake {
+    solve: fn(u8) -> Result<String, String>,
+}
+
+impl Puzzle for Fake {
+    fn parts(&self) -> u8 {
+        2
+    }
+
+    fn with_input(&self, input: &str, then: &mut dyn FnMut(&dyn Parsed)) -> Result<(), ParseError> {
+        if input != "ok" {
+            return Err(ParseError::new(input, input, "ok"));
+        }
+        then(self);
+        Ok(())
+    }
+}
+
+impl Parsed for Fake {
+    fn solve(&self, part: u8) -> Result<String, String> {
+        (self.solve)(part)
+    }
+}
+
+static SOLVES: Day = Day {
+    year: 2000,
+    day: 1,
+    puzzle: &Fake {
+        solve: |part| Ok((part * 10).to_string()),
+    },
+    simulate: None,
+};
+
+static PANICS: Day = Day {
+    year: 2000,
+    day: 2,
+    puzzle: &Fake {
+        solve: |_| unreachable!("No path found"),
+    },
+    simulate: None,
+};
+
+static HANGS: Day = Day {
+    year: 2000,
+    day: 3,
+    puzzle: &Fake {
+        solve: |part| {
+            if part == 2 {
+                thread::sleep(Duration::from_secs(60));
+            }
+            Ok("fast".to_string())
+        },
+    },
+    simulate: None,
+};
+
+static REJECTS: Day = Day {
+    year: 2000,
+    day: 4,
+    puzzle: &Fake {
+        solve: |_| unreachable!("the input never parses"),
+    },
+    simulate: None,
+};
+
+static UNANSWERED: Day = Day {
+    year: 2000,
+    day: 5,
+    puzzle: &Fake {
+        solve: |part| match part {
+            1 => Ok("50".to_string()),
+            _ => Err("nothing lines up".to_string()),
+        },
+    },
+    simulate: None,
+};
+
+fn job(day: &'static Day, input: &str) -> Job {
+    Job {
+        day,
+        input: input.to_string(),
+    }
+}
+
+fn run(jobs: &[Job]) -> Vec<Row> {
+    run_all::run_all(
+        jobs,
+        Settings {
+            workers: 2,
+            timeout: Duration::from_millis(300),
+        },
+    )
+}
+
+#[test]
+fn a_failing_day_only_fails_its_own_parts() {
+    let rows = run(&[
+        job(&HANGS, "ok"),
+        job(&PANICS, "ok"),
+        job(&SOLVES, "ok"),
+        job(&REJECTS, "bad"),
+        job(&UNANSWERED, "ok"),
+    ]);
+
+    let summary: Vec<_> = rows
+        .iter()
+        .map(|row| (row.day, row.part, row.answer.as_deref(), row.status.clone()))
+        .collect();
+    let parse_error = Status::Error("line 1, column 1: expected ok, found \"bad\"".to_string());
+    assert_eq!(
+        summary,
+        [
+            (1, 1, Some("10"), Status::Ok),
+            (1, 2, Some("20"), Status::Ok),
+            (
+                2,
+                1,
+                None,
+                Status::Panicked("internal error: entered unreachable code: No path found".into())
+            ),
+            (2, 2, None, Status::Skipped),
+            (3, 1, Some("fast"), Status::Ok),
+            (3, 2, None, Status::TimedOut),
+            (4, 1, None, parse_error.clone()),
+            (4, 2, None, parse_error),
+            (5, 1, Some("50"), Status::Ok),
+            (
+                5,
+                2,
+                None,
+                Status::Error("no answer: nothing lines up".to_string())
+            ),
+        ]
+    );
+    assert!(rows[5].elapsed >= Duration::from_millis(250));
+}
+
+#[test]
+fn prints_an_aligned_table() {
+    let rows = run(&[job(&SOLVES, "ok"), job(&PANICS, "ok")]);
+    let table = run_all::table(&rows);
+    let lines: Vec<_> = table.lines().collect();
+
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("year  day  part  answer  status"));
+    assert!(lines[1].starts_with("2000  01   1     10      ok"));
+    assert!(lines[3].starts_with(
+        "2000  02   1     -       panicked: internal error: entered unreachable code: No path found"
+    ));
+    assert!(lines[4].starts_with("2000  02   2     -       skipped"));
+}
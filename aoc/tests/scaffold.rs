@@ -0,0 +1,223 @@
+// Only the temporary directories are needed, not the mock server.
+#[allow(dead_code)]
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use aoc::scaffold::{self, NewDay, ScaffoldError};
+use common::temp_root;
+
+const WORKSPACE: &str = r#"[workspace]
+resolver = "2"
+members = ["aoc", "common/*", "2024/*", "2025/*"]
+"#;
+
+const RUNNER_MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+aoc-2024-day01 = { path = "../2024/day01" }
+aoc-2025-day01 = { path = "../2025/day01" }
+aoc-2025-day03 = { path = "../2025/day03" }
+aoc-solution = { path = "../common/solution" }
+"#;
+
+const DAYS: &str = "pub const DAYS: &[Day] = &[
+    day!(2024, 1, aoc_2024_day01::Day01),
+    day!(2025, 1, aoc_2025_day01::Day01),
+    day!(2025, 3, aoc_2025_day03::Day03),
+];
+";
+
+const README: &str = "# Quick Links
+- [2024](#advent-of-code-2024)
+- [2025](#advent-of-code-2025)
+
+# Runner
+Runs things.
+
+# [Advent of Code 2024](https://adventofcode.com/2024)
+
+### Contents
+| Puzzle|Code (Part 1 and 2)|
+|-------|----|
+| [Day 01: Historian Hysteria](https://adventofcode.com/2024/day/1) | [🦀](https://github.com/brettseton/advent_of_code/blob/master/2024/day01/src/main.rs) |
+
+<br><br>
+
+# [Advent of Code 2025](https://adventofcode.com/2025)
+
+### Contents
+| Puzzle|Code (Part 1 and 2)|
+|-------|----|
+| [Day 01: Secret Entrance](https://adventofcode.com/2025/day/1) | [🦀](https://github.com/brettseton/advent_of_code/blob/master/2025/day01/src/main.rs) |
+| [Day 03: Lobby](https://adventofcode.com/2025/day/3) | [🦀](https://github.com/brettseton/advent_of_code/blob/master/2025/day03/src/main.rs) |
+
+";
+
+const ANSWERS: &str = r#"{
+  "2025": {
+    "day01": {
+      "test1.txt": {
+        "part1": 3,
+        "part2": 6
+      }
+    }
+  }
+}
+"#;
+
+const FUZZ_MANIFEST: &str = r#"[package]
+name = "aoc-fuzz"
+
+[dependencies]
+libfuzzer-sys = "0.4"
+aoc-solution = { path = "../common/solution" }
+aoc-2025-day01 = { path = "../2025/day01" }
+aoc-2025-day03 = { path = "../2025/day03" }
+
+[[bin]]
+name = "parse_2025_day01"
+path = "fuzz_targets/parse_2025_day01.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+fn workspace(name: &str) -> std::path::PathBuf {
+    let root = temp_root(name);
+    fs::create_dir_all(root.join("aoc/src")).unwrap();
+    fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+    fs::write(root.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+    fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+    fs::write(root.join("Readme.md"), README).unwrap();
+    fs::write(root.join("answers.json"), ANSWERS).unwrap();
+    fs::create_dir_all(root.join("fuzz")).unwrap();
+    fs::write(root.join("fuzz/Cargo.toml"), FUZZ_MANIFEST).unwrap();
+    root
+}
+
+fn read(root: &Path, file: &str) -> String {
+    fs::read_to_string(root.join(file)).unwrap()
+}
+
+#[test]
+fn new_day_gets_a_crate_that_follows_the_day_layout() {
+    let root = workspace("scaffold-crate");
+
+    scaffold::create(&root, &NewDay::new(2025, 2)).unwrap();
+
+    assert!(read(&root, "2025/day02/Cargo.toml").contains("name = \"aoc-2025-day02\""));
+    assert_eq!(read(&root, "2025/day02/input/test1.txt"), "");
+
+    let lib = read(&root, "2025/day02/src/lib.rs");
+    assert!(lib.contains("impl Solution for Day02 {"));
+    assert!(lib.contains("pub fn part1(input: &str) -> usize {"));
+
+    let target = read(&root, "fuzz/fuzz_targets/parse_2025_day02.rs");
+    assert!(target.contains("let _ = aoc_2025_day02::Day02::parse(input);"));
+
+    let main = read(&root, "2025/day02/src/main.rs");
+    assert!(main.contains("use aoc_2025_day02::Day02;"));
+    assert!(main.contains("aoc_solution::cli::main::<Day02>("));
+}
+
+#[test]
+fn new_day_is_registered_in_order() {
+    let root = workspace("scaffold-register");
+
+    scaffold::create(&root, &NewDay::new(2025, 2).with_title("Gift Shop")).unwrap();
+
+    assert_eq!(read(&root, "Cargo.toml"), WORKSPACE);
+    assert!(read(&root, "aoc/Cargo.toml").contains(
+        "aoc-2025-day01 = { path = \"../2025/day01\" }\n\
+         aoc-2025-day02 = { path = \"../2025/day02\" }\n\
+         aoc-2025-day03 = { path = \"../2025/day03\" }\n"
+    ));
+    assert!(read(&root, "aoc/src/days.rs").contains(
+        "    day!(2025, 1, aoc_2025_day01::Day01),\n    \
+         day!(2025, 2, aoc_2025_day02::Day02),\n    \
+         day!(2025, 3, aoc_2025_day03::Day03),\n"
+    ));
+    assert!(read(&root, "Readme.md").contains(
+        "/2025/day01/src/main.rs) |\n\
+         | [Day 02: Gift Shop](https://adventofcode.com/2025/day/2) | [🦀](https://github.com/brettseton/advent_of_code/blob/master/2025/day02/src/main.rs) |\n\
+         | [Day 03: Lobby]"
+    ));
+    let fuzz = read(&root, "fuzz/Cargo.toml");
+    assert!(fuzz.contains(
+        "aoc-2025-day01 = { path = \"../2025/day01\" }\n\
+         aoc-2025-day02 = { path = \"../2025/day02\" }\n"
+    ));
+    assert!(fuzz.ends_with(
+        "bench = false\n\n\
+         [[bin]]\n\
+         name = \"parse_2025_day02\"\n\
+         path = \"fuzz_targets/parse_2025_day02.rs\"\n\
+         test = false\n\
+         doc = false\n\
+         bench = false\n"
+    ));
+    assert!(read(&root, "answers.json").contains(
+        "    \"day01\": {\n      \"test1.txt\": {\n        \"part1\": 3,\n        \"part2\": 6\n      }\n    },\n    \"day02\": {\n      \"test1.txt\": {\n        \"part1\": 0,\n        \"part2\": 0\n      }\n    }\n"
+    ));
+}
+
+#[test]
+fn first_day_of_a_year_adds_the_year() {
+    let root = workspace("scaffold-year");
+
+    scaffold::create(&root, &NewDay::new(2026, 1)).unwrap();
+
+    assert!(read(&root, "Cargo.toml")
+        .contains("members = [\"aoc\", \"common/*\", \"2024/*\", \"2025/*\", \"2026/*\"]"));
+    assert!(read(&root, "aoc/Cargo.toml").contains(
+        "aoc-2025-day03 = { path = \"../2025/day03\" }\n\
+         aoc-2026-day01 = { path = \"../2026/day01\" }\n\
+         aoc-solution"
+    ));
+    assert!(
+        read(&root, "aoc/src/days.rs").ends_with("    day!(2026, 1, aoc_2026_day01::Day01),\n];\n")
+    );
+
+    let readme = read(&root, "Readme.md");
+    assert!(readme.contains("- [2025](#advent-of-code-2025)\n- [2026](#advent-of-code-2026)\n\n"));
+    assert!(readme.contains(
+        "/2025/day03/src/main.rs) |\n\n<br><br>\n\n# [Advent of Code 2026](https://adventofcode.com/2026)\n\n### Run\n"
+    ));
+    assert!(readme.ends_with(
+        "|-------|----|\n\
+         | [Day 01](https://adventofcode.com/2026/day/1) | [🦀](https://github.com/brettseton/advent_of_code/blob/master/2026/day01/src/main.rs) |\n"
+    ));
+}
+
+#[test]
+fn existing_day_is_left_alone() {
+    let root = workspace("scaffold-exists");
+    fs::create_dir_all(root.join("2025/day01/src")).unwrap();
+
+    let err = scaffold::create(&root, &NewDay::new(2025, 1)).unwrap_err();
+
+    assert!(matches!(err, ScaffoldError::Exists(path) if path == root.join("2025/day01")));
+    assert_eq!(read(&root, "aoc/src/days.rs"), DAYS);
+    assert_eq!(read(&root, "Readme.md"), README);
+    assert_eq!(read(&root, "answers.json"), ANSWERS);
+}
+
+#[test]
+fn unrecognised_listing_writes_nothing() {
+    let root = workspace("scaffold-malformed");
+    fs::write(
+        root.join("aoc/src/days.rs"),
+        "pub const DAYS: &[Day] = &[];\n",
+    )
+    .unwrap();
+
+    let err = scaffold::create(&root, &NewDay::new(2025, 2)).unwrap_err();
+
+    assert!(matches!(err, ScaffoldError::Malformed { .. }));
+    assert!(!root.join("2025/day02").exists());
+    assert_eq!(read(&root, "Readme.md"), README);
+    assert_eq!(read(&root, "answers.json"), ANSWERS);
+}
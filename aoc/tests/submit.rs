@@ -0,0 +1,176 @@
+mod common;
+
+use std::fs;
+use std::time::Duration;
+
+use aoc::fetch;
+use aoc::submit::{Ledger, Submitter, Verdict};
+use common::{temp_root, MockServer};
+
+const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer to finding the chief historian.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.</p></article>";
+const TOO_LOW: &str =
+    "<article><p>That's not the right answer; your answer is too low.</p></article>";
+const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 2m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+fn submitter(server: &MockServer) -> Submitter {
+    Submitter::new("secret-token")
+        .with_base_url(&server.base_url)
+        .with_min_interval(Duration::ZERO)
+}
+
+fn ledger(name: &str) -> Ledger {
+    let root = temp_root(&format!("submit-{}", name));
+    Ledger::load(&Ledger::path(&root, 2024, 1)).unwrap()
+}
+
+#[test]
+fn submit_posts_the_answer_form() {
+    let server = MockServer::start(200, CORRECT);
+
+    let verdict = submitter(&server).submit(2024, 1, 2, "1234\n").unwrap();
+
+    assert_eq!(verdict, Verdict::Correct);
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.url, "/2024/day/1/answer");
+    assert_eq!(request.body, "level=2&answer=1234");
+    assert_eq!(request.cookie.as_deref(), Some("session=secret-token"));
+    assert_eq!(request.user_agent.as_deref(), Some(fetch::USER_AGENT));
+}
+
+#[test]
+fn submit_parses_each_verdict() {
+    let cases = [
+        (CORRECT, Verdict::Correct),
+        (TOO_HIGH, Verdict::TooHigh),
+        (TOO_LOW, Verdict::TooLow),
+        (RATE_LIMITED, Verdict::RateLimited { seconds: 125 }),
+    ];
+
+    for (body, expected) in cases {
+        let server = MockServer::start(200, body);
+        assert_eq!(
+            submitter(&server).submit(2024, 1, 1, "7").unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn submit_checked_records_verdicts_in_the_ledger() {
+    let server = MockServer::start(200, TOO_HIGH);
+    let root = temp_root("submit-record");
+    let path = Ledger::path(&root, 2024, 1);
+    let mut ledger = Ledger::load(&path).unwrap();
+
+    let outcome = submitter(&server)
+        .submit_checked(&mut ledger, 2024, 1, 1, "500")
+        .unwrap();
+
+    assert_eq!(outcome.verdict, Verdict::TooHigh);
+    assert!(!outcome.from_ledger);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1\t500\ttoo-high\n");
+
+    let reloaded = Ledger::load(&path).unwrap();
+    assert_eq!(reloaded.entries(), ledger.entries());
+}
+
+#[test]
+fn submit_checked_never_resubmits_a_wrong_answer() {
+    let server = MockServer::start(200, TOO_LOW);
+    let mut ledger = ledger("resubmit");
+    let mut submitter = submitter(&server);
+
+    submitter
+        .submit_checked(&mut ledger, 2024, 1, 1, "10")
+        .unwrap();
+    let outcome = submitter
+        .submit_checked(&mut ledger, 2024, 1, 1, "10")
+        .unwrap();
+
+    assert_eq!(outcome.verdict, Verdict::TooLow);
+    assert!(outcome.from_ledger);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_checked_enforces_bounds_locally() {
+    let server = MockServer::start(200, TOO_HIGH);
+    let mut ledger = ledger("bounds");
+    let mut submitter = submitter(&server);
+
+    submitter
+        .submit_checked(&mut ledger, 2024, 1, 1, "500")
+        .unwrap();
+    let outcome = submitter
+        .submit_checked(&mut ledger, 2024, 1, 1, "900")
+        .unwrap();
+
+    assert_eq!(outcome.verdict, Verdict::TooHigh);
+    assert!(outcome.from_ledger);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_checked_does_not_record_rate_limits() {
+    let server = MockServer::start(200, RATE_LIMITED);
+    let mut ledger = ledger("rate-limited");
+    let mut submitter = submitter(&server);
+
+    let first = submitter
+        .submit_checked(&mut ledger, 2024, 1, 1, "42")
+        .unwrap();
+    let second = submitter
+        .submit_checked(&mut ledger, 2024, 1, 1, "42")
+        .unwrap();
+
+    assert_eq!(first.verdict, Verdict::RateLimited { seconds: 125 });
+    assert!(!second.from_ledger);
+    assert!(ledger.entries().is_empty());
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn ledger_rejects_malformed_lines() {
+    let root = temp_root("submit-malformed");
+    let path = Ledger::path(&root, 2024, 1);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "1\t500\ttoo-high\nnot a ledger line\n").unwrap();
+
+    let error = Ledger::load(&path).unwrap_err();
+
+    assert!(error.to_string().ends_with(":2: malformed ledger entry"));
+}
+
+#[test]
+fn ledger_reloads_after_several_records() {
+    let root = temp_root("submit-reload");
+    let path = Ledger::path(&root, 2024, 1);
+    let mut ledger = Ledger::load(&path).unwrap();
+
+    ledger.record(1, "500\n", Verdict::TooHigh).unwrap();
+    ledger.record(1, "EFHGBRLK", Verdict::Incorrect).unwrap();
+    ledger.record(2, " 42 ", Verdict::Correct).unwrap();
+
+    let reloaded = Ledger::load(&path).unwrap();
+    assert_eq!(reloaded.entries(), ledger.entries());
+    assert_eq!(reloaded.check(2, "42"), Some(Verdict::Correct));
+}
+
+#[test]
+fn submit_checked_refuses_multi_line_answers() {
+    let server = MockServer::start(200, CORRECT);
+    let root = temp_root("submit-multi-line");
+    let path = Ledger::path(&root, 2022, 10);
+    let mut ledger = Ledger::load(&path).unwrap();
+    let drawing = "###..#..#\n#..#.#.#.\n###..##..\n";
+
+    let result = submitter(&server).submit_checked(&mut ledger, 2022, 10, 2, drawing);
+
+    assert!(result.is_err());
+    assert!(ledger.record(2, drawing, Verdict::Correct).is_err());
+    assert!(ledger.record(2, "1\t2", Verdict::Correct).is_err());
+    assert!(server.requests().is_empty());
+    assert!(Ledger::load(&path).unwrap().entries().is_empty());
+}
@@ -0,0 +1,263 @@
+//! Finding where a sequence of states starts repeating, so a simulation asked
+//! about its billionth step only has to be run until it loops.
+//!
+//! Every search takes a starting state and a step function that returns the
+//! next state, or `None` once the sequence ends; a sequence that ends has no
+//! cycle. [`floyd`] and [`brent`] only compare states and keep a few of them
+//! around. [`find`] and [`Detector`] remember a key for every state instead,
+//! which finds the cycle as soon as it closes and lets the key leave out
+//! whatever doesn't decide the future, such as a running total.
+
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+/// A sequence whose state at step `start + len` is the same as at `start`,
+/// so it repeats the steps `start..start + len` forever after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The step before `start + len` whose state step `n` repeats.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    /// The value at step `n` of something that goes up by the same amount
+    /// every time round the cycle, such as the height of a tower, from
+    /// `values` recorded for at least the first `start + len + 1` steps.
+    pub fn extrapolate(&self, values: &[i64], n: usize) -> i64 {
+        if n < values.len() {
+            return values[n];
+        }
+
+        let per_cycle = values[self.start + self.len] - values[self.start];
+        let cycles = ((n - self.start) / self.len) as i64;
+        values[self.index(n)] + cycles * per_cycle
+    }
+}
+
+/// Floyd's tortoise and hare: the hare steps twice as fast as the tortoise
+/// until they meet inside the cycle.
+pub fn floyd<S: Clone + PartialEq>(
+    start: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut tortoise = step(&start)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    // The meeting point is a whole number of cycles from the start, so
+    // walking one from the start and one from there meets at the cycle.
+    let mut cycle_start = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        cycle_start += 1;
+    }
+
+    let mut len = 1;
+    hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        len += 1;
+    }
+
+    Some(Cycle {
+        start: cycle_start,
+        len,
+    })
+}
+
+/// Brent's algorithm: the tortoise jumps to the hare at every power of two,
+/// which finds the length directly and takes fewer steps than [`floyd`].
+pub fn brent<S: Clone + PartialEq>(
+    start: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start)?;
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare)?;
+        len += 1;
+    }
+
+    // With the hare a cycle ahead, the two meet where the cycle starts.
+    let mut cycle_start = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..len {
+        hare = step(&hare)?;
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        cycle_start += 1;
+    }
+
+    Some(Cycle {
+        start: cycle_start,
+        len,
+    })
+}
+
+/// Whether the sequence from `start` repeats rather than ends, without the
+/// second walk [`brent`] takes to find where the cycle starts.
+pub fn loops<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> bool {
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = start;
+    let Some(mut hare) = step(&tortoise) else {
+        return false;
+    };
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        let Some(next) = step(&hare) else {
+            return false;
+        };
+        hare = next;
+        len += 1;
+    }
+    true
+}
+
+/// Steps from `start` until a state's key has been seen before, and returns
+/// the cycle with every state up to it: `history[cycle.index(n)]` is the
+/// state at step `n`.
+pub fn find<S, K: Hash + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+    mut key: impl FnMut(&S) -> K,
+) -> Option<(Cycle, Vec<S>)> {
+    let mut detector = Detector::new();
+    let mut history = Vec::new();
+    let mut state = start;
+    loop {
+        if let Some(cycle) = detector.record(key(&state)) {
+            return Some((cycle, history));
+        }
+        let next = step(&state)?;
+        history.push(state);
+        state = next;
+    }
+}
+
+/// Spots a cycle in states handed over one step at a time, for simulations
+/// that are easier to drive from their own loop than through a step
+/// function.
+#[derive(Debug, Clone)]
+pub struct Detector<K> {
+    seen: FxHashMap<K, usize>,
+    steps: usize,
+}
+
+impl<K: Hash + Eq> Detector<K> {
+    pub fn new() -> Self {
+        Detector {
+            seen: FxHashMap::default(),
+            steps: 0,
+        }
+    }
+
+    /// Records the key of the state at the next step, and returns the cycle
+    /// once a key comes round again.
+    pub fn record(&mut self, key: K) -> Option<Cycle> {
+        let step = self.steps;
+        self.steps += 1;
+        self.seen.insert(key, step).map(|start| Cycle {
+            start,
+            len: step - start,
+        })
+    }
+
+    /// How many states have been recorded.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl<K: Hash + Eq> Default for Detector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up from 0 and jumps back to `start` after `start + len - 1`.
+    fn rho(start: usize, len: usize) -> impl FnMut(&usize) -> Option<usize> {
+        move |&x| Some(if x + 1 < start + len { x + 1 } else { start })
+    }
+
+    #[test]
+    fn every_search_finds_the_same_cycle() {
+        for start in 0..6 {
+            for len in 1..6 {
+                let expected = Some(Cycle { start, len });
+                assert_eq!(floyd(0, rho(start, len)), expected);
+                assert_eq!(brent(0, rho(start, len)), expected);
+                assert!(loops(0, rho(start, len)));
+                let (cycle, history) = find(0, rho(start, len), |&x| x).unwrap();
+                assert_eq!(Some(cycle), expected);
+                assert_eq!(history, (0..start + len).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn a_sequence_that_ends_has_no_cycle() {
+        let step = |&x: &u32| (x < 10).then_some(x + 1);
+        assert_eq!(floyd(0, step), None);
+        assert_eq!(brent(0, step), None);
+        assert!(!loops(0, step));
+        assert_eq!(find(0, step, |&x| x), None);
+    }
+
+    #[test]
+    fn keys_can_ignore_part_of_the_state() {
+        // The running total never repeats, but the position it adds does.
+        let step = |&(x, total): &(i64, i64)| Some(((x * 3) % 7, total + x));
+        let (cycle, _) = find((1, 0), step, |&(x, _)| x).unwrap();
+        assert_eq!(cycle, Cycle { start: 0, len: 6 });
+
+        let totals = std::iter::successors(Some((1, 0)), step)
+            .map(|(_, total)| total)
+            .take(101)
+            .collect::<Vec<_>>();
+        assert_eq!(cycle.extrapolate(&totals[..=6], 100), totals[100]);
+    }
+
+    #[test]
+    fn maps_late_steps_back_into_the_first_cycle() {
+        let cycle = Cycle { start: 3, len: 4 };
+        assert_eq!(cycle.index(2), 2);
+        assert_eq!(cycle.index(3), 3);
+        assert_eq!(cycle.index(7), 3);
+        assert_eq!(cycle.index(1_000_000_002), 6);
+    }
+}
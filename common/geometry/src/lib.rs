@@ -0,0 +1,340 @@
+//! Points and vectors in two and three dimensions, over whatever number type
+//! a puzzle's coordinates come in.
+//!
+//! The same types serve as positions and as the offsets between them:
+//! `b - a` is the step from `a` to `b`, and `a + step * 2` is two steps on.
+//! Both parse from comma separated coordinates, `x,y` and `x,y,z`, with
+//! spaces around the numbers allowed.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+
+/// A coordinate type: the primitive integers and floats.
+pub trait Number:
+    Copy
+    + PartialOrd
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+}
+
+impl<T> Number for T where
+    T: Copy
+        + PartialOrd
+        + Default
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+{
+}
+
+/// `|a - b|`, which for unsigned types can't be had from `abs`.
+fn distance<T: Number>(a: T, b: T) -> T {
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+fn max<T: Number>(a: T, b: T) -> T {
+    if a < b {
+        b
+    } else {
+        a
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T: Number> Vec2<T> {
+    pub fn dot(self, other: Vec2<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z of the 3D cross product: positive when `other` is
+    /// counterclockwise of `self` with y pointing up, zero when parallel.
+    pub fn cross(self, other: Vec2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Steps between the two along the axes.
+    pub fn manhattan(self, other: Vec2<T>) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    /// Steps between the two when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Vec2<T>) -> T {
+        max(distance(self.x, other.x), distance(self.y, other.y))
+    }
+
+    /// The square of the straight line distance, which stays exact for
+    /// integers.
+    pub fn distance_squared(self, other: Vec2<T>) -> T {
+        let d = self - other;
+        d.dot(d)
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Vec2<T> {
+    /// Turned a quarter counterclockwise with y pointing up, which is
+    /// clockwise on a screen or grid where y points down.
+    pub fn rotate_left(self) -> Vec2<T> {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Turned a quarter clockwise with y pointing up, which is
+    /// counterclockwise on a screen or grid where y points down.
+    pub fn rotate_right(self) -> Vec2<T> {
+        Vec2::new(self.y, -self.x)
+    }
+}
+
+impl<T: Number> Vec3<T> {
+    pub fn dot(self, other: Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The vector at right angles to both, zero when they are parallel.
+    pub fn cross(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Steps between the two along the axes.
+    pub fn manhattan(self, other: Vec3<T>) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y) + distance(self.z, other.z)
+    }
+
+    /// Steps between the two when diagonal steps are allowed.
+    pub fn chebyshev(self, other: Vec3<T>) -> T {
+        max(
+            max(distance(self.x, other.x), distance(self.y, other.y)),
+            distance(self.z, other.z),
+        )
+    }
+
+    /// The square of the straight line distance, which stays exact for
+    /// integers.
+    pub fn distance_squared(self, other: Vec3<T>) -> T {
+        let d = self - other;
+        d.dot(d)
+    }
+}
+
+/// The arithmetic both vectors share, applied to each coordinate.
+macro_rules! impl_ops {
+    ($vec:ident { $($axis:ident: $expected:literal),* }) => {
+        impl<T: Add<Output = T>> Add for $vec<T> {
+            type Output = $vec<T>;
+
+            fn add(self, other: $vec<T>) -> $vec<T> {
+                $vec { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vec<T> {
+            type Output = $vec<T>;
+
+            fn sub(self, other: $vec<T>) -> $vec<T> {
+                $vec { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vec<T> {
+            type Output = $vec<T>;
+
+            fn neg(self) -> $vec<T> {
+                $vec { $($axis: -self.$axis),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn mul(self, scale: T) -> $vec<T> {
+                $vec { $($axis: self.$axis * scale),* }
+            }
+        }
+
+        impl<T: Div<Output = T> + Copy> Div<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn div(self, divisor: T) -> $vec<T> {
+                $vec { $($axis: self.$axis / divisor),* }
+            }
+        }
+
+        impl<T: Add<Output = T> + Copy> AddAssign for $vec<T> {
+            fn add_assign(&mut self, other: $vec<T>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Sub<Output = T> + Copy> SubAssign for $vec<T> {
+            fn sub_assign(&mut self, other: $vec<T>) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> MulAssign<T> for $vec<T> {
+            fn mul_assign(&mut self, scale: T) {
+                *self = *self * scale;
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $vec<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let coordinates = [$(self.$axis.to_string()),*];
+                write!(f, "{}", coordinates.join(","))
+            }
+        }
+
+        impl<T: FromStr> FromStr for $vec<T> {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut coordinates = s.split(',');
+                let mut coordinate = |expected| {
+                    let token = aoc_parse::next(s, s, &mut coordinates, expected)?;
+                    aoc_parse::number(s, token.trim())
+                };
+                let parsed = $vec { $($axis: coordinate($expected)?),* };
+                if let Some(extra) = coordinates.next() {
+                    return Err(ParseError::new(s, extra, "the end of the point"));
+                }
+                Ok(parsed)
+            }
+        }
+    };
+}
+
+impl_ops!(Vec2 {
+    x: "an x coordinate",
+    y: "a y coordinate"
+});
+impl_ops!(Vec3 {
+    x: "an x coordinate",
+    y: "a y coordinate",
+    z: "a z coordinate"
+});
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Vec2<T> {
+        Vec2::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Vec3<T> {
+        Vec3::new(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_and_scales_each_coordinate() {
+        let a = Vec2::new(1, 2);
+        let b = Vec2::new(4, -3);
+        assert_eq!(a + b, Vec2::new(5, -1));
+        assert_eq!(b - a, Vec2::new(3, -5));
+        assert_eq!(-a, Vec2::new(-1, -2));
+        assert_eq!(a * 3, Vec2::new(3, 6));
+        assert_eq!(Vec3::new(4, 6, 8) / 2, Vec3::new(2, 3, 4));
+
+        let mut c = Vec3::new(1, 1, 1);
+        c += Vec3::new(1, 2, 3);
+        c -= Vec3::new(0, 0, 1);
+        c *= 2;
+        assert_eq!(c, Vec3::new(4, 6, 6));
+    }
+
+    #[test]
+    fn measures_distances_without_going_negative() {
+        let a = Vec2::new(1u32, 7);
+        let b = Vec2::new(4u32, 3);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Vec2::new(1i64, 7).distance_squared(Vec2::new(4, 3)), 25);
+
+        let p = Vec3::new(0usize, 5, 2);
+        let q = Vec3::new(3usize, 1, 2);
+        assert_eq!(p.manhattan(q), 7);
+        assert_eq!(p.chebyshev(q), 4);
+        assert_eq!(Vec3::new(1, 2, 2).distance_squared(Vec3::default()), 9);
+    }
+
+    #[test]
+    fn multiplies_vectors() {
+        assert_eq!(Vec2::new(2, 3).dot(Vec2::new(4, -1)), 5);
+        assert_eq!(Vec2::new(1, 0).cross(Vec2::new(0, 1)), 1);
+        assert_eq!(Vec2::new(2, 4).cross(Vec2::new(1, 2)), 0);
+
+        let x = Vec3::new(1.0, 0.0, 0.0);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(x.cross(y), Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(x.dot(y), 0.0);
+    }
+
+    #[test]
+    fn rotates_by_quarter_turns() {
+        let east = Vec2::new(1, 0);
+        assert_eq!(east.rotate_left(), Vec2::new(0, 1));
+        assert_eq!(east.rotate_right(), Vec2::new(0, -1));
+        assert_eq!(east.rotate_left().rotate_left(), -east);
+        assert_eq!(
+            Vec2::new(3, -2).rotate_left().rotate_right(),
+            Vec2::new(3, -2)
+        );
+    }
+
+    #[test]
+    fn parses_comma_separated_coordinates() {
+        assert_eq!("498,4".parse(), Ok(Vec2::new(498, 4)));
+        assert_eq!(" 19, 13, 30".parse(), Ok(Vec3::new(19i64, 13, 30)));
+        assert_eq!(Vec3::new(1, -2, 3).to_string(), "1,-2,3");
+
+        let e = "1,2,3".parse::<Vec2<i32>>().unwrap_err();
+        assert_eq!(e.expected, "the end of the point");
+        let e = "1,2".parse::<Vec3<i32>>().unwrap_err();
+        assert_eq!(e.expected, "a z coordinate");
+        let e = "1,b".parse::<Vec2<i32>>().unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (3, "a number"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Cliques in an undirected graph: sets of nodes that all have edges to
+//! each other.
+
+use crate::{ones, AdjacencyMatrix};
+
+fn intersect(a: &[u64], b: &[u64]) -> Vec<u64> {
+    a.iter().zip(b).map(|(a, b)| a & b).collect()
+}
+
+fn count(bits: &[u64]) -> usize {
+    bits.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+impl AdjacencyMatrix {
+    /// Every clique that no other node could join, found by Bron–Kerbosch
+    /// with pivoting. Each comes out with its nodes in increasing order.
+    ///
+    /// The matrix must be symmetric, as it is for a graph whose edges were
+    /// all added both ways.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut cliques = Vec::new();
+        if self.is_empty() {
+            return cliques;
+        }
+        let mut all = vec![0; self.len.div_ceil(64)];
+        for id in 0..self.len {
+            all[id / 64] |= 1 << (id % 64);
+        }
+        let none = vec![0; all.len()];
+        self.bron_kerbosch(&mut Vec::new(), all, none, &mut cliques);
+        cliques
+    }
+
+    /// A largest clique, ties going to the first found.
+    pub fn max_clique(&self) -> Vec<usize> {
+        self.maximal_cliques()
+            .into_iter()
+            .rev()
+            .max_by_key(|clique| clique.len())
+            .unwrap_or_default()
+    }
+
+    /// Extends `clique` with each node of `candidates` in turn, never
+    /// reporting a clique that a node of `excluded` could join.
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Vec<u64>,
+        mut excluded: Vec<u64>,
+        cliques: &mut Vec<Vec<usize>>,
+    ) {
+        let Some(pivot) = ones(&candidates)
+            .chain(ones(&excluded))
+            .max_by_key(|&pivot| count(&intersect(&candidates, &self.rows[pivot])))
+        else {
+            let mut found = clique.clone();
+            found.sort_unstable();
+            cliques.push(found);
+            return;
+        };
+
+        // Any maximal clique holds the pivot or a node it has no edge to.
+        let choices: Vec<usize> = ones(&candidates)
+            .filter(|&id| !self.contains(pivot, id))
+            .collect();
+        for id in choices {
+            clique.push(id);
+            self.bron_kerbosch(
+                clique,
+                intersect(&candidates, &self.rows[id]),
+                intersect(&excluded, &self.rows[id]),
+                cliques,
+            );
+            clique.pop();
+            candidates[id / 64] &= !(1 << (id % 64));
+            excluded[id / 64] |= 1 << (id % 64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(len: usize, edges: &[(usize, usize)]) -> AdjacencyMatrix {
+        let mut matrix = AdjacencyMatrix::new(len);
+        for &(a, b) in edges {
+            matrix.insert(a, b);
+            matrix.insert(b, a);
+        }
+        matrix
+    }
+
+    #[test]
+    fn lists_every_maximal_clique() {
+        // A square 0-1-2-3 with the diagonal 0-2, and 4 hanging off 3.
+        let matrix = undirected(6, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (3, 4)]);
+        let mut cliques = matrix.maximal_cliques();
+        cliques.sort();
+        assert_eq!(cliques, [vec![0, 1, 2], vec![0, 2, 3], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    fn finds_the_largest_clique() {
+        let mut edges = vec![(0, 1), (1, 2), (70, 3)];
+        for a in [3, 64, 65, 70] {
+            for b in [3, 64, 65, 70] {
+                if a < b {
+                    edges.push((a, b));
+                }
+            }
+        }
+        assert_eq!(undirected(100, &edges).max_clique(), [3, 64, 65, 70]);
+        assert_eq!(AdjacencyMatrix::new(0).max_clique(), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,186 @@
+//! Maximum flow, and the minimum cut that comes with it.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::Graph;
+
+/// The most that can flow from a source to a sink, and a cheapest set of
+/// edges to cut to separate them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxFlow {
+    pub value: u64,
+    /// Every node the source can still reach once the flow is at its
+    /// maximum: one side of a minimum cut.
+    pub source_side: Vec<usize>,
+    /// The edges from the source side to the rest, whose capacities add up
+    /// to `value`.
+    pub cut: Vec<(usize, usize)>,
+}
+
+/// The residual graph: each edge of the graph paired with a reverse arc at
+/// the next index, so arc `i` undoes arc `i ^ 1`.
+struct Residual {
+    to: Vec<usize>,
+    capacity: Vec<u64>,
+    arcs: Vec<Vec<usize>>,
+}
+
+impl Residual {
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let mut levels = vec![None; self.arcs.len()];
+        levels[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(id) = queue.pop_front() {
+            for &arc in &self.arcs[id] {
+                let to = self.to[arc];
+                if self.capacity[arc] > 0 && levels[to].is_none() {
+                    levels[to] = Some(levels[id].unwrap() + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+        levels
+    }
+
+    /// Pushes up to `limit` along arcs that go one level further each step,
+    /// returning how much got through.
+    fn push(
+        &mut self,
+        id: usize,
+        sink: usize,
+        limit: u64,
+        levels: &[Option<usize>],
+        next_arc: &mut [usize],
+    ) -> u64 {
+        if id == sink {
+            return limit;
+        }
+        while next_arc[id] < self.arcs[id].len() {
+            let arc = self.arcs[id][next_arc[id]];
+            let to = self.to[arc];
+            if self.capacity[arc] > 0 && levels[to] == levels[id].map(|level| level + 1) {
+                let pushed = self.push(to, sink, limit.min(self.capacity[arc]), levels, next_arc);
+                if pushed > 0 {
+                    self.capacity[arc] -= pushed;
+                    self.capacity[arc ^ 1] += pushed;
+                    return pushed;
+                }
+            }
+            next_arc[id] += 1;
+        }
+        0
+    }
+}
+
+impl<K: Hash + Eq + Clone> Graph<K, u64> {
+    /// Dinic's algorithm, with each edge's weight as its capacity. An
+    /// undirected edge can carry its capacity either way.
+    pub fn max_flow(&self, source: usize, sink: usize) -> MaxFlow {
+        let mut residual = Residual {
+            to: Vec::new(),
+            capacity: Vec::new(),
+            arcs: vec![Vec::new(); self.len()],
+        };
+        for from in 0..self.len() {
+            for &(to, capacity) in self.edges(from) {
+                residual.arcs[from].push(residual.to.len());
+                residual.to.push(to);
+                residual.capacity.push(capacity);
+                residual.arcs[to].push(residual.to.len());
+                residual.to.push(from);
+                residual.capacity.push(0);
+            }
+        }
+
+        let mut value = 0;
+        loop {
+            let levels = residual.levels(source);
+            if levels[sink].is_none() {
+                let source_side: Vec<usize> =
+                    (0..self.len()).filter(|&id| levels[id].is_some()).collect();
+                let cut = source_side
+                    .iter()
+                    .flat_map(|&from| self.neighbors(from).map(move |to| (from, to)))
+                    .filter(|&(_, to)| levels[to].is_none())
+                    .collect();
+                return MaxFlow {
+                    value,
+                    source_side,
+                    cut,
+                };
+            }
+
+            let mut next_arc = vec![0; self.len()];
+            loop {
+                let pushed = residual.push(source, sink, u64::MAX, &levels, &mut next_arc);
+                if pushed == 0 {
+                    break;
+                }
+                value += pushed;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_bottleneck() {
+        // The classic example from CLRS, with its maximum flow of 23.
+        let mut graph: Graph<&str, u64> = Graph::new();
+        for (from, to, capacity) in [
+            ("s", "v1", 16),
+            ("s", "v2", 13),
+            ("v1", "v3", 12),
+            ("v2", "v1", 4),
+            ("v2", "v4", 14),
+            ("v3", "v2", 9),
+            ("v3", "t", 20),
+            ("v4", "v3", 7),
+            ("v4", "t", 4),
+        ] {
+            let from = graph.node(&from);
+            let to = graph.node(&to);
+            graph.add_edge(from, to, capacity);
+        }
+
+        let flow = graph.max_flow(graph.id(&"s").unwrap(), graph.id(&"t").unwrap());
+        assert_eq!(flow.value, 23);
+        let mut source_side: Vec<&str> =
+            flow.source_side.iter().map(|&id| *graph.key(id)).collect();
+        source_side.sort();
+        assert_eq!(source_side, ["s", "v1", "v2", "v4"]);
+        let cut_capacity: u64 = flow
+            .cut
+            .iter()
+            .map(|&(from, to)| {
+                graph
+                    .edges(from)
+                    .iter()
+                    .find(|&&(id, _)| id == to)
+                    .unwrap()
+                    .1
+            })
+            .sum();
+        assert_eq!(cut_capacity, 23);
+    }
+
+    #[test]
+    fn cuts_undirected_edges_once() {
+        // Two triangles joined by a single edge.
+        let mut graph: Graph<u8, u64> = Graph::new();
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+            let a = graph.node(&a);
+            let b = graph.node(&b);
+            graph.add_undirected_edge(a, b, 1);
+        }
+
+        let flow = graph.max_flow(0, 5);
+        assert_eq!(flow.value, 1);
+        assert_eq!(flow.source_side, [0, 1, 2]);
+        assert_eq!(flow.cut, [(2, 3)]);
+    }
+}
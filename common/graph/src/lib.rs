@@ -0,0 +1,327 @@
+//! Graphs over nodes named by strings, or anything else hashable, with the
+//! names interned into dense ids from 0 so the algorithms can work on plain
+//! vectors.
+//!
+//! Edges are directed and carry a weight `W`, which is `()` for graphs that
+//! don't need one; an undirected edge is a pair of directed ones.
+//!
+//! [`UnionFind`] tracks connectivity on its own, for puzzles that only join
+//! things up and never need the edges again.
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+use std::ops::Add;
+
+use rustc_hash::FxHashMap;
+
+mod clique;
+mod flow;
+mod order;
+mod union_find;
+
+pub use flow::MaxFlow;
+pub use order::Cycle;
+pub use union_find::{RollbackUnionFind, UnionFind};
+
+/// Hands out ids, counting up from 0, to keys in the order they are first
+/// seen.
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    keys: Vec<K>,
+    ids: FxHashMap<K, usize>,
+}
+
+impl<K: Hash + Eq + Clone> Interner<K> {
+    pub fn new() -> Interner<K> {
+        Interner {
+            keys: Vec::new(),
+            ids: FxHashMap::default(),
+        }
+    }
+
+    /// The id of `key`, handing out the next one if it is new.
+    pub fn intern<Q>(&mut self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(&id) = self.ids.get(key) {
+            return id;
+        }
+        let id = self.keys.len();
+        self.keys.push(key.to_owned());
+        self.ids.insert(key.to_owned(), id);
+        id
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.ids.get(key).copied()
+    }
+
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for Interner<K> {
+    fn default() -> Interner<K> {
+        Interner::new()
+    }
+}
+
+/// Adjacency lists over interned nodes.
+#[derive(Debug, Clone)]
+pub struct Graph<K = String, W = ()> {
+    nodes: Interner<K>,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<K: Hash + Eq + Clone, W> Graph<K, W> {
+    pub fn new() -> Graph<K, W> {
+        Graph {
+            nodes: Interner::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// The id of `key`, adding it as a node without edges if it is new.
+    pub fn node<Q>(&mut self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        let id = self.nodes.intern(key);
+        if id == self.edges.len() {
+            self.edges.push(Vec::new());
+        }
+        id
+    }
+
+    /// The id of `key`, if it is a node.
+    pub fn id<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.nodes.get(key)
+    }
+
+    pub fn key(&self, id: usize) -> &K {
+        self.nodes.key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.edges[from].push((to, weight));
+    }
+
+    /// An edge each way between `a` and `b`.
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: W)
+    where
+        W: Clone,
+    {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    /// The edges out of `id`, as the node each leads to and its weight.
+    pub fn edges(&self, id: usize) -> &[(usize, W)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[id].iter().map(|&(to, _)| to)
+    }
+
+    /// The number of edges out of `id`.
+    pub fn degree(&self, id: usize) -> usize {
+        self.edges[id].len()
+    }
+
+    pub fn adjacency_matrix(&self) -> AdjacencyMatrix {
+        let mut matrix = AdjacencyMatrix::new(self.len());
+        for from in 0..self.len() {
+            for to in self.neighbors(from) {
+                matrix.insert(from, to);
+            }
+        }
+        matrix
+    }
+}
+
+impl<K: Hash + Eq + Clone, W: Copy + Add<Output = W>> Graph<K, W> {
+    /// The graph on just the nodes `keep` picks out, with each chain of other
+    /// nodes leading from one kept node to another collapsed into a single
+    /// edge weighing their total, as when a maze is cut down to its
+    /// junctions.
+    ///
+    /// A chain follows the one edge out of each node that doesn't lead
+    /// straight back, so the nodes dropped should have exactly that; chains
+    /// that run into a dead end are left out.
+    pub fn contract(&self, mut keep: impl FnMut(usize) -> bool) -> Graph<K, W> {
+        let kept: Vec<usize> = (0..self.len()).filter(|&id| keep(id)).collect();
+        let mut is_kept = vec![false; self.len()];
+        let mut contracted = Graph::new();
+        for &id in &kept {
+            is_kept[id] = true;
+            contracted.node(self.key(id));
+        }
+
+        for (from, &start) in kept.iter().enumerate() {
+            for &(next, weight) in self.edges(start) {
+                let (mut previous, mut current, mut total) = (start, next, weight);
+                let end = loop {
+                    if is_kept[current] {
+                        break Some(current);
+                    }
+                    let Some(&(next, weight)) =
+                        self.edges(current).iter().find(|&&(to, _)| to != previous)
+                    else {
+                        break None;
+                    };
+                    (previous, current, total) = (current, next, total + weight);
+                };
+                if let Some(end) = end {
+                    let to = contracted.node(self.key(end));
+                    contracted.add_edge(from, to, total);
+                }
+            }
+        }
+        contracted
+    }
+}
+
+impl<K: Hash + Eq + Clone, W> Default for Graph<K, W> {
+    fn default() -> Graph<K, W> {
+        Graph::new()
+    }
+}
+
+/// Which nodes have an edge between them, a row of bits per node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyMatrix {
+    len: usize,
+    rows: Vec<Vec<u64>>,
+}
+
+impl AdjacencyMatrix {
+    /// `len` nodes with no edges between them.
+    pub fn new(len: usize) -> AdjacencyMatrix {
+        AdjacencyMatrix {
+            len,
+            rows: vec![vec![0; len.div_ceil(64)]; len],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, from: usize, to: usize) {
+        self.rows[from][to / 64] |= 1 << (to % 64);
+    }
+
+    pub fn contains(&self, from: usize, to: usize) -> bool {
+        self.rows[from][to / 64] & (1 << (to % 64)) != 0
+    }
+
+    pub fn neighbors(&self, from: usize) -> impl Iterator<Item = usize> + '_ {
+        ones(&self.rows[from])
+    }
+}
+
+/// The positions of the set bits in a row.
+fn ones(bits: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    bits.iter().enumerate().flat_map(|(i, &word)| {
+        let mut word = word;
+        std::iter::from_fn(move || {
+            if word == 0 {
+                return None;
+            }
+            let bit = word.trailing_zeros() as usize;
+            word &= word - 1;
+            Some(i * 64 + bit)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interns_each_name_once() {
+        let mut graph: Graph = Graph::new();
+        let a = graph.node("a");
+        let b = graph.node("b");
+        assert_eq!((a, b, graph.node("a")), (0, 1, 0));
+        graph.add_undirected_edge(a, b, ());
+        assert_eq!(graph.id("b"), Some(b));
+        assert_eq!(graph.id("c"), None);
+        assert_eq!(graph.key(b), "b");
+        assert_eq!(graph.neighbors(b).collect::<Vec<_>>(), vec![a]);
+
+        let matrix = graph.adjacency_matrix();
+        assert!(matrix.contains(a, b) && matrix.contains(b, a));
+        assert!(!matrix.contains(a, a));
+    }
+
+    #[test]
+    fn matrix_rows_span_several_words() {
+        let mut matrix = AdjacencyMatrix::new(200);
+        for to in [0, 63, 64, 199] {
+            matrix.insert(5, to);
+        }
+        assert_eq!(
+            matrix.neighbors(5).collect::<Vec<_>>(),
+            vec![0, 63, 64, 199]
+        );
+        assert!(matrix.contains(5, 64) && !matrix.contains(64, 5));
+    }
+
+    #[test]
+    fn contracts_corridors_into_weighted_edges() {
+        // a - b - c - d, with e hanging off c as a dead end
+        let mut graph: Graph<char, u32> = Graph::new();
+        let ids: Vec<usize> = "abcde".chars().map(|c| graph.node(&c)).collect();
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (2, 4)] {
+            graph.add_undirected_edge(ids[from], ids[to], 1);
+        }
+
+        let contracted = graph.contract(|id| graph.degree(id) != 2);
+        let node = |c: char| contracted.id(&c).unwrap();
+        assert_eq!(contracted.len(), 4);
+        assert_eq!(contracted.edges(node('a')), &[(node('c'), 2)]);
+        assert_eq!(
+            contracted.edges(node('c')),
+            &[(node('a'), 2), (node('d'), 1), (node('e'), 1)]
+        );
+
+        let without_e = graph.contract(|id| graph.key(id) != &'e' && graph.degree(id) != 2);
+        assert_eq!(without_e.len(), 3);
+        assert_eq!(without_e.degree(without_e.id(&'c').unwrap()), 2);
+    }
+}
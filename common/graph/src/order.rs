@@ -0,0 +1,264 @@
+//! Orderings of a directed graph: topological sort, strongly connected
+//! components, and counting the paths through an acyclic graph.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use crate::Graph;
+
+/// Nodes that each have an edge to the next, and the last back to the first,
+/// which is why a graph has no topological order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub nodes: Vec<usize>,
+}
+
+const UNVISITED: usize = usize::MAX;
+
+impl<K: Hash + Eq + Clone, W> Graph<K, W> {
+    /// Kahn's algorithm: every node after all those with edges into it, ties
+    /// going to the lower id, or a cycle that rules any such order out.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Cycle> {
+        let mut in_degree = vec![0; self.len()];
+        for from in 0..self.len() {
+            for to in self.neighbors(from) {
+                in_degree[to] += 1;
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.len())
+            .filter(|&id| in_degree[id] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(id)) = ready.pop() {
+            order.push(id);
+            for to in self.neighbors(id) {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        // Every node left still has an edge in from another node left, so
+        // following those edges backwards must come round in a loop.
+        let mut previous = vec![UNVISITED; self.len()];
+        for from in (0..self.len()).filter(|&id| in_degree[id] > 0) {
+            for to in self.neighbors(from) {
+                if in_degree[to] > 0 {
+                    previous[to] = from;
+                }
+            }
+        }
+        let mut seen = vec![false; self.len()];
+        let mut id = (0..self.len()).find(|&id| in_degree[id] > 0).unwrap();
+        while !seen[id] {
+            seen[id] = true;
+            id = previous[id];
+        }
+        let mut nodes = vec![id];
+        let mut before = previous[id];
+        while before != id {
+            nodes.push(before);
+            before = previous[before];
+        }
+        nodes.reverse();
+        nodes.rotate_right(1);
+        Err(Cycle { nodes })
+    }
+
+    /// Tarjan's algorithm. Each component comes out before any component
+    /// with an edge into it, so the list is a reverse topological order of
+    /// the components.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let mut index = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..self.len() {
+            if index[root] != UNVISITED {
+                continue;
+            }
+
+            // Each entry is a node and the position of the next edge to look
+            // down, standing in for the recursion.
+            let mut work = vec![(root, 0)];
+            while let Some((id, edge)) = work.pop() {
+                if edge == 0 {
+                    index[id] = next_index;
+                    low[id] = next_index;
+                    next_index += 1;
+                    stack.push(id);
+                    on_stack[id] = true;
+                }
+
+                let unvisited = self.edges(id)[edge..].iter().position(|&(to, _)| {
+                    if on_stack[to] {
+                        low[id] = low[id].min(index[to]);
+                    }
+                    index[to] == UNVISITED
+                });
+                if let Some(offset) = unvisited {
+                    work.push((id, edge + offset + 1));
+                    work.push((self.edges(id)[edge + offset].0, 0));
+                    continue;
+                }
+
+                if low[id] == index[id] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == id {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[id]);
+                }
+            }
+        }
+        components
+    }
+
+    /// The number of paths from `from` to `to`, which needs the graph to be
+    /// acyclic wherever `from` can reach; otherwise the cycle found.
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<u64, Cycle> {
+        let mut counts: Vec<Option<u64>> = vec![None; self.len()];
+        let mut on_path = vec![false; self.len()];
+        let mut work = vec![(from, 0)];
+        on_path[from] = true;
+
+        while let Some(&(id, edge)) = work.last() {
+            let next = if id == to {
+                None
+            } else {
+                self.edges(id).get(edge)
+            };
+            match next {
+                Some(&(next, _)) => {
+                    work.last_mut().unwrap().1 += 1;
+                    if on_path[next] {
+                        let start = work.iter().position(|&(id, _)| id == next).unwrap();
+                        let nodes = work[start..].iter().map(|&(id, _)| id).collect();
+                        return Err(Cycle { nodes });
+                    }
+                    if counts[next].is_none() {
+                        on_path[next] = true;
+                        work.push((next, 0));
+                    }
+                }
+                None => {
+                    let count = if id == to {
+                        1
+                    } else {
+                        self.neighbors(id).map(|next| counts[next].unwrap()).sum()
+                    };
+                    counts[id] = Some(count);
+                    on_path[id] = false;
+                    work.pop();
+                }
+            }
+        }
+        Ok(counts[from].unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::new();
+        for (from, to) in edges {
+            let from = graph.node(*from);
+            let to = graph.node(*to);
+            graph.add_edge(from, to, ());
+        }
+        graph
+    }
+
+    fn names(graph: &Graph, ids: &[usize]) -> Vec<String> {
+        ids.iter().map(|&id| graph.key(id).clone()).collect()
+    }
+
+    #[test]
+    fn sorts_after_every_predecessor() {
+        let graph = directed(&[
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("shirt", "jacket"),
+            ("socks", "shoes"),
+        ]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(
+            names(&graph, &order),
+            ["shirt", "tie", "jacket", "socks", "shoes"]
+        );
+    }
+
+    #[test]
+    fn reports_the_cycle_in_the_way() {
+        let graph = directed(&[("a", "b"), ("b", "c"), ("c", "d"), ("d", "b"), ("d", "e")]);
+        let cycle = graph.topological_sort().unwrap_err();
+        let mut cycle = names(&graph, &cycle.nodes);
+        let first = cycle.iter().position(|name| name == "b").unwrap();
+        cycle.rotate_left(first);
+        assert_eq!(cycle, ["b", "c", "d"]);
+    }
+
+    #[test]
+    fn finds_components_sinks_first() {
+        let graph = directed(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "e"),
+        ]);
+        let components: Vec<Vec<String>> = graph
+            .strongly_connected_components()
+            .iter()
+            .map(|component| {
+                let mut component = names(&graph, component);
+                component.sort();
+                component
+            })
+            .collect();
+        assert_eq!(components, [vec!["d", "e"], vec!["a", "b", "c"], vec!["f"]]);
+    }
+
+    #[test]
+    fn counts_paths_through_a_dag() {
+        let graph = directed(&[
+            ("you", "a"),
+            ("you", "b"),
+            ("a", "c"),
+            ("b", "c"),
+            ("c", "out"),
+            ("c", "d"),
+            ("d", "out"),
+        ]);
+        let (you, out) = (graph.id("you").unwrap(), graph.id("out").unwrap());
+        assert_eq!(graph.count_paths(you, out), Ok(4));
+        assert_eq!(graph.count_paths(out, you), Ok(0));
+
+        let looped = directed(&[("a", "b"), ("b", "c"), ("c", "b"), ("c", "d")]);
+        let cycle = looped.count_paths(0, 3).unwrap_err();
+        assert_eq!(names(&looped, &cycle.nodes), ["b", "c"]);
+    }
+}
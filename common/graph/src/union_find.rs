@@ -0,0 +1,236 @@
+//! Disjoint sets over the ids `0..len`, merged a pair at a time, for
+//! connectivity, clustering and Kruskal's algorithm.
+
+/// Union by size with path compression, so any run of operations takes
+/// close to constant time each.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// `len` sets of one element each.
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merges the sets holding `x` and `y`, returning whether they were
+    /// apart.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// The number of elements in the set holding `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// The number of sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// The size of every set, in the order of their representatives.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// The sizes of the `k` largest sets, largest first, or of all of them
+    /// if there are fewer.
+    pub fn largest_sizes(&self, k: usize) -> Vec<usize> {
+        let mut sizes = self.component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+
+    /// The elements of each set, in the order of their smallest elements.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[index[root]].push(x);
+        }
+        groups
+    }
+}
+
+/// Union by size without path compression, so that every union can be
+/// undone: for offline dynamic connectivity, where edges come and go and a
+/// search over time adds them on the way down and takes them off on the way
+/// back up.
+#[derive(Debug, Clone)]
+pub struct RollbackUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+    /// The root attached by each union that merged anything, most recent
+    /// last.
+    history: Vec<usize>,
+}
+
+impl RollbackUnionFind {
+    /// `len` sets of one element each.
+    pub fn new(len: usize) -> RollbackUnionFind {
+        RollbackUnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set holding `x`, in logarithmic time.
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets holding `x` and `y`, returning whether they were
+    /// apart.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.components -= 1;
+        self.history.push(y);
+        true
+    }
+
+    pub fn same(&self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// The number of elements in the set holding `x`.
+    pub fn size(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// The number of sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// A point to [`RollbackUnionFind::rollback`] to.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes every union since `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            let child = self.history.pop().unwrap();
+            let root = self.parent[child];
+            self.size[root] -= self.size[child];
+            self.parent[child] = child;
+            self.components += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_and_measures_sets() {
+        let mut sets = UnionFind::new(7);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 1));
+        assert!(sets.union(4, 5));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.same(0, 2) && !sets.same(0, 4));
+        assert_eq!(sets.size(2), 3);
+        assert_eq!(sets.components(), 4);
+        assert_eq!(sets.component_sizes(), [3, 1, 2, 1]);
+        assert_eq!(sets.largest_sizes(2), [3, 2]);
+        assert_eq!(sets.largest_sizes(9).len(), 4);
+        assert_eq!(sets.groups(), [vec![0, 1, 2], vec![3], vec![4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn rolls_back_to_a_snapshot() {
+        let mut sets = RollbackUnionFind::new(5);
+        sets.union(0, 1);
+        let snapshot = sets.snapshot();
+        sets.union(1, 2);
+        sets.union(3, 4);
+        assert!(!sets.union(0, 2));
+        assert_eq!((sets.size(0), sets.components()), (3, 2));
+
+        sets.rollback(snapshot);
+        assert!(sets.same(0, 1) && !sets.same(1, 2) && !sets.same(3, 4));
+        assert_eq!((sets.size(1), sets.components()), (2, 4));
+
+        sets.rollback(0);
+        assert_eq!(sets.components(), 5);
+        assert_eq!(sets.size(0), 1);
+    }
+}
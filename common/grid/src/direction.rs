@@ -0,0 +1,248 @@
+//! Compass directions on a grid drawn with its first row at the top, so
+//! north is up the screen and towards row 0.
+//!
+//! Deltas come in two conventions: `delta` as `(dx, dy)`, matching [`Pos`],
+//! and `row_col` as `(row, column)` for code that indexes rows first.
+
+use crate::Pos;
+
+/// One of the four orthogonal directions, ordered clockwise from north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north, the same order as [`crate::ORTHOGONAL`].
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The direction written as an arrow, one of `^>v<`.
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|d| d.arrow() == c)
+    }
+
+    /// The direction written as a letter, one of `URDL`.
+    pub fn from_letter(c: char) -> Option<Direction> {
+        let index = "URDL".find(c)?;
+        Some(Direction::from_index(index))
+    }
+
+    /// The direction written as a compass point, one of `NESW`.
+    pub fn from_compass(c: char) -> Option<Direction> {
+        let index = "NESW".find(c)?;
+        Some(Direction::from_index(index))
+    }
+
+    /// The direction in any of the notations above, for input that doesn't
+    /// mix them up with other meanings.
+    pub fn from_char(c: char) -> Option<Direction> {
+        Direction::from_arrow(c)
+            .or_else(|| Direction::from_letter(c))
+            .or_else(|| Direction::from_compass(c))
+    }
+
+    /// The direction as an arrow, one of `^>v<`.
+    pub fn arrow(self) -> char {
+        ['^', '>', 'v', '<'][self.index()]
+    }
+
+    /// 0 to 3, clockwise from north.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The direction with the given [`Direction::index`], taken modulo 4.
+    pub fn from_index(index: usize) -> Direction {
+        Direction::ALL[index % 4]
+    }
+
+    /// A distinct bit per direction, so a set of them fits in a `u8`.
+    pub fn bit(self) -> u8 {
+        1 << self.index()
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::from_index(self.index() + 1)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::from_index(self.index() + 3)
+    }
+
+    pub fn turn_around(self) -> Direction {
+        Direction::from_index(self.index() + 2)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// `(dx, dy)` with y growing down the screen.
+    pub fn delta(self) -> (isize, isize) {
+        crate::ORTHOGONAL[self.index()]
+    }
+
+    /// `(row, column)` with rows growing down the screen.
+    pub fn row_col(self) -> (isize, isize) {
+        let (dx, dy) = self.delta();
+        (dy, dx)
+    }
+
+    /// The neighbour of `pos` in this direction, or `None` past row or
+    /// column 0.
+    pub fn step(self, pos: Pos) -> Option<Pos> {
+        let (dx, dy) = self.delta();
+        pos.offset(dx, dy)
+    }
+}
+
+/// One of the eight directions to a neighbour, ordered clockwise from north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// 0 to 7, clockwise from north.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The direction with the given [`Direction8::index`], taken modulo 8.
+    pub fn from_index(index: usize) -> Direction8 {
+        Direction8::ALL[index % 8]
+    }
+
+    pub fn bit(self) -> u8 {
+        1 << self.index()
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::from_index(self.index() + 1)
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::from_index(self.index() + 7)
+    }
+
+    pub fn turn_around(self) -> Direction8 {
+        Direction8::from_index(self.index() + 4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// `(dx, dy)` with y growing down the screen.
+    pub fn delta(self) -> (isize, isize) {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ][self.index()]
+    }
+
+    /// `(row, column)` with rows growing down the screen.
+    pub fn row_col(self) -> (isize, isize) {
+        let (dx, dy) = self.delta();
+        (dy, dx)
+    }
+
+    /// The neighbour of `pos` in this direction, or `None` past row or
+    /// column 0.
+    pub fn step(self, pos: Pos) -> Option<Pos> {
+        let (dx, dy) = self.delta();
+        pos.offset(dx, dy)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::from_index(direction.index() * 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_come_back_around() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.turn_right().turn_right(), d.turn_around());
+            assert_eq!(d.turn_left().turn_left().turn_left(), d.turn_right());
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+        assert_eq!(Direction8::East.turn_around(), Direction8::West);
+    }
+
+    #[test]
+    fn deltas_follow_the_screen() {
+        assert_eq!(Direction::North.delta(), (0, -1));
+        assert_eq!(Direction::East.row_col(), (0, 1));
+        assert_eq!(Direction::South.step(Pos::new(2, 3)), Some(Pos::new(2, 4)));
+        assert_eq!(Direction::West.step(Pos::new(0, 3)), None);
+        assert_eq!(Direction8::SouthWest.delta(), (-1, 1));
+        for d in Direction::ALL {
+            assert_eq!(Direction8::from(d).delta(), d.delta());
+        }
+    }
+
+    #[test]
+    fn parses_every_notation() {
+        for (d, notations) in Direction::ALL.into_iter().zip(["^UN", ">RE", "vDS", "<LW"]) {
+            assert!(notations
+                .chars()
+                .all(|c| Direction::from_char(c) == Some(d)));
+            assert_eq!(Direction::from_char(d.arrow()), Some(d));
+        }
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::from_letter('L'), Some(Direction::West));
+        assert_eq!(Direction::from_letter('N'), None);
+        assert_eq!(Direction::from_compass('>'), None);
+    }
+
+    #[test]
+    fn bits_are_distinct() {
+        let all = Direction::ALL.iter().fold(0, |set, d| set | d.bit());
+        assert_eq!(all, 0b1111);
+        let all = Direction8::ALL.iter().fold(0, |set, d| set | d.bit());
+        assert_eq!(all, 0xff);
+    }
+}
@@ -0,0 +1,422 @@
+//! A dense, row-major 2D grid for puzzles whose input is a block of characters.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use aoc_parse::ParseError;
+
+pub mod direction;
+
+pub use direction::{Direction, Direction8};
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all eight neighbours, row by row from the top left.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A cell position, `x` being the column and `y` the row counted from the top.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub const fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+
+    /// Moves by `(dx, dy)`, or `None` if that would leave the first quadrant.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Pos> {
+        Some(Pos {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// Panics if `cells` does not fill a whole number of rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells do not fill rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, converting each character with `cell`.
+    ///
+    /// Trailing blank lines are ignored; every other line must be as long as
+    /// the first.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in input.trim_end_matches(['\n', '\r']).lines() {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                if width.is_some_and(|w| cells.len() - row_start == w) {
+                    return Err(ParseError::new(input, &line[i..], "the end of the row"));
+                }
+                let found = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::new(input, found, "a grid cell"))?);
+            }
+
+            let found = cells.len() - row_start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseError::new(
+                        input,
+                        &line[line.len()..],
+                        format!("a row of {} cells", expected),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Grid::from_vec(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let index = self.index_of(pos);
+        self.contains(pos).then(|| &mut self.cells[index])
+    }
+
+    /// The row-major index of `pos`, for side tables with one entry per cell.
+    pub fn index_of(&self, pos: Pos) -> usize {
+        pos.y * self.width + pos.x
+    }
+
+    /// The inverse of [`Grid::index_of`].
+    pub fn pos_of(&self, index: usize) -> Pos {
+        Pos::new(index % self.width, index / self.width)
+    }
+
+    /// The cell `(dx, dy)` away from `pos`, if it is on the grid.
+    pub fn step(&self, pos: Pos, dx: isize, dy: isize) -> Option<Pos> {
+        pos.offset(dx, dy).filter(|&next| self.contains(next))
+    }
+
+    /// The neighbour of `pos` in `direction`, if it is on the grid.
+    pub fn neighbor(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        let (dx, dy) = direction.delta();
+        self.step(pos, dx, dy)
+    }
+
+    /// Every cell from `pos` towards `(dx, dy)` up to the edge, not
+    /// including `pos` itself.
+    pub fn ray(&self, pos: Pos, dx: isize, dy: isize) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        let step = move |pos: Pos| {
+            pos.offset(dx, dy)
+                .filter(|next| next.x < width && next.y < height)
+        };
+        std::iter::successors(step(pos), move |&pos| step(pos))
+    }
+
+    /// The orthogonal neighbours of `pos` that are on the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.step(pos, dx, dy))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are on the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.step(pos, dx, dy))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Pos::new(i % width, i / width))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// The cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} of {}", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The first position holding `value` in row-major order.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The first position whose cell matches `predicate` in row-major order.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.pos_of(i))
+    }
+
+    /// Every position holding `value` in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| Pos::new(y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let last_row = self.height.saturating_sub(1);
+        self.remap(self.height, self.width, |x, y| Pos::new(y, last_row - x))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let last_column = self.width.saturating_sub(1);
+        self.remap(self.height, self.width, |x, y| Pos::new(last_column - y, x))
+    }
+
+    /// Builds a `width` by `height` grid whose cell `(x, y)` is the cell of
+    /// `self` at `source(x, y)`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Pos) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: From<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| Some(T::from(c)))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{} is outside the grid", pos);
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{} is outside the grid", pos);
+        let index = self.index_of(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "abc\ndef\n";
+
+    fn sample() -> Grid<char> {
+        SAMPLE.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_rows_of_characters() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 'f');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.to_string(), SAMPLE);
+    }
+
+    #[test]
+    fn parse_reports_ragged_lines_and_bad_cells() {
+        let short = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((short.line, short.column), (2, 3));
+        assert_eq!(short.expected, "a row of 3 cells");
+
+        let long = "abc\ndefg\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((long.line, long.column, long.text.as_str()), (2, 4, "g"));
+
+        let bad = Grid::parse_with("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((bad.line, bad.column, bad.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = sample();
+        assert_eq!(grid.get(Pos::new(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, 2)), None);
+        assert_eq!(grid.step(Pos::new(0, 0), -1, 0), None);
+        assert_eq!(grid.step(Pos::new(0, 0), 1, 1), Some(Pos::new(1, 1)));
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = sample();
+        let corner: Vec<Pos> = grid.neighbors4(Pos::new(0, 0)).collect();
+        assert_eq!(corner, [Pos::new(1, 0), Pos::new(0, 1)]);
+        assert_eq!(grid.neighbors8(Pos::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8(Pos::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rays_stop_at_the_edge() {
+        let grid = sample();
+        let ray: Vec<Pos> = grid.ray(Pos::new(0, 0), 1, 0).collect();
+        assert_eq!(ray, [Pos::new(1, 0), Pos::new(2, 0)]);
+        assert_eq!(grid.ray(Pos::new(0, 0), 0, -1).count(), 0);
+    }
+
+    #[test]
+    fn columns_and_transpose() {
+        let grid = sample();
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = sample();
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[test]
+    fn finds_values() {
+        let grid: Grid<char> = "#.#\n.#.\n".parse().unwrap();
+        assert_eq!(grid.find(&'.'), Some(Pos::new(1, 0)));
+        assert_eq!(grid.find(&'x'), None);
+        let hashes: Vec<Pos> = grid.find_all(&'#').collect();
+        assert_eq!(hashes, [Pos::new(0, 0), Pos::new(2, 0), Pos::new(1, 1)]);
+    }
+}
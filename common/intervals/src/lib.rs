@@ -0,0 +1,427 @@
+//! Integer intervals, sets of them, and maps that shift pieces of the
+//! number line.
+//!
+//! Intervals are stored half-open, `[start, end)`. Puzzles that describe
+//! inclusive ranges such as `3-7` build them with [`Interval::inclusive`]
+//! (or from a `RangeInclusive`) and read the upper bound back with
+//! [`Interval::last`].
+
+use std::fmt;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+
+/// Interval endpoints: the primitive integers, with `Default` as zero.
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Default {
+    const ONE: Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The values `start..end`. Any interval with `end <= start` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The values `first..=last`.
+    pub fn inclusive(first: T, last: T) -> Self {
+        Self::new(first, last + T::ONE)
+    }
+
+    /// The `len` values starting at `start`.
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// The largest value in the interval, which must not be empty.
+    pub fn last(&self) -> T {
+        debug_assert!(!self.is_empty(), "empty interval has no last value");
+        self.end - T::ONE
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is also in `self`.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+
+    /// The values in both intervals, or `None` if there are none.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Splits into the values below `at` and the values from `at` up. Either
+    /// half may be empty.
+    pub fn split_at(&self, at: T) -> (Interval<T>, Interval<T>) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Interval::new(self.start, at), Interval::new(at, self.end))
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (first, last) = range.into_inner();
+        Interval::inclusive(first, last)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers kept as sorted, disjoint, non-touching intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Adds every value of `interval`, merging it with any interval it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if lo < hi {
+            merged.start = merged.start.min(self.intervals[lo].start);
+            merged.end = merged.end.max(self.intervals[hi - 1].end);
+        }
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::default(), |total, i| total + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.last())
+    }
+
+    /// The intervals making up the set, in increasing order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        while let (Some(i), Some(j)) = (x, y) {
+            intervals.extend(i.intersection(j));
+            if i.end < j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+        // Pieces of disjoint, non-touching intervals can't touch either
+        IntervalSet { intervals }
+    }
+
+    /// The values in `self` but not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let mut others = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            while let Some(cut) = others.peek() {
+                if cut.end <= rest.start {
+                    others.next();
+                    continue;
+                }
+                if cut.start >= rest.end {
+                    break;
+                }
+                let (below, _) = rest.split_at(cut.start);
+                let (_, above) = rest.split_at(cut.end);
+                if !below.is_empty() {
+                    intervals.push(below);
+                }
+                rest = above;
+                if rest.is_empty() {
+                    break;
+                }
+                others.next();
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort_unstable();
+
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Integer> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+/// A function that moves each of its source intervals so it starts at a
+/// new place, and leaves every other value where it is.
+///
+/// Where sources overlap, the piece added first wins.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PiecewiseMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Integer> PiecewiseMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    /// Sends `source.start` to `destination`, and the rest of `source`
+    /// along with it.
+    pub fn push(&mut self, source: Interval<T>, destination: T) {
+        self.pieces.push((source, destination));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |&(source, destination)| {
+                value - source.start + destination
+            })
+    }
+
+    /// The image of every value in `set`.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = IntervalSet::new();
+        for &(source, destination) in &self.pieces {
+            let cut = IntervalSet::from(source);
+            for moved in unmapped.intersection(&cut).iter() {
+                let start = moved.start - source.start + destination;
+                mapped.insert(Interval::with_len(start, moved.len()));
+            }
+            unmapped = unmapped.difference(&cut);
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T: Integer> FromIterator<(Interval<T>, T)> for PiecewiseMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        Self {
+            pieces: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().map(|&(a, b)| Interval::new(a, b)).collect()
+    }
+
+    #[test]
+    fn interval_queries() {
+        let i = Interval::inclusive(3, 7);
+        assert_eq!(i, Interval::from(3..8));
+        assert_eq!(i.len(), 5);
+        assert_eq!(i.last(), 7);
+        assert!(i.contains(7) && !i.contains(8));
+        assert!(i.contains_interval(&(4..=7).into()));
+        assert!(!i.contains_interval(&(4..=8).into()));
+        assert!(i.overlaps(&(7..=9).into()));
+        assert!(!i.overlaps(&(8..=9).into()));
+        assert_eq!(i.intersection(&(5..20).into()), Some(Interval::new(5, 8)));
+        assert_eq!(i.intersection(&(8..20).into()), None);
+        assert_eq!(Interval::new(5, 2).len(), 0);
+    }
+
+    #[test]
+    fn split_at_clamps() {
+        let i = Interval::new(3u32, 8);
+        assert_eq!(i.split_at(5), (Interval::new(3, 5), Interval::new(5, 8)));
+        assert_eq!(i.split_at(0), (Interval::new(3, 3), i));
+        assert_eq!(i.split_at(10), (i, Interval::new(8, 8)));
+    }
+
+    #[test]
+    fn collect_merges_overlapping_and_touching() {
+        let s = set(&[
+            (10, 14),
+            (3, 6),
+            (5, 8),
+            (8, 9),
+            (16, 21),
+            (12, 19),
+            (30, 30),
+        ]);
+        assert_eq!(s.intervals(), &[Interval::new(3, 9), Interval::new(10, 21)]);
+        assert_eq!(s.len(), 17);
+        assert_eq!(s.min(), Some(3));
+        assert_eq!(s.max(), Some(20));
+    }
+
+    #[test]
+    fn insert_matches_collect() {
+        let ranges = [(10, 14), (3, 6), (5, 8), (8, 9), (16, 21), (12, 19), (0, 1)];
+        let mut s = IntervalSet::new();
+        for &(a, b) in &ranges {
+            s.insert(Interval::new(a, b));
+        }
+        assert_eq!(s, set(&ranges));
+    }
+
+    #[test]
+    fn point_queries() {
+        let s = set(&[(3, 6), (10, 12)]);
+        let members: Vec<_> = (0..14).filter(|&x| s.contains(x)).collect();
+        assert_eq!(members, [3, 4, 5, 10, 11]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 29), (40, 50)]);
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 50)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 29)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28), (29, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (40, 50)]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn map_points_and_sets() {
+        // seed-to-soil from 2023 day 5: 98..100 -> 50.., 50..98 -> 52..
+        let map: PiecewiseMap<u64> = [
+            (Interval::with_len(98, 2), 50),
+            (Interval::with_len(50, 48), 52),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map.map(79), 81);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(100), 100);
+        assert_eq!(map.map(10), 10);
+
+        let seeds = IntervalSet::from(Interval::new(40, 105));
+        let expected: IntervalSet<u64> = [
+            Interval::new(40, 50),
+            Interval::new(52, 100),
+            Interval::new(50, 52),
+            Interval::new(100, 105),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map.map_set(&seeds), expected);
+        assert_eq!(map.map_set(&seeds).len(), seeds.len());
+    }
+
+    #[test]
+    fn first_piece_wins_where_sources_overlap() {
+        let map: PiecewiseMap<i64> = [(Interval::new(0, 10), 100), (Interval::new(5, 15), 200)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.map(7), 107);
+        assert_eq!(map.map(12), 207);
+        let image = map.map_set(&Interval::new(0, 15).into());
+        assert_eq!(image, set(&[(100, 110), (205, 210)]));
+    }
+}
@@ -0,0 +1,6 @@
+//! Arithmetic the puzzles keep needing beyond what the standard library
+//! offers.
+
+pub mod linalg;
+pub mod number;
+pub mod rational;
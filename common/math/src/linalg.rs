@@ -0,0 +1,393 @@
+//! Systems of linear equations solved exactly, over the rationals and over
+//! GF(2), the integers mod 2 where adding is XOR.
+//!
+//! A system is given as the rows of its matrix, an equation each with a
+//! column per unknown. Solving gives the solution with every free unknown
+//! at zero; adding any combination of the null space to it gives all the
+//! others.
+
+use std::ops::BitXorAssign;
+
+use crate::rational::Rational;
+
+/// Gauss–Jordan elimination in place, leaving `rows` in reduced row echelon
+/// form. Returns the column of each nonzero row's leading 1, those rows
+/// coming first.
+pub fn row_reduce(rows: &mut [Vec<Rational>]) -> Vec<usize> {
+    let columns = rows.first().map_or(0, Vec::len);
+    let mut pivots = Vec::new();
+    for column in 0..columns {
+        let next = pivots.len();
+        let Some(pivot) = (next..rows.len()).find(|&r| !rows[r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(next, pivot);
+
+        let scale = rows[next][column].recip();
+        let pivot_row: Vec<Rational> = rows[next].iter().map(|value| value * &scale).collect();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == next || row[column].is_zero() {
+                continue;
+            }
+            let factor = row[column].clone();
+            for (value, pivot_value) in row.iter_mut().zip(&pivot_row).skip(column) {
+                *value = &*value - &factor * pivot_value;
+            }
+        }
+        rows[next] = pivot_row;
+        pivots.push(column);
+    }
+    pivots
+}
+
+/// A solution to `rows · x = target`, or `None` if the equations contradict
+/// each other.
+///
+/// Panics unless there is one target value per row.
+pub fn solve(rows: &[Vec<Rational>], target: &[Rational]) -> Option<Vec<Rational>> {
+    assert_eq!(rows.len(), target.len(), "one target value per row");
+    let columns = rows.first().map_or(0, Vec::len);
+    let mut augmented: Vec<Vec<Rational>> = rows
+        .iter()
+        .zip(target)
+        .map(|(row, value)| row.iter().chain([value]).cloned().collect())
+        .collect();
+    let pivots = row_reduce(&mut augmented);
+    if pivots.last() == Some(&columns) {
+        return None;
+    }
+
+    let mut solution = vec![Rational::zero(); columns];
+    for (row, &column) in augmented.iter_mut().zip(&pivots) {
+        solution[column] = row.swap_remove(columns);
+    }
+    Some(solution)
+}
+
+/// A basis for the vectors `x` with `rows · x = 0`, one for each free
+/// unknown.
+pub fn null_space(rows: &[Vec<Rational>]) -> Vec<Vec<Rational>> {
+    let columns = rows.first().map_or(0, Vec::len);
+    let mut reduced = rows.to_vec();
+    let pivots = row_reduce(&mut reduced);
+
+    let mut is_pivot = vec![false; columns];
+    for &column in &pivots {
+        is_pivot[column] = true;
+    }
+    (0..columns)
+        .filter(|&free| !is_pivot[free])
+        .map(|free| {
+            let mut basis = vec![Rational::zero(); columns];
+            basis[free] = Rational::one();
+            for (row, &column) in reduced.iter().zip(&pivots) {
+                basis[column] = -&row[free];
+            }
+            basis
+        })
+        .collect()
+}
+
+/// A row of bits as long as it needs to be, which doubles as a vector over
+/// GF(2).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitVec {
+    /// `len` zeros.
+    pub fn new(len: usize) -> BitVec {
+        BitVec {
+            len,
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// `len` bits with just those at `ones` set.
+    pub fn from_ones(len: usize, ones: impl IntoIterator<Item = usize>) -> BitVec {
+        let mut bits = BitVec::new(len);
+        for i in ones {
+            bits.set(i, true);
+        }
+        bits
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {} is past the end of {}", i, self.len);
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {} is past the end of {}", i, self.len);
+        let mask = 1 << (i % 64);
+        if value {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The positions of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|&i| self.get(i))
+    }
+
+    /// The dot product over GF(2): whether an odd number of positions are
+    /// set in both.
+    pub fn dot(&self, other: &BitVec) -> bool {
+        let common: u32 = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones())
+            .sum();
+        common % 2 == 1
+    }
+}
+
+impl BitXorAssign<&BitVec> for BitVec {
+    fn bitxor_assign(&mut self, other: &BitVec) {
+        assert_eq!(
+            self.len, other.len,
+            "can't add bit vectors of different lengths"
+        );
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word ^= other;
+        }
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(bits: I) -> BitVec {
+        let bits: Vec<bool> = bits.into_iter().collect();
+        BitVec::from_ones(bits.len(), (0..bits.len()).filter(|&i| bits[i]))
+    }
+}
+
+/// A system over GF(2) eliminated once up front, so it can be solved
+/// against any number of targets for the price of a few dot products each.
+#[derive(Debug, Clone)]
+pub struct Gf2System {
+    columns: usize,
+    /// The pivot column of each of the first `rank` reduced rows.
+    pivots: Vec<usize>,
+    /// Which of the original equations add up to each reduced row.
+    transform: Vec<BitVec>,
+    null_space: Vec<BitVec>,
+}
+
+impl Gf2System {
+    /// Eliminates `rows`, each `columns` bits long.
+    pub fn new(rows: &[BitVec], columns: usize) -> Gf2System {
+        let mut rows = rows.to_vec();
+        let mut transform: Vec<BitVec> = (0..rows.len())
+            .map(|i| BitVec::from_ones(rows.len(), [i]))
+            .collect();
+        let mut pivots = Vec::new();
+
+        for column in 0..columns {
+            let next = pivots.len();
+            let Some(pivot) = (next..rows.len()).find(|&r| rows[r].get(column)) else {
+                continue;
+            };
+            rows.swap(next, pivot);
+            transform.swap(next, pivot);
+
+            let (pivot_row, pivot_transform) = (rows[next].clone(), transform[next].clone());
+            for r in (0..rows.len()).filter(|&r| r != next) {
+                if rows[r].get(column) {
+                    rows[r] ^= &pivot_row;
+                    transform[r] ^= &pivot_transform;
+                }
+            }
+            pivots.push(column);
+        }
+
+        let mut is_pivot = vec![false; columns];
+        for &column in &pivots {
+            is_pivot[column] = true;
+        }
+        let null_space = (0..columns)
+            .filter(|&free| !is_pivot[free])
+            .map(|free| {
+                let mut basis = BitVec::from_ones(columns, [free]);
+                for (row, &column) in rows.iter().zip(&pivots) {
+                    basis.set(column, row.get(free));
+                }
+                basis
+            })
+            .collect();
+
+        Gf2System {
+            columns,
+            pivots,
+            transform,
+            null_space,
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    pub fn null_space(&self) -> &[BitVec] {
+        &self.null_space
+    }
+
+    /// A solution to `rows · x = target`, or `None` if the equations
+    /// contradict each other.
+    pub fn solve(&self, target: &BitVec) -> Option<BitVec> {
+        let reduced: Vec<bool> = self.transform.iter().map(|row| row.dot(target)).collect();
+        if reduced[self.rank()..].iter().any(|&bit| bit) {
+            return None;
+        }
+        let mut solution = BitVec::new(self.columns);
+        for (&bit, &column) in reduced.iter().zip(&self.pivots) {
+            solution.set(column, bit);
+        }
+        Some(solution)
+    }
+
+    /// Every solution to `rows · x = target`, two to the power of the size
+    /// of the null space of them, or `None` if there are none.
+    ///
+    /// Panics if the null space has 64 or more vectors, since that many
+    /// solutions could never all be listed.
+    pub fn solutions(&self, target: &BitVec) -> Option<impl Iterator<Item = BitVec> + '_> {
+        let mut solution = self.solve(target)?;
+        let count = 1u64
+            .checked_shl(self.null_space.len() as u32)
+            .expect("at most 2^63 solutions to list");
+        // Counting in Gray code changes one basis vector at a time.
+        Some((0..count).map(move |i| {
+            if i > 0 {
+                solution ^= &self.null_space[i.trailing_zeros() as usize];
+            }
+            solution.clone()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rationals(rows: &[&[i64]]) -> Vec<Vec<Rational>> {
+        rows.iter()
+            .map(|row| row.iter().map(|&value| Rational::from(value)).collect())
+            .collect()
+    }
+
+    fn times(rows: &[Vec<Rational>], x: &[Rational]) -> Vec<Rational> {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .zip(x)
+                    .fold(Rational::zero(), |sum, (a, b)| sum + a * b)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn solves_rational_systems_exactly() {
+        let rows = rationals(&[&[2, 1, -1], &[-3, -1, 2], &[-2, 1, 2]]);
+        let target = rationals(&[&[8, -11, -3]]).remove(0);
+        let x = solve(&rows, &target).unwrap();
+        assert_eq!(x, rationals(&[&[2, 3, -1]])[0]);
+        assert!(null_space(&rows).is_empty());
+
+        let halves = rationals(&[&[2, 0], &[0, 4]]);
+        let x = solve(&halves, &rationals(&[&[1, 1]])[0]).unwrap();
+        assert_eq!(x, [Rational::new(1, 2), Rational::new(1, 4)]);
+
+        let contradiction = rationals(&[&[1, 1], &[2, 2]]);
+        assert_eq!(solve(&contradiction, &rationals(&[&[1, 3]])[0]), None);
+    }
+
+    #[test]
+    fn spans_the_rational_null_space() {
+        let rows = rationals(&[&[1, 2, 3, 4], &[2, 4, 7, 9]]);
+        let mut reduced = rows.clone();
+        assert_eq!(row_reduce(&mut reduced), [0, 2]);
+
+        let basis = null_space(&rows);
+        assert_eq!(basis.len(), 2);
+        for vector in &basis {
+            assert!(times(&rows, vector).iter().all(|value| value.is_zero()));
+        }
+
+        let target = rationals(&[&[1, 3]]).remove(0);
+        let x = solve(&rows, &target).unwrap();
+        assert_eq!(times(&rows, &x), target);
+    }
+
+    #[test]
+    fn handles_bit_vectors_past_one_word() {
+        let mut a = BitVec::from_ones(130, [0, 64, 129]);
+        assert!(a.get(64) && !a.get(65));
+        assert_eq!(a.ones().collect::<Vec<_>>(), [0, 64, 129]);
+        a ^= &BitVec::from_ones(130, [64, 100]);
+        assert_eq!(a.ones().collect::<Vec<_>>(), [0, 100, 129]);
+        assert_eq!(a.count_ones(), 3);
+        assert!(a.dot(&BitVec::from_ones(130, [100])));
+        assert!(!a.dot(&BitVec::from_ones(130, [0, 129])));
+        assert_eq!(
+            [true, false, true].into_iter().collect::<BitVec>(),
+            BitVec::from_ones(3, [0, 2])
+        );
+    }
+
+    #[test]
+    fn solves_gf2_systems_wider_than_a_word() {
+        // x0 + x1 = 1, x1 + x2 = 0, and x99 alone = 1, over 100 unknowns.
+        let rows = [
+            BitVec::from_ones(100, [0, 1]),
+            BitVec::from_ones(100, [1, 2]),
+            BitVec::from_ones(100, [99]),
+        ];
+        let system = Gf2System::new(&rows, 100);
+        assert_eq!(system.rank(), 3);
+        assert_eq!(system.null_space().len(), 97);
+
+        let target: BitVec = [true, false, true].into_iter().collect();
+        let x = system.solve(&target).unwrap();
+        let satisfied: BitVec = rows.iter().map(|row| row.dot(&x)).collect();
+        assert_eq!(satisfied, target);
+        for basis in system.null_space() {
+            assert!(rows.iter().all(|row| !row.dot(basis)));
+        }
+    }
+
+    #[test]
+    fn lists_every_gf2_solution() {
+        // The same equation twice, so the target must agree with itself.
+        let rows = [BitVec::from_ones(3, [0, 1]), BitVec::from_ones(3, [0, 1])];
+        let system = Gf2System::new(&rows, 3);
+        assert!(system.solve(&BitVec::from_ones(2, [0])).is_none());
+
+        let mut solutions: Vec<Vec<usize>> = system
+            .solutions(&BitVec::from_ones(2, [0, 1]))
+            .unwrap()
+            .map(|x| x.ones().collect())
+            .collect();
+        solutions.sort();
+        assert_eq!(solutions, [vec![0], vec![0, 2], vec![1], vec![1, 2]]);
+    }
+}
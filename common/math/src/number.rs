@@ -0,0 +1,251 @@
+//! Number theory: greatest common divisors, modular arithmetic and the
+//! Chinese remainder theorem.
+//!
+//! The modular functions work on `i64` and multiply in `i128`, so any
+//! modulus that fits in an `i64` is safe from overflow.
+
+use std::ops::{Div, Mul, Rem, Sub};
+
+/// The primitive integers, so [`gcd`] and [`lcm`] work on whatever type a
+/// puzzle counts in.
+pub trait Integer:
+    Copy + Ord + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+
+    /// The absolute value, which for unsigned types is the value itself.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    (signed: $($s:ty),*; unsigned: $($u:ty),*) => {
+        $(impl Integer for $s {
+            const ZERO: Self = 0;
+
+            fn abs(self) -> Self {
+                <$s>::abs(self)
+            }
+        })*
+        $(impl Integer for $u {
+            const ZERO: Self = 0;
+
+            fn abs(self) -> Self {
+                self
+            }
+        })*
+    };
+}
+
+impl_integer!(
+    signed: i8, i16, i32, i64, i128, isize;
+    unsigned: u8, u16, u32, u64, u128, usize
+);
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// The least common multiple, never negative, or 0 when either is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        T::ZERO
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, or `None` when `a` and `m`
+/// share a factor. `m` must be positive.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base` to the power `exp`, modulo `m`, in `0..m`. `m` must be positive.
+pub fn mod_pow(base: i64, exp: u64, m: i64) -> i64 {
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut exp = exp;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// The numbers `start + k * modulus` for `k >= 0`: something that first
+/// happens at `start` and then again every `modulus` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Congruence {
+    /// The smallest number in the congruence, which may be past the modulus.
+    pub start: i64,
+    pub modulus: i64,
+}
+
+impl Congruence {
+    /// Every number, the identity for [`Congruence::and`].
+    pub const ANY: Congruence = Congruence {
+        start: i64::MIN,
+        modulus: 1,
+    };
+
+    /// The numbers `start`, `start + modulus`, and so on. `modulus` must be
+    /// positive.
+    pub fn new(start: i64, modulus: i64) -> Congruence {
+        assert!(modulus > 0, "modulus must be positive, got {}", modulus);
+        Congruence { start, modulus }
+    }
+
+    /// The remainder every number in the congruence leaves, in `0..modulus`.
+    pub fn residue(&self) -> i64 {
+        self.start.rem_euclid(self.modulus)
+    }
+
+    /// The numbers in both, starting once both have, or `None` when there
+    /// aren't any. The moduli needn't be coprime.
+    ///
+    /// Panics if the combined modulus doesn't fit in an `i64`.
+    pub fn and(self, other: Congruence) -> Option<Congruence> {
+        let (r1, m1) = (self.residue() as i128, self.modulus as i128);
+        let (r2, m2) = (other.residue() as i128, other.modulus as i128);
+
+        // x = r1 + m1 * k, so m1 * k ≡ r2 - r1 (mod m2), which needs
+        // gcd(m1, m2) to divide the difference.
+        let g = gcd(m1, m2);
+        let diff = r2 - r1;
+        if diff % g != 0 {
+            return None;
+        }
+
+        let m2_g = m2 / g;
+        let inverse = mod_inverse((m1 / g % m2_g) as i64, m2_g as i64)
+            .expect("m1 / g and m2 / g are coprime") as i128;
+        let k = (diff / g).rem_euclid(m2_g) * inverse % m2_g;
+        let modulus = m1 * m2_g;
+        let modulus = i64::try_from(modulus).expect("the combined modulus overflows i64");
+        let combined = Congruence::new((r1 + m1 * k) as i64, modulus);
+        Some(Congruence::new(
+            combined.first_from(self.start.max(other.start)),
+            modulus,
+        ))
+    }
+
+    /// The smallest number in the congruence that is at least `min`.
+    pub fn first_from(&self, min: i64) -> i64 {
+        let min = min.max(self.start) as i128;
+        let (start, modulus) = (self.start as i128, self.modulus as i128);
+        (min + (start - min).rem_euclid(modulus)) as i64
+    }
+}
+
+/// The generalized Chinese remainder theorem: the numbers in every one of
+/// `congruences`, or `None` when no number is. The moduli needn't be coprime.
+///
+/// Panics if the combined modulus doesn't fit in an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::ANY, Congruence::and)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm_ignore_signs() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0u32, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+    }
+
+    #[test]
+    fn extended_gcd_finds_bezout_coefficients() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 5), (0, 9), (9, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn inverses_exist_only_for_coprime_numbers() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn powers_do_not_overflow() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        let p = 1_000_000_007;
+        assert_eq!(mod_pow(i64::MAX, p as u64 - 1, p), 1);
+    }
+
+    #[test]
+    fn crt_combines_coprime_moduli() {
+        let x = crt([
+            Congruence::new(2, 3),
+            Congruence::new(3, 5),
+            Congruence::new(2, 7),
+        ]);
+        assert_eq!(x, Some(Congruence::new(23, 105)));
+    }
+
+    #[test]
+    fn crt_handles_shared_factors() {
+        let x = crt([Congruence::new(3, 4), Congruence::new(5, 6)]);
+        assert_eq!(x, Some(Congruence::new(11, 12)));
+        assert_eq!(crt([Congruence::new(1, 4), Congruence::new(2, 6)]), None);
+        assert_eq!(crt([]), Some(Congruence::ANY));
+    }
+
+    #[test]
+    fn offsets_past_the_modulus_keep_their_lower_bound() {
+        // Repeats every 4 steps from step 9, and every 6 from step 3.
+        let x = crt([Congruence::new(9, 4), Congruence::new(3, 6)]).unwrap();
+        assert_eq!(x, Congruence::new(9, 12));
+        assert_eq!(x.residue(), 9);
+        assert_eq!(x.first_from(10), 21);
+        assert_eq!(x.first_from(-20), 9);
+
+        // Both hit 1 mod 4, but the second only from step 13 on.
+        let late = Congruence::new(1, 2).and(Congruence::new(13, 4)).unwrap();
+        assert_eq!(late, Congruence::new(13, 4));
+        assert_eq!(Congruence::ANY.and(late), Some(late));
+    }
+}
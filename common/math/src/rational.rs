@@ -0,0 +1,254 @@
+//! Exact fractions, for arithmetic that has to come out to whole numbers
+//! without trusting floating point to land on them.
+//!
+//! The numerator and denominator are big integers, so elimination over a
+//! puzzle's 15 digit coordinates can't overflow however large the
+//! intermediate fractions get.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// A fraction kept in lowest terms with a positive denominator, so equal
+/// values are equal structurally.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    /// `numerator / denominator`, which must not divide by zero.
+    pub fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>) -> Rational {
+        let (numerator, denominator) = (numerator.into(), denominator.into());
+        assert!(
+            !denominator.is_zero(),
+            "a rational can't have a zero denominator"
+        );
+        let mut divisor = numerator.gcd(&denominator);
+        if denominator.is_negative() {
+            divisor = -divisor;
+        }
+        Rational {
+            numerator: numerator / &divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn integer(value: impl Into<BigInt>) -> Rational {
+        Rational {
+            numerator: value.into(),
+            denominator: BigInt::one(),
+        }
+    }
+
+    pub fn zero() -> Rational {
+        Rational::integer(0)
+    }
+
+    pub fn one() -> Rational {
+        Rational::integer(1)
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    /// Always positive.
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator.is_one()
+    }
+
+    /// The value as an `i64`, if it is a whole number that fits.
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.is_integer() {
+            self.numerator.to_i64()
+        } else {
+            None
+        }
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// One over the value, which must not be zero.
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.denominator.clone(), self.numerator.clone())
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Rational {
+        Rational::zero()
+    }
+}
+
+macro_rules! impl_from {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Rational {
+            fn from(value: $int) -> Rational {
+                Rational::integer(value)
+            }
+        })*
+    };
+}
+
+impl_from!(i32, i64, i128, u32, u64, usize, BigInt);
+
+fn sum(a: &Rational, b: &Rational) -> Rational {
+    Rational::new(
+        &a.numerator * &b.denominator + &b.numerator * &a.denominator,
+        &a.denominator * &b.denominator,
+    )
+}
+
+fn difference(a: &Rational, b: &Rational) -> Rational {
+    Rational::new(
+        &a.numerator * &b.denominator - &b.numerator * &a.denominator,
+        &a.denominator * &b.denominator,
+    )
+}
+
+fn product(a: &Rational, b: &Rational) -> Rational {
+    Rational::new(&a.numerator * &b.numerator, &a.denominator * &b.denominator)
+}
+
+fn quotient(a: &Rational, b: &Rational) -> Rational {
+    Rational::new(&a.numerator * &b.denominator, &a.denominator * &b.numerator)
+}
+
+/// Each operator for every mix of owned and borrowed operands, so
+/// elimination can work through rows without cloning every entry.
+macro_rules! impl_op {
+    ($op:ident, $method:ident, $function:ident) => {
+        impl $op<&Rational> for &Rational {
+            type Output = Rational;
+
+            fn $method(self, other: &Rational) -> Rational {
+                $function(self, other)
+            }
+        }
+
+        impl $op<Rational> for &Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                $function(self, &other)
+            }
+        }
+
+        impl $op<&Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: &Rational) -> Rational {
+                $function(&self, other)
+            }
+        }
+
+        impl $op<Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                $function(&self, &other)
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, sum);
+impl_op!(Sub, sub, difference);
+impl_op!(Mul, mul, product);
+impl_op!(Div, div, quotient);
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -self.clone()
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_lowest_terms() {
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(0, -7), Rational::zero());
+        assert_eq!(Rational::new(-3, 2).denominator(), &BigInt::from(2));
+        assert_eq!(Rational::new(10, 5).to_i64(), Some(2));
+        assert_eq!(Rational::new(1, 3).to_i64(), None);
+        assert_eq!(Rational::new(-7, 3).to_string(), "-7/3");
+    }
+
+    #[test]
+    fn does_exact_arithmetic() {
+        let third = Rational::new(1, 3);
+        let sixth = Rational::new(1, 6);
+        assert_eq!(&third + &sixth, Rational::new(1, 2));
+        assert_eq!(&third - &sixth, sixth);
+        assert_eq!(&third * &sixth, Rational::new(1, 18));
+        assert_eq!(&third / &sixth, Rational::integer(2));
+        assert_eq!(-&third, Rational::new(-1, 3));
+        assert!(sixth < third && -third < Rational::zero());
+    }
+
+    #[test]
+    fn grows_past_any_machine_integer() {
+        let big = Rational::from(i128::MAX);
+        let square = &big * &big;
+        assert!(square > big);
+        assert_eq!(square.to_i64(), None);
+        assert_eq!((square / big).to_string(), i128::MAX.to_string());
+    }
+}
@@ -0,0 +1,308 @@
+//! A parse error that knows where in the puzzle input it happened, and a few
+//! helpers for raising it.
+//!
+//! Parsers work on slices of the input they were given, so an error raised
+//! deep inside a line can be built straight from the offending slice. Errors
+//! from a parser that only saw part of the input are re-anchored with
+//! [`ParseError::within`] (or [`Within::within`] on a `Result`) so that the
+//! line and column always count from the start of the whole input.
+//!
+//! `{}` prints a one-line message and `{:#}` adds the source line with a
+//! caret under the offending text:
+//!
+//! ```text
+//! line 2, column 9: expected a number, found "x4"
+//!   |
+//! 2 | Card 2: x4 13 | 61 30
+//!   |         ^^
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    offset: usize,
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, in characters, of the offending text.
+    pub column: usize,
+    /// The offending text itself, empty if the input ended too soon.
+    pub text: String,
+    /// What the parser wanted to see instead.
+    pub expected: String,
+    source_line: String,
+    at_end: bool,
+}
+
+impl ParseError {
+    /// An error about `found`, which should be a slice of `input`. Text
+    /// that isn't a slice is looked up by value instead, falling back to
+    /// the start of the input.
+    pub fn new(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, found)
+            .or_else(|| input.find(found))
+            .unwrap_or(0);
+        Self::at(input, offset, found.len(), expected)
+    }
+
+    /// An error about the `len` bytes at `offset` in `input`.
+    pub fn at(input: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let offset = floor_char_boundary(input, offset);
+        let end = floor_char_boundary(input, offset + len);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            offset,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[offset..end].to_string(),
+            expected: expected.into(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            at_end: offset == input.len(),
+        }
+    }
+
+    /// An error for input that stopped before `expected` turned up.
+    pub fn end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, input.len(), 0, expected)
+    }
+
+    /// Re-anchors an error raised while parsing `part`, a slice of `input`,
+    /// so that its position counts from the start of `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        match offset_in(input, part) {
+            Some(base) => Self::at(input, base + self.offset, self.text.len(), self.expected),
+            None => self,
+        }
+    }
+
+    /// Byte offset of the offending text in the input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        match self.text.as_str() {
+            "" if self.at_end => write!(f, "end of input")?,
+            "" => write!(f, "end of line")?,
+            text => write!(f, "{:?}", text)?,
+        }
+
+        if f.alternate() {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let carets = self
+                .text
+                .lines()
+                .next()
+                .map_or(1, |t| t.trim_end_matches('\r').chars().count().max(1));
+            write!(
+                f,
+                "\n{} |\n{} | {}\n{} | {}{}",
+                gutter,
+                self.line,
+                self.source_line,
+                gutter,
+                " ".repeat(self.column - 1),
+                "^".repeat(carets)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Shows the caret diagnostic, so a failed `expect` points at the input.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#}", self)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// [`ParseError::within`] for results.
+pub trait Within<T> {
+    fn within(self, input: &str, part: &str) -> Result<T, ParseError>;
+}
+
+impl<T> Within<T> for Result<T, ParseError> {
+    fn within(self, input: &str, part: &str) -> Result<T, ParseError> {
+        self.map_err(|e| e.within(input, part))
+    }
+}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(input, token, "a number"))
+}
+
+/// Parses every whitespace-separated token of `part` as a number.
+pub fn numbers<T: FromStr>(input: &str, part: &str) -> Result<Vec<T>, ParseError> {
+    part.split_whitespace()
+        .map(|token| number(input, token))
+        .collect()
+}
+
+/// Splits `part` around the first `delimiter`.
+pub fn split_once<'a>(
+    input: &str,
+    part: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    part.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(input, part, format!("{:?}", delimiter)))
+}
+
+/// `part` without `prefix`, which it must start with.
+pub fn strip_prefix<'a>(input: &str, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    part.strip_prefix(prefix).ok_or_else(|| {
+        let found = part
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(part, |(i, _)| &part[..i]);
+        ParseError::new(input, found, format!("{:?}", prefix))
+    })
+}
+
+/// The next item of `items`, or an error at the end of `part` naming what
+/// was `expected`.
+pub fn next<'a>(
+    input: &str,
+    part: &'a str,
+    items: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    items
+        .next()
+        .ok_or_else(|| ParseError::new(input, &part[part.len()..], expected))
+}
+
+/// The blocks of lines separated by blank lines, tolerating `\r\n` endings.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        let (start, _) = line_spans(rest).find(|&(_, line)| !line.trim().is_empty())?;
+        let end = line_spans(&rest[start..])
+            .find(|&(_, line)| line.trim().is_empty())
+            .map_or(rest.len(), |(i, _)| start + i);
+        let block = rest[start..end].trim_end_matches(['\r', '\n']);
+        rest = &rest[end..];
+        Some(block)
+    })
+}
+
+/// Each line of `input` with its byte offset.
+fn line_spans(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line))
+    })
+}
+
+/// Where `part` starts in `input`, if it is a slice of it.
+fn offset_in(input: &str, part: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).checked_sub(start)?;
+    (offset + part.len() <= input.len()).then_some(offset)
+}
+
+fn floor_char_boundary(input: &str, mut index: usize) -> usize {
+    index = index.min(input.len());
+    while !input.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Card 1: 41 48 | 83 86\nCard 2: x4 13 | 61 30\n";
+
+    #[test]
+    fn locates_slices() {
+        let token = &INPUT[30..32];
+        let e = ParseError::new(INPUT, token, "a number");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 9, "x4"));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 9: expected a number, found \"x4\""
+        );
+    }
+
+    #[test]
+    fn caret_diagnostic() {
+        let e = number::<u32>(INPUT, &INPUT[30..32]).unwrap_err();
+        assert_eq!(
+            format!("{:#}", e),
+            "line 2, column 9: expected a number, found \"x4\"\n  \
+             |\n\
+             2 | Card 2: x4 13 | 61 30\n  \
+             |         ^^"
+        );
+    }
+
+    #[test]
+    fn within_reanchors() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let (_, numbers) = line.split_once(": ").unwrap();
+        let e = super::numbers::<u32>(numbers, numbers)
+            .within(INPUT, numbers)
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (2, 9));
+    }
+
+    #[test]
+    fn end_of_input_and_crlf() {
+        let input = "1 2\r\n3 4\r\n";
+        let e = ParseError::end(input, "a blank line");
+        assert_eq!(e.line, 3);
+        assert!(e.to_string().ends_with("found end of input"));
+
+        let e = ParseError::new(input, &input[7..8], "an even number");
+        assert_eq!((e.line, e.column), (2, 3));
+        assert!(format!("{:#}", e).contains("2 | 3 4\n"));
+    }
+
+    #[test]
+    fn helpers() {
+        let input = "move 3 from 1";
+        assert_eq!(strip_prefix(input, input, "move "), Ok("3 from 1"));
+        let e = strip_prefix(input, input, "jump ").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (1, "move "));
+
+        let e = split_once(input, input, " to ").unwrap_err();
+        assert_eq!(e.expected, "\" to \"");
+
+        let mut words = input.split(' ');
+        let e = (0..5)
+            .map(|_| next(input, input, &mut words, "a word"))
+            .last()
+            .unwrap()
+            .unwrap_err();
+        assert!(e.at_end);
+    }
+
+    #[test]
+    fn blocks_split_on_blank_lines() {
+        let input = "a\nb\n\nc\r\n\r\n\r\nd\n";
+        let blocks: Vec<_> = blocks(input).collect();
+        assert_eq!(blocks, ["a\nb", "c", "d"]);
+    }
+}
@@ -0,0 +1,459 @@
+//! Shortest-path searches over graphs described by a successor function.
+//!
+//! Every search takes any number of start nodes, so a multi-source search is
+//! just a search with several starts.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Edge weights: anything that can be added up and compared, with
+/// `Default` as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// A cheapest path, from its start to its goal inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn goal(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+}
+
+const ROOT: usize = usize::MAX;
+
+/// Every node reached so far, numbered in discovery order.
+struct Nodes<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    index: FxHashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone> Nodes<N> {
+    fn new() -> Nodes<N> {
+        Nodes {
+            nodes: Vec::new(),
+            parents: Vec::new(),
+            index: FxHashMap::default(),
+        }
+    }
+
+    /// Adds `node` unless it has been seen, returning its number either way
+    /// and whether it is new.
+    fn insert(&mut self, node: N, parent: usize) -> (usize, bool) {
+        match self.index.entry(node) {
+            Entry::Occupied(e) => (*e.get(), false),
+            Entry::Vacant(e) => {
+                let i = self.nodes.len();
+                self.nodes.push(e.key().clone());
+                self.parents.push(parent);
+                e.insert(i);
+                (i, true)
+            }
+        }
+    }
+
+    fn path_to<C>(&self, mut i: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![self.nodes[i].clone()];
+        while self.parents[i] != ROOT {
+            i = self.parents[i];
+            nodes.push(self.nodes[i].clone());
+        }
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+}
+
+/// Breadth-first search where every edge costs one step.
+pub fn bfs<N, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+    mut is_goal: FG,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut seen = Nodes::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let (i, true) = seen.insert(start, ROOT) {
+            queue.push_back((i, 0));
+        }
+    }
+
+    while let Some((i, steps)) = queue.pop_front() {
+        if is_goal(&seen.nodes[i]) {
+            return Some(seen.path_to(i, steps));
+        }
+
+        let node = seen.nodes[i].clone();
+        for next in successors(&node) {
+            if let (j, true) = seen.insert(next, i) {
+                queue.push_back((j, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps to every node reachable from the starts.
+pub fn bfs_distances<N, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+) -> FxHashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut distances = FxHashMap::default();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(e) = distances.entry(start.clone()) {
+            e.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        for next in successors(&node) {
+            if let Entry::Vacant(e) = distances.entry(next.clone()) {
+                e.insert(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Dijkstra's algorithm over non-negative edge costs.
+pub fn dijkstra<N, C, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FN,
+    is_goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost, or
+/// the path found may not be the cheapest.
+pub fn astar<N, C, FN, IN, FH, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut seen = Nodes::new();
+    let mut costs = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let (i, true) = seen.insert(start, ROOT) {
+            costs.push(C::default());
+            heap.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        if is_goal(&seen.nodes[i]) {
+            return Some(seen.path_to(i, cost));
+        }
+
+        let node = seen.nodes[i].clone();
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            let (j, new) = seen.insert(next, i);
+            if new {
+                costs.push(next_cost);
+            } else if next_cost < costs[j] {
+                costs[j] = next_cost;
+                seen.parents[j] = i;
+            } else {
+                continue;
+            }
+            heap.push(Reverse((estimate, next_cost, j)));
+        }
+    }
+    None
+}
+
+/// Every cheapest path to the nearest goals, kept as a DAG of predecessors.
+#[derive(Debug, Clone)]
+pub struct AllPaths<N, C> {
+    cost: C,
+    nodes: Vec<N>,
+    index: FxHashMap<N, usize>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<N: Eq + Hash, C: Copy> AllPaths<N, C> {
+    /// The cost shared by every optimal path.
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// The goals reached at the optimal cost.
+    pub fn goals(&self) -> impl Iterator<Item = &N> {
+        self.goals.iter().map(|&i| &self.nodes[i])
+    }
+
+    /// The nodes that precede `node` on some cheapest path to it.
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.index
+            .get(node)
+            .map_or(&[][..], |&i| &self.predecessors[i])
+            .iter()
+            .map(|&i| &self.nodes[i])
+    }
+
+    /// Every node on at least one optimal path, goals and starts included.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        let mut on_path = FxHashSet::default();
+        let mut stack = self.goals.clone();
+        while let Some(i) = stack.pop() {
+            if on_path.insert(i) {
+                stack.extend(&self.predecessors[i]);
+            }
+        }
+        on_path.into_iter().map(|i| &self.nodes[i])
+    }
+}
+
+/// Dijkstra's algorithm that keeps every optimal path instead of one.
+///
+/// The search stops once the cheapest goals are settled, and all goals at
+/// that cost are kept.
+pub fn dijkstra_all<N, C, FN, IN, FG>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: FN,
+    mut is_goal: FG,
+) -> Option<AllPaths<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut seen = Nodes::new();
+    let mut costs = Vec::new();
+    let mut predecessors: Vec<Vec<usize>> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if let (i, true) = seen.insert(start, ROOT) {
+            costs.push(C::default());
+            predecessors.push(Vec::new());
+            heap.push(Reverse((C::default(), i)));
+        }
+    }
+
+    let mut best = None;
+    let mut goals = Vec::new();
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if is_goal(&seen.nodes[i]) {
+            best = Some(cost);
+            goals.push(i);
+            continue;
+        }
+
+        let node = seen.nodes[i].clone();
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let (j, new) = seen.insert(next, i);
+            if new {
+                costs.push(next_cost);
+                predecessors.push(vec![i]);
+            } else if next_cost < costs[j] {
+                costs[j] = next_cost;
+                predecessors[j] = vec![i];
+            } else {
+                if next_cost == costs[j] && !predecessors[j].contains(&i) {
+                    predecessors[j].push(i);
+                }
+                continue;
+            }
+            heap.push(Reverse((next_cost, j)));
+        }
+    }
+
+    Some(AllPaths {
+        cost: best?,
+        nodes: seen.nodes,
+        index: seen.index,
+        predecessors,
+        goals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::{Grid, Pos};
+
+    const MAZE: &str = "\
+.S.#
+.#..
+...E
+";
+
+    fn maze() -> (Grid<char>, Pos, Pos) {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+        (grid, start, end)
+    }
+
+    fn open_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbors4(pos).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let (grid, start, end) = maze();
+
+        let path = bfs([start], |&p| open_neighbours(&grid, p), |&p| p == end).unwrap();
+
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.nodes.len(), 5);
+        assert_eq!((*path.start(), *path.goal()), (start, end));
+        for pair in path.nodes.windows(2) {
+            assert!(open_neighbours(&grid, pair[0]).contains(&pair[1]));
+        }
+    }
+
+    #[test]
+    fn bfs_from_several_starts_uses_the_nearest() {
+        let (grid, start, end) = maze();
+        let near = Pos::new(3, 1);
+
+        let path = bfs([start, near], |&p| open_neighbours(&grid, p), |&p| p == end).unwrap();
+
+        assert_eq!(path.nodes, [near, end]);
+    }
+
+    #[test]
+    fn bfs_distances_floods_the_reachable_area() {
+        let (grid, start, end) = maze();
+
+        let distances = bfs_distances([start], |&p| open_neighbours(&grid, p));
+
+        assert_eq!(distances.len(), 10);
+        assert_eq!(distances[&start], 0);
+        assert_eq!(distances[&end], 4);
+    }
+
+    #[test]
+    fn unreachable_goals_give_none() {
+        let successors = |&n: &u32| if n < 10 { vec![(n + 1, 1u32)] } else { vec![] };
+
+        assert!(bfs(
+            [0],
+            |&n| successors(&n).into_iter().map(|(n, _)| n),
+            |&n| n == 20
+        )
+        .is_none());
+        assert!(dijkstra([0], successors, |&n| n == 20).is_none());
+        assert!(dijkstra_all([0], successors, |&n| n == 20).is_none());
+    }
+
+    /// A small weighted graph where the direct edge is not the cheapest.
+    fn weighted(n: &char) -> Vec<(char, u32)> {
+        match n {
+            'a' => vec![('b', 1), ('c', 4), ('d', 10)],
+            'b' => vec![('c', 1), ('d', 6)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_follows_the_cheapest_edges() {
+        let path = dijkstra(['a'], weighted, |&n| n == 'd').unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes, ['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let (grid, start, end) = maze();
+        let successors = |&p: &Pos| open_neighbours(&grid, p).into_iter().map(|n| (n, 1));
+        let manhattan = |p: &Pos| p.x.abs_diff(end.x) + p.y.abs_diff(end.y);
+
+        let astar = astar([start], successors, manhattan, |&p| p == end).unwrap();
+        let dijkstra = dijkstra([start], successors, |&p| p == end).unwrap();
+
+        assert_eq!(astar.cost, dijkstra.cost);
+    }
+
+    #[test]
+    fn dijkstra_all_keeps_every_optimal_path() {
+        let (grid, start, end) = maze();
+        let successors = |&p: &Pos| open_neighbours(&grid, p).into_iter().map(|n| (n, 1));
+
+        let all = dijkstra_all([start], successors, |&p| p == end).unwrap();
+
+        assert_eq!(all.cost(), 4);
+        assert_eq!(all.goals().collect::<Vec<_>>(), [&end]);
+        // Both ways round (3, 2), but not the long way down the left side.
+        let mut on_path: Vec<Pos> = all.nodes().copied().collect();
+        on_path.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(
+            on_path,
+            [
+                Pos::new(1, 0),
+                Pos::new(2, 0),
+                Pos::new(2, 1),
+                Pos::new(3, 1),
+                Pos::new(2, 2),
+                Pos::new(3, 2)
+            ]
+        );
+        let mut into_end: Vec<Pos> = all.predecessors(&end).copied().collect();
+        into_end.sort();
+        assert_eq!(into_end, [Pos::new(2, 2), Pos::new(3, 1)]);
+    }
+}
@@ -0,0 +1,270 @@
+//! The command line every day's binary shares:
+//!
+//! ```text
+//! dayNN [<input>...] [--part <1|2>] [--expect <answer>]
+//! ```
+//!
+//! Each input is a path, or `-` for stdin, and the day's `input/test1.txt`
+//! is solved when none is given. Both parts are printed as `part N: answer`
+//! unless `--part` picks one, in which case only its answer is printed, so
+//! the binary can sit in a pipeline. `--expect` makes the binary exit with
+//! an error when an answer differs from it.
+
+use std::env;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::{Answer, Solution};
+
+const USAGE: &str = "\
+Usage: {name} [<input>...] [--part <1|2>] [--expect <answer>]
+
+Arguments:
+    <input>             Puzzle input to solve, or - for stdin (default: {default})
+
+Options:
+    --part <1|2>        Only solve the given part and print just its answer
+    --expect <answer>   Exit with an error unless every answer is this one";
+
+/// What the command line asked for.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Args {
+    pub inputs: Vec<String>,
+    pub part: Option<u8>,
+    pub expect: Option<String>,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let part = args.next().ok_or("--part needs a value")?;
+                    parsed.part = match part.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("--part must be 1 or 2, got '{}'", part)),
+                    };
+                }
+                "--expect" => {
+                    parsed.expect = Some(args.next().ok_or("--expect needs a value")?);
+                }
+                "-h" | "--help" => parsed.help = true,
+                _ if arg.starts_with("--") => return Err(format!("unexpected argument '{}'", arg)),
+                _ => parsed.inputs.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// The `main` of a day's binary. `default_input` is solved when no input is
+/// given on the command line.
+pub fn main<S: Solution>(default_input: &str) -> ExitCode {
+    let mut args = env::args();
+    let name = args
+        .next()
+        .as_deref()
+        .and_then(|path| Path::new(path).file_name())
+        .map_or("day".to_string(), |name| {
+            name.to_string_lossy().into_owned()
+        });
+
+    let result = Args::parse(args).and_then(|args| {
+        if args.help {
+            let usage = USAGE
+                .replace("{name}", &name)
+                .replace("{default}", default_input);
+            // A closed pipe, as from `--help | head`, is no reason to panic.
+            let _ = writeln!(io::stdout(), "{}", usage);
+            return Ok(true);
+        }
+        run::<S>(
+            &args,
+            default_input,
+            &mut io::stdout().lock(),
+            &mut read_input,
+        )
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    let mut text = String::new();
+    let read = match path {
+        "-" => io::stdin().read_to_string(&mut text).map(|_| text),
+        _ => std::fs::read_to_string(path),
+    };
+    read.map_err(|e| format!("unable to read {}: {}", path, e))
+}
+
+/// Solves the inputs `args` asks for, reading each with `read` and printing
+/// the answers to `out`. Returns false if any answer wasn't the expected one.
+pub fn run<S: Solution>(
+    args: &Args,
+    default_input: &str,
+    out: &mut impl Write,
+    read: &mut impl FnMut(&str) -> Result<String, String>,
+) -> Result<bool, String> {
+    let parts: Vec<u8> = match args.part {
+        Some(part) if part > S::PARTS => return Err(format!("there is no part {}", part)),
+        Some(part) => vec![part],
+        None => (1..=S::PARTS).collect(),
+    };
+    if args.expect.is_some() && parts.len() > 1 {
+        return Err("--expect needs --part to say which answer to check".to_string());
+    }
+
+    let inputs = match args.inputs.as_slice() {
+        [] => vec![default_input.to_string()],
+        inputs => inputs.to_vec(),
+    };
+
+    let mut matched = true;
+    for path in &inputs {
+        let text = read(path)?;
+        let parsed = S::parse(&text).map_err(|e| format!("{}: {:#}", path, e))?;
+        for &part in &parts {
+            let answer = match part {
+                1 => S::part1(&parsed).render(),
+                _ => S::part2(&parsed).render(),
+            }
+            .map_err(|e| format!("{}: no answer to part {}: {}", path, part, e))?;
+
+            // Name the input when there's more than one, and the part when
+            // both are printed.
+            let mut label = Vec::new();
+            if inputs.len() > 1 {
+                label.push(path.clone());
+            }
+            if args.part.is_none() {
+                label.push(format!("part {}", part));
+            }
+            let write = if label.is_empty() {
+                writeln!(out, "{}", answer)
+            } else {
+                writeln!(out, "{}: {}", label.join(" "), answer)
+            };
+            write.map_err(|e| format!("unable to write the answer: {}", e))?;
+
+            if let Some(expected) = &args.expect {
+                if answer.trim() != expected.trim() {
+                    eprintln!(
+                        "{} part {}: expected {} but got {}",
+                        path, part, expected, answer
+                    );
+                    matched = false;
+                }
+            }
+        }
+    }
+    Ok(matched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    /// Sums and multiplies whitespace separated numbers.
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input<'a> = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            aoc_parse::numbers(input, input)
+        }
+
+        fn part1(numbers: &Self::Input<'_>) -> u64 {
+            numbers.iter().sum()
+        }
+
+        fn part2(numbers: &Self::Input<'_>) -> u64 {
+            numbers.iter().product()
+        }
+    }
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    fn run_with(args: &Args) -> (Result<bool, String>, String) {
+        let mut out = Vec::new();
+        let mut read = |path: &str| match path {
+            "-" => Ok("2 3 4".to_string()),
+            "input/test1.txt" => Ok("1 2".to_string()),
+            "bad.txt" => Ok("1 x".to_string()),
+            _ => Err(format!("unable to read {}", path)),
+        };
+        let result = run::<Numbers>(args, "input/test1.txt", &mut out, &mut read);
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parses_inputs_and_options_in_any_order() {
+        let parsed = args(&["a.txt", "--part", "2", "-", "--expect", "42"]).unwrap();
+        assert_eq!(parsed.inputs, ["a.txt", "-"]);
+        assert_eq!(parsed.part, Some(2));
+        assert_eq!(parsed.expect.as_deref(), Some("42"));
+
+        assert!(args(&["--part", "3"]).is_err());
+        assert!(args(&["--expect"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn solves_the_default_input_when_none_is_given() {
+        let (result, out) = run_with(&args(&[]).unwrap());
+        assert_eq!(result, Ok(true));
+        assert_eq!(out, "part 1: 3\npart 2: 2\n");
+    }
+
+    #[test]
+    fn prints_only_the_answer_for_one_part() {
+        let (result, out) = run_with(&args(&["-", "--part", "2"]).unwrap());
+        assert_eq!(result, Ok(true));
+        assert_eq!(out, "24\n");
+    }
+
+    #[test]
+    fn names_each_input_when_there_are_several() {
+        let (_, out) = run_with(&args(&["input/test1.txt", "-", "--part", "1"]).unwrap());
+        assert_eq!(out, "input/test1.txt: 3\n-: 9\n");
+    }
+
+    #[test]
+    fn fails_when_an_answer_is_not_the_expected_one() {
+        let (result, _) = run_with(&args(&["-", "--part", "1", "--expect", "9"]).unwrap());
+        assert_eq!(result, Ok(true));
+
+        let (result, _) = run_with(&args(&["-", "--part", "1", "--expect", "10"]).unwrap());
+        assert_eq!(result, Ok(false));
+
+        let (result, _) = run_with(&args(&["-", "--expect", "9"]).unwrap());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn reports_unreadable_and_malformed_inputs() {
+        let (result, _) = run_with(&args(&["missing.txt"]).unwrap());
+        assert_eq!(result, Err("unable to read missing.txt".to_string()));
+
+        let (result, _) = run_with(&args(&["bad.txt"]).unwrap());
+        assert!(result.unwrap_err().starts_with("bad.txt: line 1, column 3"));
+    }
+}
@@ -0,0 +1,150 @@
+//! The shape every day's solution takes, so the runner can parse an input
+//! once, time each phase and print the answers without knowing their types.
+//!
+//! A day implements [`Solution`] on a unit struct named after it:
+//!
+//! ```
+//! use aoc_solution::{ParseError, Solution};
+//!
+//! pub struct Day01;
+//!
+//! impl Solution for Day01 {
+//!     type Input<'a> = Vec<u32>;
+//!     type Answer1 = u32;
+//!     type Answer2 = u32;
+//!
+//!     fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+//!         aoc_parse::numbers(input, input)
+//!     }
+//!
+//!     fn part1(depths: &Self::Input<'_>) -> u32 {
+//!         depths.iter().sum()
+//!     }
+//!
+//!     fn part2(depths: &Self::Input<'_>) -> u32 {
+//!         depths.iter().copied().max().unwrap_or(0)
+//!     }
+//! }
+//!
+//! let depths = Day01::parse("199 200 208").unwrap();
+//! assert_eq!(Day01::part1(&depths), 607);
+//! assert_eq!(Day01::part2(&depths), 208);
+//! ```
+
+use std::fmt;
+
+pub use aoc_parse::ParseError;
+
+pub mod cli;
+
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts. It may borrow from the
+    /// text it was parsed from.
+    type Input<'a>;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// How many parts the puzzle has; the last day of a year only has one.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+/// What a part returns. Most parts always find an answer, but one whose
+/// input can turn out to have none returns a `Result`, and its error is
+/// reported in place of an answer, never printed or submitted as one.
+pub trait Answer {
+    /// The answer as it is printed and submitted, or why there isn't one.
+    fn render(&self) -> Result<String, String>;
+}
+
+macro_rules! display_answers {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn render(&self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_answers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String, NoPuzzle);
+
+impl<T: Answer, E: fmt::Display> Answer for Result<T, E> {
+    fn render(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => answer.render(),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// The second answer of a day with [`Solution::PARTS`] set to 1: the last day
+/// has no second puzzle, only a star for finishing all the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPuzzle;
+
+impl fmt::Display for NoPuzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no puzzle")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts words, borrowing them from the input.
+    struct Words;
+
+    impl Solution for Words {
+        type Input<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = NoPuzzle;
+
+        const PARTS: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            match input
+                .split_whitespace()
+                .find(|w| !w.chars().all(char::is_alphabetic))
+            {
+                Some(word) => Err(ParseError::new(input, word, "a word")),
+                None => Ok(input.split_whitespace().collect()),
+            }
+        }
+
+        fn part1(words: &Self::Input<'_>) -> usize {
+            words.len()
+        }
+
+        fn part2(_: &Self::Input<'_>) -> NoPuzzle {
+            NoPuzzle
+        }
+    }
+
+    #[test]
+    fn parses_once_for_both_parts() {
+        let words = Words::parse("the quick\nbrown fox").unwrap();
+        assert_eq!(Words::part1(&words), 4);
+        assert_eq!(Words::part2(&words).to_string(), "no puzzle");
+        assert_eq!(Words::PARTS, 1);
+
+        let e = Words::parse("the quick\nbr0wn fox").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn errors_render_as_no_answer() {
+        assert_eq!(42u64.render(), Ok("42".to_string()));
+        assert_eq!(Ok::<_, String>(7).render(), Ok("7".to_string()));
+        assert_eq!(
+            Err::<i32, _>("no rock hits every hailstone").render(),
+            Err("no rock hits every hailstone".to_string())
+        );
+    }
+}
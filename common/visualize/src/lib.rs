@@ -0,0 +1,340 @@
+//! Playback for the days that run a simulation. The simulation hands out its
+//! state one frame at a time through [`Simulation`], and a [`Player`] either
+//! redraws the frames in place on an ANSI terminal or writes them out as
+//! numbered PNGs or an animated GIF.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, DynamicImage, Frame, Rgb, RgbImage};
+
+/// A simulation that can be watched while it runs.
+pub trait Simulation {
+    /// The current state, one character per cell.
+    fn frame(&self) -> Grid<char>;
+
+    /// Advances to the next frame, or returns false once there are no more.
+    fn step(&mut self) -> bool;
+
+    /// The colour `cell` is drawn in.
+    fn color(&self, cell: char) -> [u8; 3] {
+        palette(cell)
+    }
+}
+
+/// Sets up the simulation behind one part of a day from its puzzle input.
+pub type Simulate = fn(input: &str, part: u8) -> Result<Box<dyn Simulation>, ParseError>;
+
+/// Colours for the characters the days draw with. Empty space is black and
+/// walls are grey; other characters get a bright colour picked from the
+/// character itself, so different things at least look different.
+pub fn palette(cell: char) -> [u8; 3] {
+    const BRIGHT: [[u8; 3]; 6] = [
+        [85, 255, 85],
+        [85, 170, 255],
+        [255, 85, 255],
+        [85, 255, 255],
+        [255, 170, 0],
+        [255, 255, 255],
+    ];
+
+    match cell {
+        '.' | ' ' => [0, 0, 0],
+        '#' => [136, 136, 136],
+        '|' | '-' | '+' => [85, 85, 85],
+        '@' => [255, 85, 85],
+        'o' => [255, 221, 119],
+        '~' => [119, 102, 68],
+        _ => BRIGHT[cell as usize % BRIGHT.len()],
+    }
+}
+
+/// Where a [`Player`] sends the frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Output {
+    /// Redraw each frame in place on the terminal.
+    Terminal,
+    /// Write `frame_00000.png`, `frame_00001.png`, ... into a directory.
+    Png(PathBuf),
+    /// Write one looping animated GIF.
+    Gif(PathBuf),
+}
+
+impl Output {
+    /// The output for a `--visualize` path: the terminal without one, a GIF
+    /// for a path ending in `.gif` and PNG frames in any other directory.
+    pub fn for_path(path: Option<PathBuf>) -> Output {
+        match path {
+            None => Output::Terminal,
+            Some(path)
+                if path
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("gif")) =>
+            {
+                Output::Gif(path)
+            }
+            Some(path) => Output::Png(path),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub output: Output,
+    /// Frames a second, on the terminal and in a GIF.
+    pub fps: u32,
+    /// Stop after this many frames, for simulations that run a long time.
+    pub max_frames: Option<usize>,
+}
+
+impl Player {
+    pub fn new(output: Output) -> Player {
+        Player {
+            output,
+            fps: 30,
+            max_frames: None,
+        }
+    }
+
+    /// Plays `simulation` from its current state until it finishes or
+    /// `max_frames` have been shown, and returns how many were.
+    pub fn play(&self, simulation: &mut dyn Simulation) -> io::Result<usize> {
+        match &self.output {
+            Output::Terminal => self.play_terminal(simulation),
+            Output::Png(dir) => self.write_pngs(simulation, dir),
+            Output::Gif(path) => self.write_gif(simulation, path),
+        }
+    }
+
+    fn frame_time(&self) -> Duration {
+        Duration::from_secs(1) / self.fps.max(1)
+    }
+
+    /// Calls `show` with every frame, stepping between them.
+    fn for_each_frame(
+        &self,
+        simulation: &mut dyn Simulation,
+        mut show: impl FnMut(&dyn Simulation, &Grid<char>) -> io::Result<()>,
+    ) -> io::Result<usize> {
+        let mut shown = 0;
+        loop {
+            show(simulation, &simulation.frame())?;
+            shown += 1;
+            if self.max_frames.is_some_and(|max| shown >= max) || !simulation.step() {
+                return Ok(shown);
+            }
+        }
+    }
+
+    fn play_terminal(&self, simulation: &mut dyn Simulation) -> io::Result<usize> {
+        let mut out = BufWriter::new(io::stdout().lock());
+        // Hide the cursor and start from a clear screen.
+        write!(out, "\x1b[?25l\x1b[2J")?;
+
+        let mut next = Instant::now();
+        let shown = self.for_each_frame(simulation, |simulation, frame| {
+            write_ansi(&mut out, frame, |cell| simulation.color(cell))?;
+            out.flush()?;
+            next += self.frame_time();
+            thread::sleep(next.saturating_duration_since(Instant::now()));
+            Ok(())
+        });
+
+        write!(out, "\x1b[?25h")?;
+        out.flush()?;
+        shown
+    }
+
+    fn write_pngs(&self, simulation: &mut dyn Simulation, dir: &Path) -> io::Result<usize> {
+        fs::create_dir_all(dir)?;
+        let mut index = 0;
+        self.for_each_frame(simulation, |simulation, frame| {
+            let image = render(frame, frame.width(), frame.height(), |cell| {
+                simulation.color(cell)
+            });
+            let path = dir.join(format!("frame_{:05}.png", index));
+            index += 1;
+            image.save(&path).map_err(io::Error::other)
+        })
+    }
+
+    fn write_gif(&self, simulation: &mut dyn Simulation, path: &Path) -> io::Result<usize> {
+        // A GIF's frames share one size, which isn't known until the
+        // simulation has finished growing, so keep them all until then.
+        let mut frames = Vec::new();
+        let shown = self.for_each_frame(simulation, |_, frame| {
+            frames.push(frame.clone());
+            Ok(())
+        })?;
+
+        let width = frames.iter().map(Grid::width).max().unwrap_or(0);
+        let height = frames.iter().map(Grid::height).max().unwrap_or(0);
+        let delay = Delay::from_saturating_duration(self.frame_time());
+
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut encoder = GifEncoder::new_with_speed(BufWriter::new(File::create(path)?), 30);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        for frame in &frames {
+            let image = render(frame, width, height, |cell| simulation.color(cell));
+            let rgba = DynamicImage::ImageRgb8(image).into_rgba8();
+            encoder
+                .encode_frame(Frame::from_parts(rgba, 0, 0, delay))
+                .map_err(io::Error::other)?;
+        }
+        Ok(shown)
+    }
+}
+
+/// Draws `frame` from the top left of the screen, one coloured character
+/// per cell, clearing whatever an earlier, larger frame left behind.
+fn write_ansi(
+    out: &mut impl Write,
+    frame: &Grid<char>,
+    color: impl Fn(char) -> [u8; 3],
+) -> io::Result<()> {
+    write!(out, "\x1b[H")?;
+    for row in frame.rows() {
+        let mut current = None;
+        for &cell in row {
+            let [r, g, b] = color(cell);
+            if current != Some([r, g, b]) {
+                write!(out, "\x1b[38;2;{};{};{}m", r, g, b)?;
+                current = Some([r, g, b]);
+            }
+            write!(out, "{}", cell)?;
+        }
+        writeln!(out, "\x1b[0m\x1b[K")?;
+    }
+    write!(out, "\x1b[J")
+}
+
+/// How many pixels across each cell is drawn, so small grids aren't tiny.
+fn scale(width: usize, height: usize) -> u32 {
+    (640 / width.max(height).max(1)).clamp(1, 16) as u32
+}
+
+/// Draws `frame` as square blocks of colour on a black `width` by `height`
+/// cell canvas.
+fn render(
+    frame: &Grid<char>,
+    width: usize,
+    height: usize,
+    color: impl Fn(char) -> [u8; 3],
+) -> RgbImage {
+    let scale = scale(width, height);
+    let mut image = RgbImage::new(width.max(1) as u32 * scale, height.max(1) as u32 * scale);
+    for (pos, &cell) in frame.iter() {
+        let rgb = Rgb(color(cell));
+        for dy in 0..scale {
+            for dx in 0..scale {
+                image.put_pixel(pos.x as u32 * scale + dx, pos.y as u32 * scale + dy, rgb);
+            }
+        }
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A bar that grows by one cell a frame until it is `length` long.
+    struct Bar {
+        filled: usize,
+        length: usize,
+    }
+
+    impl Simulation for Bar {
+        fn frame(&self) -> Grid<char> {
+            Grid::from_vec(self.filled, vec!['#'; self.filled])
+        }
+
+        fn step(&mut self) -> bool {
+            self.filled += 1;
+            self.filled <= self.length
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-visualize-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn picks_the_output_from_the_path() {
+        assert_eq!(Output::for_path(None), Output::Terminal);
+        assert_eq!(
+            Output::for_path(Some("out/sand.GIF".into())),
+            Output::Gif("out/sand.GIF".into())
+        );
+        assert_eq!(
+            Output::for_path(Some("out/frames".into())),
+            Output::Png("out/frames".into())
+        );
+    }
+
+    #[test]
+    fn draws_each_run_of_a_colour_once() {
+        let frame: Grid<char> = "#..\n@#.".parse().unwrap();
+        let mut out = Vec::new();
+        write_ansi(&mut out, &frame, palette).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[H\
+             \x1b[38;2;136;136;136m#\x1b[38;2;0;0;0m..\x1b[0m\x1b[K\n\
+             \x1b[38;2;255;85;85m@\x1b[38;2;136;136;136m#\x1b[38;2;0;0;0m.\x1b[0m\x1b[K\n\
+             \x1b[J"
+        );
+    }
+
+    #[test]
+    fn writes_a_png_per_frame() {
+        let dir = temp_dir("png");
+        let mut bar = Bar {
+            filled: 1,
+            length: 4,
+        };
+        let shown = Player::new(Output::Png(dir.clone()))
+            .play(&mut bar)
+            .unwrap();
+
+        assert_eq!(shown, 4);
+        let last = image::open(dir.join("frame_00003.png")).unwrap();
+        assert_eq!((last.width(), last.height()), (4 * 16, 16));
+        assert!(!dir.join("frame_00004.png").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn stops_after_max_frames() {
+        let dir = temp_dir("gif");
+        let path = dir.join("bar.gif");
+        let mut bar = Bar {
+            filled: 1,
+            length: 100,
+        };
+        let player = Player {
+            max_frames: Some(5),
+            ..Player::new(Output::Gif(path.clone()))
+        };
+
+        assert_eq!(player.play(&mut bar).unwrap(), 5);
+        assert_eq!(bar.filled, 5);
+        let gif = image::open(&path).unwrap();
+        assert_eq!((gif.width(), gif.height()), (5 * 16, 16));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc_solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = aoc_2022_day05::Day05::parse(input);
+});